@@ -0,0 +1,235 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use goldilocks_64bit::hash::{monolith, poseidon, poseidon2};
+use goldilocks_64bit::ntt::{four_step_ntt, ntt_in_place, poly_mul};
+use goldilocks_64bit::reduction::{BarrettReducer, EpsilonReducer, Reducer};
+use goldilocks_64bit::{
+    batch_inverse, inner_product, FixedBasePow, GoldilocksCubicExtension, GoldilocksField,
+    GoldilocksQuadraticExtension, GoldilocksQuinticExtension, MODULUS,
+};
+use lambdaworks_math::field::traits::IsField;
+
+const X: u64 = MODULUS - 0x1234_5678_9abc;
+
+fn bench_square(c: &mut Criterion) {
+    let mut group = c.benchmark_group("square");
+    group.bench_function("square", |b| {
+        b.iter(|| <GoldilocksField as IsField>::square(black_box(&X)))
+    });
+    group.bench_function("mul(a, a)", |b| {
+        b.iter(|| GoldilocksField::mul(black_box(&X), black_box(&X)))
+    });
+    group.finish();
+}
+
+fn bench_mul_small(c: &mut Criterion) {
+    let x = GoldilocksField::new(X);
+    let seven = GoldilocksField::GENERATOR;
+    let mut group = c.benchmark_group("mul_small");
+    group.bench_function("mul_u32(7)", |b| b.iter(|| black_box(x).mul_u32(black_box(7))));
+    group.bench_function("mul_by_7", |b| b.iter(|| black_box(x).mul_by_7()));
+    group.bench_function("mul(x, 7)", |b| b.iter(|| black_box(x) * black_box(seven)));
+    group.bench_function("mul_by_3", |b| b.iter(|| black_box(x).mul_by_3()));
+    group.finish();
+}
+
+fn bench_extension_mul(c: &mut Criterion) {
+    let coeff = |i: u64| GoldilocksField::new(X ^ (i * 0x9e37_79b9));
+    let mut group = c.benchmark_group("extension_mul");
+    let (a, b) = (
+        GoldilocksQuadraticExtension::new(core::array::from_fn(|i| coeff(i as u64))),
+        GoldilocksQuadraticExtension::new(core::array::from_fn(|i| coeff(i as u64 + 5))),
+    );
+    group.bench_function("quadratic", |bench| bench.iter(|| black_box(a) * black_box(b)));
+    let (a, b) = (
+        GoldilocksCubicExtension::new(core::array::from_fn(|i| coeff(i as u64))),
+        GoldilocksCubicExtension::new(core::array::from_fn(|i| coeff(i as u64 + 5))),
+    );
+    group.bench_function("cubic", |bench| bench.iter(|| black_box(a) * black_box(b)));
+    let (a, b) = (
+        GoldilocksQuinticExtension::new(core::array::from_fn(|i| coeff(i as u64))),
+        GoldilocksQuinticExtension::new(core::array::from_fn(|i| coeff(i as u64 + 5))),
+    );
+    group.bench_function("quintic", |bench| bench.iter(|| black_box(a) * black_box(b)));
+    group.finish();
+}
+
+/// The Karatsuba `mul` of Fp^2 and Fp^3 against the schoolbook formulas
+/// written out on base-field operations, with one reduction per product.
+fn bench_karatsuba(c: &mut Criterion) {
+    let coeff = |i: u64| GoldilocksField::new(X ^ (i * 0x9e37_79b9));
+    let mut group = c.benchmark_group("karatsuba");
+
+    let (a, b) = (
+        GoldilocksQuadraticExtension::new(core::array::from_fn(|i| coeff(i as u64))),
+        GoldilocksQuadraticExtension::new(core::array::from_fn(|i| coeff(i as u64 + 5))),
+    );
+    group.bench_function("quadratic karatsuba", |bench| bench.iter(|| black_box(a) * black_box(b)));
+    group.bench_function("quadratic schoolbook", |bench| {
+        bench.iter(|| {
+            let ([a0, a1], [b0, b1]) = (black_box(a).coefficients(), black_box(b).coefficients());
+            [a0 * b0 + (a1 * b1).mul_by_7(), a0 * b1 + a1 * b0]
+        })
+    });
+
+    let (a, b) = (
+        GoldilocksCubicExtension::new(core::array::from_fn(|i| coeff(i as u64))),
+        GoldilocksCubicExtension::new(core::array::from_fn(|i| coeff(i as u64 + 5))),
+    );
+    group.bench_function("cubic karatsuba", |bench| bench.iter(|| black_box(a) * black_box(b)));
+    group.bench_function("cubic schoolbook", |bench| {
+        bench.iter(|| {
+            let ([a0, a1, a2], [b0, b1, b2]) = (black_box(a).coefficients(), black_box(b).coefficients());
+            [
+                a0 * b0 + (a1 * b2 + a2 * b1).double(),
+                a0 * b1 + a1 * b0 + (a2 * b2).double(),
+                a0 * b2 + a1 * b1 + a2 * b0,
+            ]
+        })
+    });
+    group.finish();
+}
+
+fn bench_inner_product(c: &mut Criterion) {
+    let a: Vec<_> = (0..1u64 << 12).map(|i| GoldilocksField::new(X ^ (i * 0x9e37_79b9))).collect();
+    let b: Vec<_> = a.iter().map(|x| x.mul_by_7()).collect();
+    let mut group = c.benchmark_group("inner_product");
+    group.bench_function("lazy reduction", |bench| {
+        bench.iter(|| inner_product(black_box(&a), black_box(&b)))
+    });
+    group.bench_function("naive fold", |bench| {
+        bench.iter(|| {
+            black_box(&a)
+                .iter()
+                .zip(black_box(&b))
+                .fold(GoldilocksField::ZERO, |acc, (x, y)| acc + *x * *y)
+        })
+    });
+    group.finish();
+}
+
+fn bench_reducers(c: &mut Criterion) {
+    let y = X ^ 0x9e37_79b9;
+    let mut group = c.benchmark_group("reduction");
+    group.bench_function("epsilon mul", |b| {
+        b.iter(|| EpsilonReducer::mul(black_box(X), black_box(y)))
+    });
+    group.bench_function("barrett mul", |b| {
+        b.iter(|| BarrettReducer::mul(black_box(X), black_box(y)))
+    });
+    group.finish();
+}
+
+fn bench_batch_inverse(c: &mut Criterion) {
+    let values: Vec<_> = (1..=1u64 << 10).map(|i| GoldilocksField::new(X ^ (i * 0x9e37_79b9))).collect();
+    let mut group = c.benchmark_group("batch_inverse");
+    group.bench_function("batch_inverse", |b| b.iter(|| batch_inverse(black_box(&values))));
+    group.bench_function("inv per element", |b| {
+        b.iter(|| {
+            black_box(&values).iter().map(|x| GoldilocksField::ONE / *x).collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn bench_fixed_base_pow(c: &mut Criterion) {
+    let exponents: Vec<u64> = (0..100u64).map(|i| X ^ (i * 0x9e37_79b9_7f4a_7c15)).collect();
+    let base = GoldilocksField::GENERATOR;
+    let mut group = c.benchmark_group("fixed_base_pow");
+    group.bench_function("100 x pow_u64", |b| {
+        b.iter(|| black_box(&exponents).iter().map(|&e| base.pow_u64(e)).collect::<Vec<_>>())
+    });
+    group.bench_function("100 x FixedBasePow (incl. tables)", |b| {
+        b.iter(|| {
+            let table = FixedBasePow::new(black_box(base), 8);
+            black_box(&exponents).iter().map(|&e| table.pow(e)).collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn schoolbook_mul(a: &[GoldilocksField], b: &[GoldilocksField]) -> Vec<GoldilocksField> {
+    let mut product = vec![GoldilocksField::ZERO; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] += *x * *y;
+        }
+    }
+    product
+}
+
+fn bench_poly_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poly_mul");
+    for len in [8usize, 16, 32, 64, 128, 256] {
+        let a: Vec<_> = (0..len as u64).map(|i| GoldilocksField::new(X ^ (i * 0x9e37_79b9))).collect();
+        let b: Vec<_> = a.iter().map(|x| x.mul_by_7()).collect();
+        group.bench_function(format!("ntt {len}"), |bench| bench.iter(|| poly_mul(black_box(&a), black_box(&b))));
+        group.bench_function(format!("schoolbook {len}"), |bench| {
+            bench.iter(|| schoolbook_mul(black_box(&a), black_box(&b)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_large_ntt(c: &mut Criterion) {
+    let values: Vec<_> = (0..1u64 << 22).map(|i| GoldilocksField::new(X ^ (i * 0x9e37_79b9))).collect();
+    let mut group = c.benchmark_group("ntt 2^22");
+    group.sample_size(10);
+    group.bench_function("radix-2", |b| {
+        b.iter(|| {
+            let mut v = values.clone();
+            ntt_in_place(black_box(&mut v)).unwrap();
+            v
+        })
+    });
+    group.bench_function("four-step", |b| {
+        b.iter(|| {
+            let mut v = values.clone();
+            four_step_ntt(black_box(&mut v)).unwrap();
+            v
+        })
+    });
+    group.finish();
+}
+
+fn bench_permutations(c: &mut Criterion) {
+    let state: [GoldilocksField; 12] = core::array::from_fn(|i| GoldilocksField::new(X ^ i as u64));
+    let mut group = c.benchmark_group("permutation width 12");
+    group.bench_function("poseidon", |b| {
+        b.iter(|| {
+            let mut s = black_box(state);
+            poseidon::poseidon_permutation(&mut s);
+            s
+        })
+    });
+    group.bench_function("poseidon2", |b| {
+        b.iter(|| {
+            let mut s = black_box(state);
+            poseidon2::permute(&mut s);
+            s
+        })
+    });
+    group.bench_function("monolith", |b| {
+        b.iter(|| {
+            let mut s = black_box(state);
+            monolith::permutation(&mut s);
+            s
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_square,
+    bench_mul_small,
+    bench_extension_mul,
+    bench_karatsuba,
+    bench_inner_product,
+    bench_reducers,
+    bench_batch_inverse,
+    bench_fixed_base_pow,
+    bench_poly_mul,
+    bench_large_ntt,
+    bench_permutations
+);
+criterion_main!(benches);
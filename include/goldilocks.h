@@ -0,0 +1,112 @@
+#ifndef GOLDILOCKS_H
+#define GOLDILOCKS_H
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of a `goldilocks_*` call. Outputs are written only on `Ok`.
+ */
+typedef enum GoldilocksStatus {
+  /**
+   * The call succeeded.
+   */
+  GOLDILOCKS_STATUS_OK = 0,
+  /**
+   * A required pointer was null.
+   */
+  GOLDILOCKS_STATUS_NULL_POINTER = 1,
+  /**
+   * An input value was p or more.
+   */
+  GOLDILOCKS_STATUS_NON_CANONICAL = 2,
+  /**
+   * Zero was inverted.
+   */
+  GOLDILOCKS_STATUS_ZERO_INVERSE = 3,
+  /**
+   * The buffer length is not supported, e.g. an NTT length that is not a
+   * power of two or exceeds 2^32.
+   */
+  GOLDILOCKS_STATUS_INVALID_LENGTH = 4,
+  /**
+   * The library panicked; outputs are unspecified.
+   */
+  GOLDILOCKS_STATUS_PANIC = 5,
+} GoldilocksStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Writes (a + b) mod p to `*out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writing one `uint64_t`, aligned to 8
+ * bytes. It stays owned by the caller.
+ */
+GoldilocksStatus goldilocks_add(uint64_t a, uint64_t b, uint64_t *out);
+
+/**
+ * Writes (a - b) mod p to `*out`.
+ *
+ * # Safety
+ *
+ * As for `goldilocks_add`.
+ */
+GoldilocksStatus goldilocks_sub(uint64_t a, uint64_t b, uint64_t *out);
+
+/**
+ * Writes (a * b) mod p to `*out`.
+ *
+ * # Safety
+ *
+ * As for `goldilocks_add`.
+ */
+GoldilocksStatus goldilocks_mul(uint64_t a, uint64_t b, uint64_t *out);
+
+/**
+ * Writes a^-1 mod p to `*out`, or returns `ZeroInverse` for a = 0.
+ *
+ * # Safety
+ *
+ * As for `goldilocks_add`.
+ */
+GoldilocksStatus goldilocks_inv(uint64_t a, uint64_t *out);
+
+/**
+ * Replaces the `len` values at `values` by their NTT, or by their inverse
+ * NTT (including the scaling by 1/len) when `inverse` is true. Both sides
+ * are in natural order, as in `ntt::ntt_in_place`. `len` must be a power of
+ * two no larger than 2^32.
+ *
+ * # Safety
+ *
+ * `values` must be null or point to `len` initialized `uint64_t`s, aligned
+ * to 8 bytes, valid for reads and writes and not accessed by anything else
+ * until the call returns. The buffer stays owned by the caller.
+ */
+GoldilocksStatus goldilocks_ntt(uint64_t *values, uintptr_t len, bool inverse);
+
+/**
+ * Replaces each of the `len` values at `values` by its inverse, using one
+ * field inversion for the whole buffer. Returns `ZeroInverse`, leaving the
+ * buffer unchanged, if any value is zero. An empty buffer is accepted.
+ *
+ * # Safety
+ *
+ * As for `goldilocks_ntt`.
+ */
+GoldilocksStatus goldilocks_batch_inverse(uint64_t *values, uintptr_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* GOLDILOCKS_H */
@@ -0,0 +1,119 @@
+//! Internal representation of field elements.
+//!
+//! By default the stored u64 is the canonical residue itself. With the
+//! `montgomery` feature it is the Montgomery form x * 2^64 mod p, and
+//! multiplication uses REDC. Every value that crosses the public API goes
+//! through `to_repr`/`from_repr`, so external behavior is identical.
+
+#[cfg(not(feature = "montgomery"))]
+mod imp {
+    use crate::canonicalize;
+    use crate::reduction::{ActiveReducer, Reducer};
+
+    /// Internal representation of one.
+    pub(crate) const ONE: u64 = 1;
+
+    /// Converts any u64 into the internal representation of its residue.
+    pub(crate) const fn to_repr(x: u64) -> u64 {
+        canonicalize(x)
+    }
+
+    /// Converts an internal value back to the canonical residue.
+    pub(crate) const fn from_repr(x: u64) -> u64 {
+        x
+    }
+
+    pub(crate) fn mul(a: u64, b: u64) -> u64 {
+        ActiveReducer::mul(a, b)
+    }
+
+    /// a * b + c with a single reduction; at most p(p - 1) < 2^128.
+    pub(crate) fn mul_add(a: u64, b: u64, c: u64) -> u64 {
+        ActiveReducer::reduce_u128(a as u128 * b as u128 + c as u128)
+    }
+
+    /// Maps sum(a_i * b_i) mod p, computed on internal values, to the internal
+    /// value of the sum of products.
+    pub(crate) fn from_product_sum(x: u64) -> u64 {
+        x
+    }
+}
+
+#[cfg(feature = "montgomery")]
+mod imp {
+    use crate::MODULUS;
+
+    /// p' = p^-1 mod 2^64 = 2^32 + 1, for the subtractive REDC variant.
+    const P_INV: u64 = 0x0000_0001_0000_0001;
+    /// R^2 mod p with R = 2^64, used to convert into Montgomery form.
+    const R2: u64 = 0xffff_fffe_0000_0001;
+
+    /// Internal representation of one, R mod p = 2^32 - 1.
+    pub(crate) const ONE: u64 = 0xffff_ffff;
+
+    /// Montgomery reduction: x * 2^-64 mod p for x < p * 2^64. With
+    /// m = x_lo * p^-1, x - m * p is divisible by 2^64 and the quotient is
+    /// x_hi - hi(m * p), which lies in (-p, p).
+    pub(crate) const fn redc(x: u128) -> u64 {
+        let x_lo = x as u64;
+        let x_hi = (x >> 64) as u64;
+        let m = x_lo.wrapping_mul(P_INV);
+        let mp_hi = ((m as u128 * MODULUS as u128) >> 64) as u64;
+        let (r, borrow) = x_hi.overflowing_sub(mp_hi);
+        r.wrapping_add(MODULUS * borrow as u64)
+    }
+
+    /// Converts any u64 into the Montgomery form of its residue.
+    pub(crate) const fn to_repr(x: u64) -> u64 {
+        redc(x as u128 * R2 as u128)
+    }
+
+    /// Converts a Montgomery form value back to the canonical residue.
+    pub(crate) const fn from_repr(x: u64) -> u64 {
+        redc(x as u128)
+    }
+
+    pub(crate) fn mul(a: u64, b: u64) -> u64 {
+        redc(a as u128 * b as u128)
+    }
+
+    /// a * b + c. The Montgomery product needs its own reduction, so unlike the
+    /// default backend this reduces twice.
+    pub(crate) fn mul_add(a: u64, b: u64, c: u64) -> u64 {
+        let (sum, over) = mul(a, b).overflowing_add(c);
+        let sum = sum.wrapping_add(crate::EPSILON * over as u64);
+        crate::canonicalize(sum)
+    }
+
+    /// sum(aR * bR) mod p = (sum a * b) R^2, one REDC away from Montgomery form.
+    pub(crate) fn from_product_sum(x: u64) -> u64 {
+        redc(x as u128)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn montgomery_round_trip() {
+            for x in [0, 1, 2, 7, crate::EPSILON, 1 << 63, MODULUS - 1] {
+                assert_eq!(from_repr(to_repr(x)), x);
+            }
+            assert_eq!(to_repr(1), ONE);
+            assert_eq!(to_repr(MODULUS), 0);
+        }
+
+        #[test]
+        fn montgomery_mul_matches_reference() {
+            let mut x: u64 = 0x0123_4567_89ab_cdef;
+            for _ in 0..10_000 {
+                x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                let (a, b) = (x % MODULUS, x.rotate_left(29) % MODULUS);
+                let expected = ((a as u128 * b as u128) % MODULUS as u128) as u64;
+                assert_eq!(from_repr(mul(to_repr(a), to_repr(b))), expected);
+            }
+        }
+    }
+}
+
+pub(crate) use imp::*;
@@ -0,0 +1,115 @@
+//! `borsh` support, behind the `borsh` feature.
+//!
+//! An element is its canonical value as 8 little-endian bytes, and an extension
+//! element is its coefficients in that form, lowest degree first, with no
+//! length prefix. Decoding rejects values ≥ p, so every element has exactly one
+//! encoding, as borsh expects.
+
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{GoldilocksCubicExtension, GoldilocksField, GoldilocksQuadraticExtension, GoldilocksQuinticExtension, MODULUS};
+
+impl BorshSerialize for GoldilocksField {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.to_canonical_u64().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for GoldilocksField {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let value = u64::deserialize_reader(reader)?;
+        if value >= MODULUS {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("non-canonical Goldilocks element {value:#x}: must be below {MODULUS:#x}"),
+            ));
+        }
+        Ok(GoldilocksField::from_canonical(value))
+    }
+}
+
+macro_rules! impl_extension_borsh {
+    ($ty:ident) => {
+        impl BorshSerialize for $ty {
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.coefficients().serialize(writer)
+            }
+        }
+
+        impl BorshDeserialize for $ty {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                BorshDeserialize::deserialize_reader(reader).map(Self::new)
+            }
+        }
+    };
+}
+
+impl_extension_borsh!(GoldilocksQuadraticExtension);
+impl_extension_borsh!(GoldilocksCubicExtension);
+impl_extension_borsh!(GoldilocksQuinticExtension);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+
+    fn round_trip<T: BorshSerialize + BorshDeserialize + PartialEq + core::fmt::Debug>(value: &T, len: usize) {
+        let bytes = borsh::to_vec(value).unwrap();
+        assert_eq!(bytes.len(), len);
+        assert_eq!(&borsh::from_slice::<T>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut rng = SplitMix64(0xb025);
+        let elements: Vec<GoldilocksField> = (0..100).map(|_| rng.next_field()).collect();
+        for x in &elements {
+            round_trip(x, 8);
+        }
+        round_trip(&GoldilocksField::new(MODULUS - 1), 8);
+        round_trip(&elements, 4 + 8 * elements.len());
+        round_trip(&Vec::<GoldilocksField>::new(), 4);
+
+        round_trip(&GoldilocksQuadraticExtension::new([rng.next_field(), rng.next_field()]), 16);
+        round_trip(&GoldilocksCubicExtension::new([(); 3].map(|_| rng.next_field())), 24);
+        round_trip(&GoldilocksQuinticExtension::new([(); 5].map(|_| rng.next_field())), 40);
+        let quintics: Vec<_> = (0..10).map(|_| GoldilocksQuinticExtension::new([(); 5].map(|_| rng.next_field()))).collect();
+        round_trip(&quintics, 4 + 40 * quintics.len());
+    }
+
+    #[test]
+    fn byte_layout_is_pinned() {
+        assert_eq!(
+            borsh::to_vec(&GoldilocksField::new(0x0123_4567_89ab_cdef)).unwrap(),
+            [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]
+        );
+        let column = vec![GoldilocksField::ONE, GoldilocksField::new(MODULUS - 1)];
+        assert_eq!(
+            borsh::to_vec(&column).unwrap(),
+            [2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]
+        );
+        let x = GoldilocksQuadraticExtension::new([GoldilocksField::new(1), GoldilocksField::new(2)]);
+        assert_eq!(borsh::to_vec(&x).unwrap(), [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn rejects_non_canonical_and_truncated_input() {
+        for value in [MODULUS, u64::MAX] {
+            let err = borsh::from_slice::<GoldilocksField>(&value.to_le_bytes()).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+            assert!(err.to_string().contains("non-canonical"), "{err}");
+        }
+
+        let mut bytes = borsh::to_vec(&GoldilocksCubicExtension::ONE).unwrap();
+        bytes[16..].copy_from_slice(&MODULUS.to_le_bytes());
+        assert!(borsh::from_slice::<GoldilocksCubicExtension>(&bytes).is_err());
+
+        let mut bytes = borsh::to_vec(&vec![GoldilocksField::ONE; 3]).unwrap();
+        bytes[12..20].copy_from_slice(&MODULUS.to_le_bytes());
+        assert!(borsh::from_slice::<Vec<GoldilocksField>>(&bytes).is_err());
+
+        assert!(borsh::from_slice::<GoldilocksField>(&[0; 7]).is_err());
+        assert!(borsh::from_slice::<GoldilocksField>(&[0; 9]).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+//! The EcGFp5 group: the double-odd curve y^2 = x (x^2 + 2 x + 263 z) over
+//! Fp^5 = Fp[z] / (z^5 - 3), with a prime-order group of about 2^319
+//! elements.
+
+use crate::{GoldilocksField, GoldilocksQuinticExtension};
+
+type Fp5 = GoldilocksQuinticExtension;
+
+const fn base(x: u64) -> GoldilocksField {
+    GoldilocksField::from_canonical(x)
+}
+
+/// The curve coefficient a = 2.
+pub const A: Fp5 = Fp5::from_base(base(2));
+/// The curve coefficient b = 263 z.
+pub const B: Fp5 = Fp5::new([base(0), base(263), base(0), base(0), base(0)]);
+
+/// The curve has 2 * ORDER points; ORDER is the prime order of the group.
+/// Little-endian 64-bit limbs.
+pub const ORDER: [u64; 5] = [0xe80f_d996_948b_ffe1, 0xe888_5c39_d724_a09c, 0x7fff_ffe6_cfb8_0639, 0x7fff_fff1_0000_0016, 0x7fff_fffd_8000_0007];
+
+/// A group element, stored as an affine point of odd order on the curve, or
+/// the point at infinity.
+///
+/// EcGFp5 elements are defined on the coset of the odd-order subgroup that
+/// contains N = (0, 0), with neutral element N and law P + Q + N; P -> P + N
+/// maps the odd-order points P used here onto that coset, so the points here
+/// add with the ordinary curve law and only `encode`/`decode` see the coset.
+/// The odd-order points are those whose x is a square in Fp^5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    x: Fp5,
+    y: Fp5,
+    is_identity: bool,
+}
+
+impl Point {
+    pub const IDENTITY: Point = Point { x: Fp5::ZERO, y: Fp5::ZERO, is_identity: true };
+
+    /// The conventional generator, the element with encoding w = 4, the
+    /// smallest integer that decodes.
+    pub const GENERATOR: Point = Point {
+        x: Fp5::new([
+            base(0x4d35_e870_30bb_ac6e),
+            base(0xc38a_8876_7c92_c15d),
+            base(0xb728_0d74_d925_02f6),
+            base(0xe1f0_ea37_02bb_3d73),
+            base(0xde05_8002_37da_ddf0),
+        ]),
+        y: Fp5::new([
+            base(0xcb28_5e3d_3d11_4e4a),
+            base(0xf1d5_de22_0db4_fa90),
+            base(0x235f_ca29_9b6b_f42b),
+            base(0x783c_571f_f513_0a38),
+            base(0x87e9_fff3_2094_8844),
+        ]),
+        is_identity: false,
+    };
+
+    /// Returns the point (x, y) if it lies on the curve and in the group.
+    pub fn from_affine(x: Fp5, y: Fp5) -> Option<Self> {
+        let point = Point { x, y, is_identity: false };
+        (point.is_on_curve() && x.is_square()).then_some(point)
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.is_identity
+    }
+
+    /// Whether y^2 = x (x^2 + a x + b); the identity counts as on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        self.is_identity || self.y.square() == self.x * (self.x.square() + A * self.x + B)
+    }
+
+    /// Returns (x, y), or `None` for the identity.
+    pub fn to_affine(&self) -> Option<(Fp5, Fp5)> {
+        (!self.is_identity).then_some((self.x, self.y))
+    }
+
+    pub fn double(&self) -> Self {
+        // The only point with y = 0 is N, which has even order.
+        if self.is_identity || self.y == Fp5::ZERO {
+            return Self::IDENTITY;
+        }
+        let x_squared = self.x.square();
+        let numerator = x_squared + x_squared + x_squared + (A + A) * self.x + B;
+        let lambda = numerator / (self.y + self.y);
+        self.chord(lambda, &self.x)
+    }
+
+    /// Returns k * self for the scalar k = sum scalar[i] * 2^(64 i).
+    pub fn mul_scalar(&self, scalar: &[u64]) -> Self {
+        let mut acc = Self::IDENTITY;
+        for limb in scalar.iter().rev() {
+            for bit in (0..64).rev() {
+                acc = acc.double();
+                if (limb >> bit) & 1 == 1 {
+                    acc = acc + *self;
+                }
+            }
+        }
+        acc
+    }
+
+    /// Returns the compressed encoding w: 0 for the identity, and otherwise
+    /// w = y' / x' for the coset representative (x', y') = P + N, which is
+    /// -y / x.
+    pub fn encode(&self) -> Fp5 {
+        if self.is_identity {
+            return Fp5::ZERO;
+        }
+        -(self.y / self.x)
+    }
+
+    /// Inverts `encode`, returning `None` if w encodes no group element.
+    ///
+    /// The curve points with y = w x have x^2 - (w^2 - a) x + b = 0. The two
+    /// roots multiply to b, which is not a square, so exactly one of them is a
+    /// square; it is the odd-order point's x, and y = -w x.
+    pub fn decode(w: &Fp5) -> Option<Self> {
+        if *w == Fp5::ZERO {
+            return Some(Self::IDENTITY);
+        }
+        let e = w.square() - A;
+        let delta = e.square() - (B + B + B + B);
+        let root = delta.sqrt()?;
+        let half = Fp5::from_base(GoldilocksField::ONE.halve());
+        let x = (e + root) * half;
+        let x = if x.is_square() { x } else { (e - root) * half };
+        Some(Point { x, y: -(*w * x), is_identity: false })
+    }
+
+    /// Third intersection of the line of slope lambda through self and
+    /// (other_x, _), reflected.
+    fn chord(&self, lambda: Fp5, other_x: &Fp5) -> Self {
+        let x = lambda.square() - A - self.x - *other_x;
+        let y = lambda * (self.x - x) - self.y;
+        Point { x, y, is_identity: false }
+    }
+}
+
+impl core::ops::Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if self.is_identity {
+            return rhs;
+        }
+        if rhs.is_identity {
+            return self;
+        }
+        if self.x == rhs.x {
+            return if self.y == rhs.y { self.double() } else { Self::IDENTITY };
+        }
+        let lambda = (rhs.y - self.y) / (rhs.x - self.x);
+        self.chord(lambda, &rhs.x)
+    }
+}
+
+impl core::ops::Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl core::ops::Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point { y: -self.y, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+
+    fn fp5(limbs: [u64; 5]) -> Fp5 {
+        Fp5::new(limbs.map(GoldilocksField::new))
+    }
+
+    fn random_point(rng: &mut SplitMix64) -> Point {
+        Point::GENERATOR.mul_scalar(&[rng.next_u64(), rng.next_u64()])
+    }
+
+    /// ORDER + delta for a small signed delta.
+    fn order_plus(delta: i64) -> [u64; 5] {
+        let mut limbs = ORDER;
+        limbs[0] = limbs[0].wrapping_add_signed(delta);
+        limbs
+    }
+
+    #[test]
+    fn generator_matches_the_published_parameters() {
+        let g = Point::GENERATOR;
+        assert!(g.is_on_curve());
+        assert_eq!(g.encode(), Fp5::from_base(GoldilocksField::new(4)));
+        // The published generator is the coset point G + N, whose x is b / x.
+        let (x, _) = g.to_affine().unwrap();
+        assert_eq!(
+            B / x,
+            fp5([12883135586176881569, 4356519642755055268, 5248930565894896907, 2165973894480315022, 2448410071095648785])
+        );
+        // 1, 2 and 3 encode nothing, so 4 is the smallest integer encoding.
+        for w in 1..4 {
+            assert_eq!(Point::decode(&Fp5::from_base(GoldilocksField::new(w))), None);
+        }
+        assert_eq!(Point::decode(&Fp5::from_base(GoldilocksField::new(4))), Some(g));
+    }
+
+    #[test]
+    fn b_is_not_a_square() {
+        assert!(!B.is_square());
+        assert!(A.is_square());
+    }
+
+    #[test]
+    fn generator_has_the_group_order() {
+        let g = Point::GENERATOR;
+        assert!(g.mul_scalar(&ORDER).is_identity());
+        assert_eq!(g.mul_scalar(&order_plus(-1)), -g);
+        assert_eq!(g.mul_scalar(&order_plus(1)), g);
+        assert_eq!(g.mul_scalar(&order_plus(2)), g.double());
+        assert_eq!(g.mul_scalar(&[]), Point::IDENTITY);
+        assert_eq!(g.mul_scalar(&[1]), g);
+    }
+
+    #[test]
+    fn matches_the_reference_test_vectors() {
+        // From Pornin's ecgfp5 implementation: w1 and w2 encode points P1 and
+        // P2, and w3 to w6 encode P1 + P2, 2 P1, 2 P2 and 2 P1 + P2.
+        let w: [[u64; 5]; 6] = [
+            [12539254003028696409, 15524144070600887654, 15092036948424041984, 11398871370327264211, 10958391180505708567],
+            [11001943240060308920, 17075173755187928434, 3940989555384655766, 15017795574860011099, 5548543797011402287],
+            [246872606398642312, 4900963247917836450, 7327006728177203977, 13945036888436667069, 3062018119121328861],
+            [8058035104653144162, 16041715455419993830, 7448530016070824199, 11253639182222911208, 6228757819849640866],
+            [10523134687509281194, 11148711503117769087, 9056499921957594891, 13016664454465495026, 16494247923890248266],
+            [12173306542237620, 6587231965341539782, 17027985748515888117, 17194831817613584995, 10056734072351459010],
+        ];
+        let [w1, w2, w3, w4, w5, w6] = w.map(fp5);
+        assert_eq!(Point::IDENTITY.encode(), Fp5::ZERO);
+        assert_eq!(Point::decode(&Fp5::ZERO), Some(Point::IDENTITY));
+        let p1 = Point::decode(&w1).unwrap();
+        let p2 = Point::decode(&w2).unwrap();
+        assert_eq!((p1 + p2).encode(), w3);
+        assert_eq!(p1.double().encode(), w4);
+        assert_eq!(p1.mul_scalar(&[2]).encode(), w4);
+        assert_eq!(p2.double().encode(), w5);
+        assert_eq!((p1.double() + p2).encode(), w6);
+        assert_eq!((p1.mul_scalar(&[3]) - p1 + p2).encode(), w6);
+        for w in [w1, w2, w3, w4, w5, w6] {
+            assert_eq!(Point::decode(&w).unwrap().encode(), w);
+        }
+    }
+
+    #[test]
+    fn group_law_holds_on_random_points() {
+        let mut rng = SplitMix64(0xec5);
+        for _ in 0..20 {
+            let (p, q, r) = (random_point(&mut rng), random_point(&mut rng), random_point(&mut rng));
+            assert!(p.is_on_curve() && q.is_on_curve() && r.is_on_curve());
+            assert_eq!((p + q) + r, p + (q + r));
+            assert_eq!(p + q, q + p);
+            assert_eq!(p + p, p.double());
+            assert_eq!(p - p, Point::IDENTITY);
+            assert_eq!(p + Point::IDENTITY, p);
+            assert_eq!((p + q) - q, p);
+        }
+        assert_eq!(Point::IDENTITY.double(), Point::IDENTITY);
+        assert_eq!(-Point::IDENTITY, Point::IDENTITY);
+    }
+
+    #[test]
+    fn scalar_multiplication_is_linear() {
+        let mut rng = SplitMix64(0xec6);
+        let g = Point::GENERATOR;
+        for _ in 0..10 {
+            let (a, b) = (rng.next_u64() >> 1, rng.next_u64() >> 1);
+            assert_eq!(g.mul_scalar(&[a]) + g.mul_scalar(&[b]), g.mul_scalar(&[a + b]));
+            let p = g.mul_scalar(&[a]);
+            assert_eq!(p.mul_scalar(&[b]), g.mul_scalar(&[b]).mul_scalar(&[a]));
+        }
+    }
+
+    #[test]
+    fn encoding_round_trips() {
+        let mut rng = SplitMix64(0xec7);
+        for _ in 0..20 {
+            let p = random_point(&mut rng);
+            assert_eq!(Point::decode(&p.encode()), Some(p));
+            assert_eq!((-p).encode(), -p.encode());
+        }
+        assert_eq!(Point::IDENTITY.encode(), Fp5::ZERO);
+        assert_eq!(Point::decode(&Fp5::ZERO), Some(Point::IDENTITY));
+
+        // About half of all w decode; every decoded point is in the group.
+        let mut failures = 0;
+        for _ in 0..100 {
+            let w = Fp5::new(core::array::from_fn(|_| rng.next_field()));
+            match Point::decode(&w) {
+                Some(p) => {
+                    assert!(p.is_on_curve());
+                    assert!(p.mul_scalar(&ORDER).is_identity());
+                    assert_eq!(p.encode(), w);
+                }
+                None => failures += 1,
+            }
+        }
+        assert!((20..80).contains(&failures));
+    }
+
+    #[test]
+    fn from_affine_rejects_points_off_the_group() {
+        let (x, y) = Point::GENERATOR.to_affine().unwrap();
+        assert_eq!(Point::from_affine(x, y), Some(Point::GENERATOR));
+        assert_eq!(Point::from_affine(x, y + Fp5::ONE), None);
+        // The coset representative G + N is on the curve but has even order.
+        let coset_x = B / x;
+        let coset_y = -(B * y) / x.square();
+        assert!(Point { x: coset_x, y: coset_y, is_identity: false }.is_on_curve());
+        assert_eq!(Point::from_affine(coset_x, coset_y), None);
+    }
+}
@@ -0,0 +1,309 @@
+//! Contiguous byte encodings of element slices.
+//!
+//! Each element is its canonical value as 8 little-endian bytes, with no
+//! header: the number of elements is the byte length divided by 8. The same
+//! layout can be streamed through `std::io` with `write_elements`,
+//! `read_elements` and `ElementReader`.
+
+use std::io;
+
+use crate::{GoldilocksField, MODULUS};
+
+/// Bytes per encoded element.
+pub const ELEMENT_SIZE: usize = 8;
+
+/// Error returned when decoding a byte buffer into elements fails.
+///
+/// Unlike lambdaworks' `DeserializationError`, it says where decoding failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeserializationError {
+    /// The byte length is not a multiple of `ELEMENT_SIZE`.
+    InvalidLength(usize),
+    /// The element at `index` encodes `value`, which is not below p.
+    NonCanonical { index: usize, value: u64 },
+}
+
+impl core::fmt::Display for DeserializationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeserializationError::InvalidLength(len) => {
+                write!(f, "byte length {len} is not a multiple of {ELEMENT_SIZE}")
+            }
+            DeserializationError::NonCanonical { index, value } => {
+                write!(f, "element {index} encodes {value:#x}, which is not below {MODULUS:#x}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeserializationError {}
+
+/// Appends the encoding of `elems` to `out`, reserving the space up front.
+pub fn serialize_elements(elems: &[GoldilocksField], out: &mut Vec<u8>) {
+    out.reserve(ELEMENT_SIZE * elems.len());
+    for x in elems {
+        out.extend_from_slice(&x.to_canonical_u64().to_le_bytes());
+    }
+}
+
+/// Decodes a buffer written by `serialize_elements`, rejecting buffers whose
+/// length is not a multiple of 8 and elements that are not canonical.
+pub fn deserialize_elements(bytes: &[u8]) -> Result<Vec<GoldilocksField>, DeserializationError> {
+    if bytes.len() % ELEMENT_SIZE != 0 {
+        return Err(DeserializationError::InvalidLength(bytes.len()));
+    }
+    bytes
+        .chunks_exact(ELEMENT_SIZE)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let value = u64::from_le_bytes(chunk.try_into().unwrap());
+            if value >= MODULUS {
+                return Err(DeserializationError::NonCanonical { index, value });
+            }
+            Ok(GoldilocksField::from_canonical(value))
+        })
+        .collect()
+}
+
+/// Elements encoded per write or read call by the streaming functions.
+const CHUNK: usize = 512;
+
+/// Writes the encoding of `elems` to `w`, a chunk of elements per write call.
+pub fn write_elements<W: io::Write>(w: &mut W, elems: &[GoldilocksField]) -> io::Result<()> {
+    let mut buf = [0u8; ELEMENT_SIZE * CHUNK];
+    for chunk in elems.chunks(CHUNK) {
+        for (x, bytes) in chunk.iter().zip(buf.chunks_exact_mut(ELEMENT_SIZE)) {
+            bytes.copy_from_slice(&x.to_canonical_u64().to_le_bytes());
+        }
+        w.write_all(&buf[..ELEMENT_SIZE * chunk.len()])?;
+    }
+    Ok(())
+}
+
+/// Reads exactly `count` elements from `r`.
+///
+/// Fails with `UnexpectedEof` if the input ends first, and with `InvalidData`
+/// wrapping a `DeserializationError` if an element is not canonical. Memory is
+/// allocated as data arrives, so a bogus `count` cannot exhaust it up front.
+pub fn read_elements<R: io::Read>(r: &mut R, count: usize) -> io::Result<Vec<GoldilocksField>> {
+    let mut elems = Vec::with_capacity(count.min(CHUNK));
+    let mut buf = [0u8; ELEMENT_SIZE * CHUNK];
+    while elems.len() < count {
+        let n = (count - elems.len()).min(CHUNK);
+        let bytes = &mut buf[..ELEMENT_SIZE * n];
+        r.read_exact(bytes)?;
+        for chunk in bytes.chunks_exact(ELEMENT_SIZE) {
+            elems.push(decode(chunk.try_into().unwrap(), elems.len())?);
+        }
+    }
+    Ok(elems)
+}
+
+fn decode(bytes: [u8; ELEMENT_SIZE], index: usize) -> io::Result<GoldilocksField> {
+    let value = u64::from_le_bytes(bytes);
+    if value >= MODULUS {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, DeserializationError::NonCanonical { index, value }));
+    }
+    Ok(GoldilocksField::from_canonical(value))
+}
+
+/// Iterator over the elements encoded in a reader, until end of input.
+///
+/// Input that ends on an element boundary ends the iteration cleanly; input
+/// that ends inside an element yields an `UnexpectedEof` error. A
+/// non-canonical element yields an `InvalidData` error. Iteration stops after
+/// the first error. Each element is a separate read of at most 8 bytes, so
+/// wrap unbuffered readers in a `BufReader`.
+#[derive(Debug)]
+pub struct ElementReader<R> {
+    reader: R,
+    index: usize,
+    failed: bool,
+}
+
+impl<R: io::Read> ElementReader<R> {
+    pub fn new(reader: R) -> Self {
+        ElementReader { reader, index: 0, failed: false }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Fills `buf`, returning false on end of input before its first byte.
+    fn fill(&mut self, buf: &mut [u8; ELEMENT_SIZE]) -> io::Result<bool> {
+        let mut filled = 0;
+        while filled < ELEMENT_SIZE {
+            match self.reader.read(&mut buf[filled..]) {
+                Ok(0) if filled == 0 => return Ok(false),
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("input ends {filled} bytes into element {}", self.index),
+                    ))
+                }
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<R: io::Read> Iterator for ElementReader<R> {
+    type Item = io::Result<GoldilocksField>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let mut buf = [0u8; ELEMENT_SIZE];
+        let result = match self.fill(&mut buf) {
+            Ok(false) => return None,
+            Ok(true) => decode(buf, self.index),
+            Err(e) => Err(e),
+        };
+        self.index += 1;
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use std::io::Cursor;
+
+    /// Hands out at most 3 bytes per read, with an interruption before each.
+    struct Trickle<R> {
+        inner: R,
+        interrupt: bool,
+    }
+
+    impl<R: io::Read> io::Read for Trickle<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let len = buf.len().min(3);
+            self.inner.read(&mut buf[..len])
+        }
+    }
+
+    fn encoded(n: usize) -> (Vec<GoldilocksField>, Vec<u8>) {
+        let mut rng = SplitMix64(92);
+        let elems: Vec<_> = (0..n).map(|_| rng.next_field()).collect();
+        let mut bytes = Vec::new();
+        write_elements(&mut bytes, &elems).unwrap();
+        (elems, bytes)
+    }
+
+    fn round_trip(elems: &[GoldilocksField]) {
+        let mut bytes = Vec::new();
+        serialize_elements(elems, &mut bytes);
+        assert_eq!(bytes.len(), ELEMENT_SIZE * elems.len());
+        assert_eq!(deserialize_elements(&bytes).unwrap(), elems);
+    }
+
+    #[test]
+    fn round_trips_empty_single_and_large_slices() {
+        round_trip(&[]);
+        round_trip(&[GoldilocksField::new(MODULUS - 1)]);
+        let mut rng = SplitMix64(91);
+        let elems: Vec<_> = (0..1_000_000).map(|_| rng.next_field()).collect();
+        round_trip(&elems);
+    }
+
+    #[test]
+    fn layout_is_little_endian_and_appends() {
+        let mut bytes = vec![0xaa];
+        serialize_elements(&[GoldilocksField::new(0x0102_0304_0506_0708), GoldilocksField::ONE], &mut bytes);
+        assert_eq!(bytes, [0xaa, 8, 7, 6, 5, 4, 3, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn rejects_corrupted_buffers() {
+        let mut rng = SplitMix64(0xc0);
+        let elems: Vec<_> = (0..1000).map(|_| rng.next_field()).collect();
+        let mut bytes = Vec::new();
+        serialize_elements(&elems, &mut bytes);
+
+        for len in [1, 7, bytes.len() - 1, bytes.len() + 3] {
+            let mut truncated = bytes.clone();
+            truncated.resize(len, 0);
+            assert_eq!(deserialize_elements(&truncated), Err(DeserializationError::InvalidLength(len)));
+        }
+
+        bytes[8 * 417..8 * 418].copy_from_slice(&MODULUS.to_le_bytes());
+        bytes[8 * 900..8 * 901].copy_from_slice(&u64::MAX.to_le_bytes());
+        let err = deserialize_elements(&bytes).unwrap_err();
+        assert_eq!(err, DeserializationError::NonCanonical { index: 417, value: MODULUS });
+        assert_eq!(err.to_string(), "element 417 encodes 0xffffffff00000001, which is not below 0xffffffff00000001");
+    }
+
+    #[test]
+    fn streaming_matches_the_batch_encoding() {
+        let (elems, bytes) = encoded(2 * CHUNK + 7);
+        let mut batch = Vec::new();
+        serialize_elements(&elems, &mut batch);
+        assert_eq!(bytes, batch);
+
+        assert_eq!(read_elements(&mut Cursor::new(&bytes), elems.len()).unwrap(), elems);
+        let mut cursor = Cursor::new(&bytes);
+        assert_eq!(read_elements(&mut cursor, 10).unwrap(), elems[..10]);
+        assert_eq!(read_elements(&mut cursor, 0).unwrap(), []);
+        assert_eq!(read_elements(&mut cursor, elems.len() - 10).unwrap(), elems[10..]);
+
+        let read: Vec<_> = ElementReader::new(Cursor::new(&bytes)).map(Result::unwrap).collect();
+        assert_eq!(read, elems);
+        let trickled = Trickle { inner: Cursor::new(&bytes), interrupt: false };
+        let read: Vec<_> = ElementReader::new(trickled).map(Result::unwrap).collect();
+        assert_eq!(read, elems);
+    }
+
+    #[test]
+    fn truncated_input_fails_mid_record_only() {
+        let (elems, bytes) = encoded(100);
+        let err = read_elements(&mut Cursor::new(&bytes[..8 * 99]), 100).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        let err = read_elements(&mut Cursor::new(&bytes[..8 * 99 + 5]), 100).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        // Ending on a record boundary is a clean end of input.
+        let read: Vec<_> = ElementReader::new(Cursor::new(&bytes[..8 * 60])).map(Result::unwrap).collect();
+        assert_eq!(read, elems[..60]);
+        assert_eq!(ElementReader::new(Cursor::new(&[])).count(), 0);
+
+        let mut reader = ElementReader::new(Cursor::new(&bytes[..8 * 60 + 3]));
+        for x in &elems[..60] {
+            assert_eq!(reader.next().unwrap().unwrap(), *x);
+        }
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(err.to_string(), "input ends 3 bytes into element 60");
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn corrupted_input_reports_the_element() {
+        let (elems, mut bytes) = encoded(1000);
+        bytes[8 * 700..8 * 701].copy_from_slice(&MODULUS.to_le_bytes());
+        let expected = DeserializationError::NonCanonical { index: 700, value: MODULUS };
+
+        let err = read_elements(&mut Cursor::new(&bytes), 1000).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.into_inner().unwrap().downcast_ref::<DeserializationError>(), Some(&expected));
+
+        let mut reader = ElementReader::new(Cursor::new(&bytes));
+        let read: Vec<_> = reader.by_ref().take(700).map(Result::unwrap).collect();
+        assert_eq!(read, elems[..700]);
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.into_inner().unwrap().downcast_ref::<DeserializationError>(), Some(&expected));
+        assert!(reader.next().is_none());
+    }
+}
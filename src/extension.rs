@@ -0,0 +1,379 @@
+//! Extension fields of Goldilocks.
+
+mod cubic;
+mod quadratic;
+mod quintic;
+
+pub use cubic::GoldilocksCubicExtension;
+pub use quadratic::GoldilocksQuadraticExtension;
+pub use quintic::GoldilocksQuinticExtension;
+
+/// `IsSubFieldOf` for `GoldilocksField` in an extension type as in
+/// `impl_extension_boilerplate`, so that lambdaworks `FieldElement`s of the
+/// base field combine with extension elements directly: the base element is
+/// embedded as a constant polynomial and the operations act on coefficients.
+macro_rules! impl_subfield {
+    ($ty:ident, $degree:expr) => {
+        impl lambdaworks_math::field::traits::IsSubFieldOf<$ty> for GoldilocksField {
+            fn mul(a: &u64, b: &[GoldilocksField; $degree]) -> [GoldilocksField; $degree] {
+                let a = GoldilocksField { value: *a };
+                b.map(|c| a * c)
+            }
+
+            fn add(a: &u64, b: &[GoldilocksField; $degree]) -> [GoldilocksField; $degree] {
+                let mut sum = *b;
+                sum[0] = GoldilocksField { value: *a } + sum[0];
+                sum
+            }
+
+            fn div(a: &u64, b: &[GoldilocksField; $degree]) -> [GoldilocksField; $degree] {
+                <$ty as IsField>::div(&$ty::from_base(GoldilocksField { value: *a }).value, b)
+            }
+
+            fn sub(a: &u64, b: &[GoldilocksField; $degree]) -> [GoldilocksField; $degree] {
+                let mut difference = <$ty as IsField>::neg(b);
+                difference[0] = GoldilocksField { value: *a } + difference[0];
+                difference
+            }
+
+            fn embed(a: u64) -> [GoldilocksField; $degree] {
+                $ty::from_base(GoldilocksField { value: a }).value
+            }
+
+            fn to_subfield_vec(b: [GoldilocksField; $degree]) -> Vec<u64> {
+                b.iter().map(|c| c.value).collect()
+            }
+        }
+    };
+}
+
+use impl_subfield;
+
+/// Operators, byte conversion and serialization for an extension type with a
+/// `value: [GoldilocksField; $degree]` field whose `IsField::BaseType` is that
+/// array. Encodings are the coefficients in increasing degree, each as in
+/// `GoldilocksField`'s `ByteConversion`.
+macro_rules! impl_extension_boilerplate {
+    ($ty:ident, $degree:expr) => {
+        impl core::ops::Add for $ty {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $ty { value: <Self as IsField>::add(&self.value, &rhs.value) }
+            }
+        }
+
+        impl core::ops::Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $ty { value: <Self as IsField>::sub(&self.value, &rhs.value) }
+            }
+        }
+
+        impl core::ops::Mul for $ty {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                $ty { value: <Self as IsField>::mul(&self.value, &rhs.value) }
+            }
+        }
+
+        /// Multiplies every coefficient by a base-field scalar.
+        impl core::ops::Mul<GoldilocksField> for $ty {
+            type Output = Self;
+
+            fn mul(self, rhs: GoldilocksField) -> Self {
+                $ty { value: self.value.map(|c| c * rhs) }
+            }
+        }
+
+        impl core::ops::Div for $ty {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                $ty { value: <Self as IsField>::div(&self.value, &rhs.value) }
+            }
+        }
+
+        impl core::ops::Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $ty { value: <Self as IsField>::neg(&self.value) }
+            }
+        }
+
+        impl From<GoldilocksField> for $ty {
+            fn from(x: GoldilocksField) -> Self {
+                Self::from_base(x)
+            }
+        }
+
+        impl ByteConversion for $ty {
+            fn to_bytes_be(&self) -> Vec<u8> {
+                self.value.iter().flat_map(|c| c.to_bytes_be()).collect()
+            }
+
+            fn to_bytes_le(&self) -> Vec<u8> {
+                self.value.iter().flat_map(|c| c.to_bytes_le()).collect()
+            }
+
+            /// Reads the first 8 * degree bytes; shorter inputs are an error.
+            /// Coefficients >= p are reduced.
+            fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+                let bytes = bytes.get(..8 * $degree).ok_or(ByteConversionError::FromBEBytesError)?;
+                let mut value = [GoldilocksField::ZERO; $degree];
+                for (c, chunk) in value.iter_mut().zip(bytes.chunks_exact(8)) {
+                    *c = GoldilocksField::from_bytes_be(chunk)?;
+                }
+                Ok($ty { value })
+            }
+
+            /// Reads the first 8 * degree bytes; shorter inputs are an error.
+            /// Coefficients >= p are reduced.
+            fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+                let bytes = bytes.get(..8 * $degree).ok_or(ByteConversionError::FromLEBytesError)?;
+                let mut value = [GoldilocksField::ZERO; $degree];
+                for (c, chunk) in value.iter_mut().zip(bytes.chunks_exact(8)) {
+                    *c = GoldilocksField::from_bytes_le(chunk)?;
+                }
+                Ok($ty { value })
+            }
+        }
+
+        impl Deserializable for $ty {
+            /// Accepts exactly the `as_bytes` encodings: inputs that are not
+            /// 8 * degree bytes long fail with `InvalidAmountOfBytes`, and
+            /// coefficients >= p with `InvalidValue`.
+            fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError> {
+                Self::deserialize_coefficients(bytes, GoldilocksField::deserialize)
+            }
+        }
+
+        /// The little-endian coefficients, as `to_bytes_le`, like
+        /// `GoldilocksField`'s encoding. Changed in 0.2.0 from big-endian, see
+        /// `serialize_be`.
+        impl AsBytes for $ty {
+            fn as_bytes(&self) -> Vec<u8> {
+                self.to_bytes_le()
+            }
+        }
+
+        impl $ty {
+            /// The big-endian encoding `AsBytes` produced before 0.2.0.
+            pub fn serialize_be(&self) -> Vec<u8> {
+                self.to_bytes_be()
+            }
+
+            /// Reads the encoding written by `serialize_be`, with the same
+            /// length and canonicity checks as `Deserializable::deserialize`.
+            pub fn deserialize_be(bytes: &[u8]) -> Result<Self, DeserializationError> {
+                Self::deserialize_coefficients(bytes, GoldilocksField::deserialize_be)
+            }
+
+            fn deserialize_coefficients(
+                bytes: &[u8],
+                coefficient: fn(&[u8]) -> Result<GoldilocksField, DeserializationError>,
+            ) -> Result<Self, DeserializationError> {
+                if bytes.len() != 8 * $degree {
+                    return Err(DeserializationError::InvalidAmountOfBytes);
+                }
+                let mut value = [GoldilocksField::ZERO; $degree];
+                for (c, chunk) in value.iter_mut().zip(bytes.chunks_exact(8)) {
+                    *c = coefficient(chunk)?;
+                }
+                Ok($ty { value })
+            }
+        }
+    };
+}
+
+use impl_extension_boilerplate;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::{GoldilocksField, MODULUS};
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::traits::{IsField, IsSubFieldOf};
+    use lambdaworks_math::errors::DeserializationError;
+    use lambdaworks_math::traits::{AsBytes, Deserializable};
+
+    fn check_mixed_arithmetic<L: IsField>(rng: &mut SplitMix64, random: impl Fn(&mut SplitMix64) -> L::BaseType)
+    where
+        GoldilocksField: IsSubFieldOf<L>,
+    {
+        for _ in 0..200 {
+            let base = FieldElement::<GoldilocksField>::new(GoldilocksField::from_u64(rng.next_element()));
+            let ext = FieldElement::<L>::new(random(rng));
+            let lifted = base.to_extension::<L>();
+            assert_eq!(base * &ext, &lifted * &ext);
+            assert_eq!(base + &ext, &lifted + &ext);
+            assert_eq!(base - &ext, &lifted - &ext);
+            assert_eq!(base / &ext, &lifted * &ext.inv().unwrap());
+            // Dividing by a base element is multiplying by its lifted inverse.
+            let base_inv = base.inv().unwrap();
+            assert_eq!(&ext / &lifted, base_inv * &ext);
+            let coefficients = <GoldilocksField as IsSubFieldOf<L>>::to_subfield_vec(lifted.value().clone());
+            assert_eq!(coefficients[0], *base.value());
+            assert!(coefficients[1..].iter().all(|c| *c == GoldilocksField::zero()));
+        }
+        let zero = FieldElement::<GoldilocksField>::zero().to_extension::<L>();
+        let one = FieldElement::<GoldilocksField>::one().to_extension::<L>();
+        assert_eq!(zero, FieldElement::<L>::zero());
+        assert_eq!(one, FieldElement::<L>::one());
+        let ext = FieldElement::<L>::new(random(rng));
+        assert_eq!(FieldElement::<GoldilocksField>::one() * &ext, ext);
+        assert_eq!(FieldElement::<GoldilocksField>::zero() * &ext, FieldElement::<L>::zero());
+        assert_eq!(FieldElement::<GoldilocksField>::zero() + &ext, ext);
+    }
+
+    /// The Frobenius maps, norms and traces each extension defines inherently.
+    trait Conjugates: Copy + PartialEq + core::fmt::Debug + core::ops::Add<Output = Self> + core::ops::Mul<Output = Self> {
+        const DEGREE: usize;
+        fn random(rng: &mut SplitMix64) -> Self;
+        fn lift(x: GoldilocksField) -> Self;
+        fn pow_p(&self) -> Self;
+        fn conjugate(&self, power: usize) -> Self;
+        fn norm_of(&self) -> GoldilocksField;
+        fn trace_of(&self) -> GoldilocksField;
+    }
+
+    macro_rules! impl_conjugates {
+        ($ty:ident, $degree:literal) => {
+            impl Conjugates for $ty {
+                const DEGREE: usize = $degree;
+
+                fn random(rng: &mut SplitMix64) -> Self {
+                    $ty::new(core::array::from_fn(|_| rng.next_field()))
+                }
+
+                fn lift(x: GoldilocksField) -> Self {
+                    $ty::from_base(x)
+                }
+
+                fn pow_p(&self) -> Self {
+                    $ty::new(<$ty as IsField>::pow(&self.coefficients(), MODULUS))
+                }
+
+                fn conjugate(&self, power: usize) -> Self {
+                    self.frobenius(power)
+                }
+
+                fn norm_of(&self) -> GoldilocksField {
+                    self.norm()
+                }
+
+                fn trace_of(&self) -> GoldilocksField {
+                    self.trace()
+                }
+            }
+        };
+    }
+
+    impl_conjugates!(GoldilocksQuadraticExtension, 2);
+    impl_conjugates!(GoldilocksCubicExtension, 3);
+    impl_conjugates!(GoldilocksQuinticExtension, 5);
+
+    fn check_frobenius_norm_and_trace<E: Conjugates>(rng: &mut SplitMix64) {
+        let degree = E::DEGREE;
+        for _ in 0..50 {
+            let a = E::random(rng);
+            let mut b = a;
+            for _ in 0..degree {
+                b = b.conjugate(1);
+            }
+            assert_eq!(b, a);
+            assert_eq!(a.conjugate(degree), a);
+            assert_eq!(a.conjugate(1), a.pow_p());
+            assert_eq!(a.conjugate(degree + 1), a.conjugate(1));
+            let c = E::random(rng);
+            assert_eq!((a * c).conjugate(1), a.conjugate(1) * c.conjugate(1));
+        }
+        for _ in 0..200 {
+            let (a, b) = (E::random(rng), E::random(rng));
+            assert_eq!((a * b).norm_of(), a.norm_of() * b.norm_of());
+            assert_eq!((a + b).trace_of(), a.trace_of() + b.trace_of());
+            let conjugates = (1..degree).map(|k| a.conjugate(k));
+            assert_eq!(conjugates.clone().fold(a, |acc, c| acc * c), E::lift(a.norm_of()));
+            assert_eq!(conjugates.fold(a, |acc, c| acc + c), E::lift(a.trace_of()));
+        }
+        for _ in 0..20 {
+            let x = rng.next_field();
+            assert_eq!(E::lift(x).norm_of(), x.pow_u64(degree as u64));
+            assert_eq!(E::lift(x).trace_of(), x * GoldilocksField::new(degree as u64));
+        }
+    }
+
+    #[test]
+    fn frobenius_norm_and_trace_in_every_degree() {
+        let mut rng = SplitMix64(0x0f5);
+        check_frobenius_norm_and_trace::<GoldilocksQuadraticExtension>(&mut rng);
+        check_frobenius_norm_and_trace::<GoldilocksCubicExtension>(&mut rng);
+        check_frobenius_norm_and_trace::<GoldilocksQuinticExtension>(&mut rng);
+    }
+
+    #[test]
+    fn base_field_elements_mix_with_extension_elements() {
+        let mut rng = SplitMix64(0x5ab);
+        check_mixed_arithmetic::<GoldilocksQuadraticExtension>(&mut rng, |rng| [rng.next_field(), rng.next_field()]);
+        check_mixed_arithmetic::<GoldilocksCubicExtension>(&mut rng, |rng| core::array::from_fn(|_| rng.next_field()));
+        check_mixed_arithmetic::<GoldilocksQuinticExtension>(&mut rng, |rng| core::array::from_fn(|_| rng.next_field()));
+    }
+
+    #[test]
+    fn extension_serialization_is_little_endian_coefficients() {
+        let x = GoldilocksQuadraticExtension::new([GoldilocksField::new(0x0102), GoldilocksField::new(MODULUS - 1)]);
+        let mut le = vec![0x02, 0x01, 0, 0, 0, 0, 0, 0];
+        le.extend_from_slice(&(MODULUS - 1).to_le_bytes());
+        assert_eq!(x.as_bytes(), le);
+        let mut be = vec![0, 0, 0, 0, 0, 0, 0x01, 0x02];
+        be.extend_from_slice(&(MODULUS - 1).to_be_bytes());
+        assert_eq!(x.serialize_be(), be);
+
+        let mut rng = SplitMix64(0x93);
+        let y = GoldilocksQuinticExtension::new(core::array::from_fn(|_| rng.next_field()));
+        assert_eq!(GoldilocksQuinticExtension::deserialize(&y.as_bytes()), Ok(y));
+        assert_eq!(GoldilocksQuinticExtension::deserialize_be(&y.serialize_be()), Ok(y));
+        let z = GoldilocksCubicExtension::new(core::array::from_fn(|_| rng.next_field()));
+        assert_eq!(GoldilocksCubicExtension::deserialize(&z.as_bytes()), Ok(z));
+        assert_eq!(GoldilocksCubicExtension::deserialize_be(&z.serialize_be()), Ok(z));
+    }
+
+    #[test]
+    fn extension_deserialize_requires_exactly_the_serialized_length() {
+        let x = GoldilocksCubicExtension::new([GoldilocksField::new(1), GoldilocksField::new(2), GoldilocksField::new(3)]);
+        for mut bytes in [x.as_bytes(), x.serialize_be()] {
+            bytes.push(0);
+            for len in [0, 8, 23, 25] {
+                assert_eq!(GoldilocksCubicExtension::deserialize(&bytes[..len]), Err(DeserializationError::InvalidAmountOfBytes));
+                assert_eq!(GoldilocksCubicExtension::deserialize_be(&bytes[..len]), Err(DeserializationError::InvalidAmountOfBytes));
+            }
+        }
+        assert_eq!(GoldilocksQuadraticExtension::deserialize(&[0; 15]), Err(DeserializationError::InvalidAmountOfBytes));
+        assert_eq!(GoldilocksQuinticExtension::deserialize(&[0; 41]), Err(DeserializationError::InvalidAmountOfBytes));
+        assert_eq!(GoldilocksQuinticExtension::deserialize(&[0; 40]), Ok(GoldilocksQuinticExtension::new([GoldilocksField::ZERO; 5])));
+    }
+
+    #[test]
+    fn extension_deserialize_rejects_non_canonical_coefficients() {
+        for encoded in [MODULUS, u64::MAX] {
+            for position in 0..2 {
+                let mut le = vec![0; 16];
+                le[8 * position..8 * position + 8].copy_from_slice(&encoded.to_le_bytes());
+                assert_eq!(GoldilocksQuadraticExtension::deserialize(&le), Err(DeserializationError::InvalidValue));
+                let mut be = vec![0; 16];
+                be[8 * position..8 * position + 8].copy_from_slice(&encoded.to_be_bytes());
+                assert_eq!(GoldilocksQuadraticExtension::deserialize_be(&be), Err(DeserializationError::InvalidValue));
+            }
+        }
+        let mut le = [0; 16];
+        le[8..].copy_from_slice(&(MODULUS - 1).to_le_bytes());
+        assert_eq!(
+            GoldilocksQuadraticExtension::deserialize(&le),
+            Ok(GoldilocksQuadraticExtension::new([GoldilocksField::ZERO, -GoldilocksField::ONE]))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! The cubic extension Fp[v] / (v^3 - 2).
+
+use lambdaworks_math::{
+    errors::{ByteConversionError, DeserializationError},
+    field::errors::FieldError,
+    field::traits::IsField,
+    traits::{AsBytes, ByteConversion, Deserializable},
+};
+
+use super::{impl_extension_boilerplate, impl_subfield};
+use crate::{GoldilocksField, WideSum};
+
+/// omega^j for omega = 2^((p - 1) / 3) = 2^32 - 1, a primitive cube root of
+/// unity. Since v^p = v * omega, the k-th Frobenius power maps the
+/// coefficient of v^i to its product with omega^(i * k mod 3).
+const FROBENIUS_COEFFS: [GoldilocksField; 3] = [
+    GoldilocksField::from_canonical(1),
+    GoldilocksField::from_canonical(0x0000_0000_ffff_ffff),
+    GoldilocksField::from_canonical(0xffff_fffe_0000_0001),
+];
+
+/// An element a0 + a1 * v + a2 * v^2 of Fp^3, with v^3 = 2.
+///
+/// Like `GoldilocksField`, the type is both the element and the lambdaworks
+/// field marker, with the coefficient array as `IsField::BaseType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GoldilocksCubicExtension {
+    value: [GoldilocksField; 3],
+}
+
+impl GoldilocksCubicExtension {
+    pub const ZERO: Self = Self::new([GoldilocksField::ZERO; 3]);
+    pub const ONE: Self = Self::from_base(GoldilocksField::ONE);
+    /// The non-cube v^3 reduces to. Since 2^96 = -1, 2 has order 192, which is
+    /// divisible by 3 while (p - 1) / 3 is not, so 2^((p - 1) / 3) != 1 and
+    /// v^3 - 2 is irreducible. Multiplying by it is a doubling.
+    pub const NONRESIDUE: GoldilocksField = GoldilocksField::TWO;
+
+    /// Creates a0 + a1 * v + a2 * v^2 from [a0, a1, a2].
+    pub const fn new(value: [GoldilocksField; 3]) -> Self {
+        GoldilocksCubicExtension { value }
+    }
+
+    /// Embeds a base-field element as a0 + 0 * v + 0 * v^2.
+    pub const fn from_base(x: GoldilocksField) -> Self {
+        Self::new([x, GoldilocksField::ZERO, GoldilocksField::ZERO])
+    }
+
+    /// Returns [a0, a1, a2].
+    pub const fn coefficients(&self) -> [GoldilocksField; 3] {
+        self.value
+    }
+
+    pub fn square(&self) -> Self {
+        Self::new(<Self as IsField>::square(&self.value))
+    }
+
+    /// Fails with `FieldError::InvZeroError` for zero.
+    pub fn inv(&self) -> Result<Self, FieldError> {
+        <Self as IsField>::inv(&self.value).map(Self::new)
+    }
+
+    /// Returns self^(p^power), multiplying the coefficient of v^i by
+    /// omega^(i * power).
+    pub fn frobenius(&self, power: usize) -> Self {
+        let power = power % 3;
+        Self::new(core::array::from_fn(|i| self.value[i] * FROBENIUS_COEFFS[i * power % 3]))
+    }
+
+    /// Returns self * self^p * self^(p^2), an element of Fp.
+    pub fn norm(&self) -> GoldilocksField {
+        norm_and_adjugate(&self.value).0
+    }
+
+    /// Returns self + self^p + self^(p^2) = 3 a0, as the conjugates of v and
+    /// v^2 sum to zero.
+    pub fn trace(&self) -> GoldilocksField {
+        self.value[0].mul_u32(3)
+    }
+}
+
+/// Multiplies by the non-residue 2, a doubling.
+fn mul_by_nonresidue(x: GoldilocksField) -> GoldilocksField {
+    x.double()
+}
+
+fn wide_mul_by_nonresidue(x: WideSum) -> WideSum {
+    x + x
+}
+
+/// Returns the norm N = a0 A0 + 2 (a2 A1 + a1 A2) and the adjugate
+/// (A0, A1, A2), where A0 = a0^2 - 2 a1 a2, A1 = 2 a2^2 - a0 a1 and
+/// A2 = a1^2 - a0 a2, so that a * (A0 + A1 v + A2 v^2) = N.
+fn norm_and_adjugate(a: &[GoldilocksField; 3]) -> (GoldilocksField, [GoldilocksField; 3]) {
+    let adj0 = a[0].square() - mul_by_nonresidue(a[1] * a[2]);
+    let adj1 = mul_by_nonresidue(a[2].square()) - a[0] * a[1];
+    let adj2 = a[1].square() - a[0] * a[2];
+    let norm = a[0] * adj0 + mul_by_nonresidue(a[2] * adj1 + a[1] * adj2);
+    (norm, [adj0, adj1, adj2])
+}
+
+impl IsField for GoldilocksCubicExtension {
+    type BaseType = [GoldilocksField; 3];
+
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+    }
+
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+    }
+
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        [-a[0], -a[1], -a[2]]
+    }
+
+    /// Karatsuba with six base products: from vi = ai bi, the cross terms are
+    /// ai bj + aj bi = (ai + aj)(bi + bj) - vi - vj, and the v^3 and v^4 terms
+    /// fold back with v^3 = 2. Each coefficient is accumulated unreduced and
+    /// reduced once.
+    fn mul(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let v: [WideSum; 3] = core::array::from_fn(|i| WideSum::product(a[i], b[i]));
+        let cross = |i: usize, j: usize| WideSum::product(a[i] + a[j], b[i] + b[j]) - v[i] - v[j];
+        [
+            (v[0] + wide_mul_by_nonresidue(cross(1, 2))).reduce(),
+            (cross(0, 1) + wide_mul_by_nonresidue(v[2])).reduce(),
+            (cross(0, 2) + v[1]).reduce(),
+        ]
+    }
+
+    /// Multiplies the adjugate by the inverse of the norm, which is nonzero
+    /// unless a is zero.
+    fn inv(a: &Self::BaseType) -> Result<Self::BaseType, FieldError> {
+        let (norm, adjugate) = norm_and_adjugate(a);
+        let norm_inv = GoldilocksField::checked_div(&GoldilocksField::ONE, &norm)?;
+        Ok(adjugate.map(|c| c * norm_inv))
+    }
+
+    fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        match <Self as IsField>::inv(b) {
+            Ok(inverse) => Self::mul(a, &inverse),
+            Err(_) => panic!("GoldilocksCubicExtension::div: divisor is zero"),
+        }
+    }
+
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a == b
+    }
+
+    fn zero() -> Self::BaseType {
+        [GoldilocksField::ZERO; 3]
+    }
+
+    fn one() -> Self::BaseType {
+        [GoldilocksField::ONE, GoldilocksField::ZERO, GoldilocksField::ZERO]
+    }
+
+    fn from_u64(x: u64) -> Self::BaseType {
+        [GoldilocksField::new(x), GoldilocksField::ZERO, GoldilocksField::ZERO]
+    }
+
+    fn from_base_type(x: Self::BaseType) -> Self::BaseType {
+        x
+    }
+}
+
+impl_extension_boilerplate!(GoldilocksCubicExtension, 3);
+impl_subfield!(GoldilocksCubicExtension, 3);
+
+/// Schoolbook product with nine base products, folding the v^3 and v^4 terms
+/// back with v^3 = 2; the reference for the Karatsuba `mul`.
+#[cfg(test)]
+fn schoolbook_mul(a: &[GoldilocksField; 3], b: &[GoldilocksField; 3]) -> [GoldilocksField; 3] {
+    [
+        a[0] * b[0] + mul_by_nonresidue(a[1] * b[2] + a[2] * b[1]),
+        a[0] * b[1] + a[1] * b[0] + mul_by_nonresidue(a[2] * b[2]),
+        a[0] * b[2] + a[1] * b[1] + a[2] * b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+
+    type Fp3 = GoldilocksCubicExtension;
+
+    fn random(rng: &mut SplitMix64) -> Fp3 {
+        Fp3::new([rng.next_field(), rng.next_field(), rng.next_field()])
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        let mut rng = SplitMix64(0x78);
+        let edges = [0, 1, crate::EPSILON, 1 << 32, 1 << 63, MODULUS - 1].map(GoldilocksField::new);
+        for a0 in edges {
+            for a1 in edges {
+                for a2 in edges {
+                    let a = [a0, a1, a2];
+                    let b = [a2, -a0, a1];
+                    assert_eq!(<Fp3 as IsField>::mul(&a, &b), schoolbook_mul(&a, &b));
+                    assert_eq!(<Fp3 as IsField>::mul(&a, &a), schoolbook_mul(&a, &a));
+                }
+            }
+        }
+        for _ in 0..100_000 {
+            let (a, b) = (random(&mut rng), random(&mut rng));
+            assert_eq!((a * b).value, schoolbook_mul(&a.value, &b.value));
+        }
+    }
+
+    #[test]
+    fn mul_by_nonresidue_matches_generic_mul() {
+        let mut rng = SplitMix64(0x77);
+        let edges = [0, 1, 2, crate::EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1];
+        let samples: Vec<u64> = (0..1000).map(|_| rng.next_element()).collect();
+        for x in edges.into_iter().chain(samples).map(GoldilocksField::new) {
+            assert_eq!(mul_by_nonresidue(x), x * Fp3::NONRESIDUE, "{x}");
+        }
+    }
+
+    #[test]
+    fn two_is_not_a_cube() {
+        assert!(Fp3::NONRESIDUE.cube_root().is_none());
+        assert_ne!(Fp3::NONRESIDUE.pow_u64((MODULUS - 1) / 3), GoldilocksField::ONE);
+        // 5 is a cube, so it would not do.
+        assert!(GoldilocksField::new(5).cube_root().is_some());
+    }
+
+    #[test]
+    fn v_cubes_to_two() {
+        let v = Fp3::new([GoldilocksField::ZERO, GoldilocksField::ONE, GoldilocksField::ZERO]);
+        assert_eq!(v * v, Fp3::new([GoldilocksField::ZERO, GoldilocksField::ZERO, GoldilocksField::ONE]));
+        assert_eq!(v * v * v, Fp3::from_base(Fp3::NONRESIDUE));
+    }
+
+    #[test]
+    fn field_axioms_hold_on_random_elements() {
+        let mut rng = SplitMix64(0x0f31);
+        for _ in 0..1000 {
+            let (a, b, c) = (random(&mut rng), random(&mut rng), random(&mut rng));
+            assert_eq!(a + b, b + a);
+            assert_eq!(a * b, b * a);
+            assert_eq!((a + b) + c, a + (b + c));
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!((a + b) * c, a * c + b * c);
+            assert_eq!(a + Fp3::ZERO, a);
+            assert_eq!(a * Fp3::ONE, a);
+            assert_eq!(a + (-a), Fp3::ZERO);
+            assert_eq!(a.square(), a * a);
+        }
+    }
+
+    #[test]
+    fn base_field_embeds_as_a_subfield() {
+        let mut rng = SplitMix64(0x0f32);
+        for _ in 0..100 {
+            let (x, y) = (rng.next_field(), rng.next_field());
+            assert_eq!(Fp3::from_base(x) + Fp3::from_base(y), Fp3::from_base(x + y));
+            assert_eq!(Fp3::from_base(x) * Fp3::from_base(y), Fp3::from_base(x * y));
+            let a = random(&mut rng);
+            let [a0, a1, a2] = a.coefficients();
+            assert_eq!(Fp3::from(x) * a, Fp3::new([x * a0, x * a1, x * a2]));
+        }
+    }
+
+    #[test]
+    fn division_undoes_multiplication() {
+        let mut rng = SplitMix64(0x0f33);
+        for _ in 0..1000 {
+            let (a, b) = (random(&mut rng), random(&mut rng));
+            if b == Fp3::ZERO {
+                continue;
+            }
+            assert_eq!((a * b) * b.inv().unwrap(), a);
+            assert_eq!(b * b.inv().unwrap(), Fp3::ONE);
+        }
+        for sparse in [[0, 1, 0], [0, 0, 1], [3, 0, 4]] {
+            let a = Fp3::new(sparse.map(GoldilocksField::new));
+            assert_eq!(a * a.inv().unwrap(), Fp3::ONE);
+        }
+        assert!(matches!(Fp3::ZERO.inv(), Err(FieldError::InvZeroError)));
+    }
+
+    #[test]
+    fn encoding_is_24_bytes() {
+        let a = Fp3::new([GoldilocksField::new(1), GoldilocksField::new(MODULUS - 1), GoldilocksField::new(0xabcd)]);
+        let le = a.to_bytes_le();
+        assert_eq!(le.len(), 24);
+        assert_eq!(le[16..], 0xabcdu64.to_le_bytes());
+        assert_eq!(Fp3::from_bytes_le(&le).unwrap(), a);
+        assert_eq!(a.to_bytes_be().len(), 24);
+        assert_eq!(Fp3::from_bytes_be(&a.to_bytes_be()).unwrap(), a);
+        assert_eq!(Fp3::deserialize(&a.as_bytes()).unwrap(), a);
+        assert!(Fp3::from_bytes_le(&le[..23]).is_err());
+    }
+}
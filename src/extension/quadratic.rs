@@ -0,0 +1,425 @@
+//! The quadratic extension Fp[u] / (u^2 - 7).
+
+use lambdaworks_math::{
+    errors::{ByteConversionError, DeserializationError},
+    field::errors::FieldError,
+    field::traits::{IsFFTField, IsField},
+    traits::{AsBytes, ByteConversion, Deserializable},
+};
+
+use super::{impl_extension_boilerplate, impl_subfield};
+use crate::{GoldilocksField, WideSum};
+
+/// 7^((p - 1) / 2) = -1 to the power i, for i in 0..2: u^p = -u, so the
+/// Frobenius map negates the coefficient of u.
+const FROBENIUS_COEFFS: [GoldilocksField; 2] = [GoldilocksField::ONE, GoldilocksField::NEG_ONE];
+
+/// Multiplies by the non-residue 7.
+fn mul_by_nonresidue(x: GoldilocksField) -> GoldilocksField {
+    x.mul_by_7()
+}
+
+/// Multiplies an unreduced sum by 7 as 8x - x.
+fn wide_mul_by_nonresidue(x: WideSum) -> WideSum {
+    let x2 = x + x;
+    let x4 = x2 + x2;
+    x4 + x4 - x
+}
+
+/// An element a0 + a1 * u of Fp^2, with u^2 = 7.
+///
+/// Like `GoldilocksField`, the type is both the element and the lambdaworks
+/// field marker, with the coefficient array as `IsField::BaseType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GoldilocksQuadraticExtension {
+    value: [GoldilocksField; 2],
+}
+
+impl GoldilocksQuadraticExtension {
+    pub const ZERO: Self = Self::new([GoldilocksField::ZERO; 2]);
+    pub const ONE: Self = Self::from_base(GoldilocksField::ONE);
+    /// The non-residue u^2 reduces to. 7 generates the multiplicative group,
+    /// so it is not a square and u^2 - 7 is irreducible.
+    pub const NONRESIDUE: GoldilocksField = GoldilocksField::GENERATOR;
+
+    /// Creates a0 + a1 * u from [a0, a1].
+    pub const fn new(value: [GoldilocksField; 2]) -> Self {
+        GoldilocksQuadraticExtension { value }
+    }
+
+    /// Embeds a base-field element as a0 + 0 * u.
+    pub const fn from_base(x: GoldilocksField) -> Self {
+        Self::new([x, GoldilocksField::ZERO])
+    }
+
+    /// Returns [a0, a1].
+    pub const fn coefficients(&self) -> [GoldilocksField; 2] {
+        self.value
+    }
+
+    /// Returns a0 - a1 * u, the image under the nontrivial automorphism.
+    pub fn conjugate(&self) -> Self {
+        let [a0, a1] = self.value;
+        Self::new([a0, -a1])
+    }
+
+    /// Returns self^(p^power): the coefficient of u^i is multiplied by
+    /// (-1)^(i * power), so odd powers conjugate and even powers are the
+    /// identity.
+    pub fn frobenius(&self, power: usize) -> Self {
+        let power = power % 2;
+        Self::new(core::array::from_fn(|i| self.value[i] * FROBENIUS_COEFFS[i * power % 2]))
+    }
+
+    /// Returns self * conj(self) = a0^2 - 7 a1^2.
+    pub fn norm(&self) -> GoldilocksField {
+        let [a0, a1] = self.value;
+        a0.square() - mul_by_nonresidue(a1.square())
+    }
+
+    /// Returns self + conj(self) = 2 a0.
+    pub fn trace(&self) -> GoldilocksField {
+        self.value[0].double()
+    }
+
+    pub fn square(&self) -> Self {
+        Self::new(<Self as IsField>::square(&self.value))
+    }
+
+    /// Fails with `FieldError::InvZeroError` for zero.
+    pub fn inv(&self) -> Result<Self, FieldError> {
+        <Self as IsField>::inv(&self.value).map(Self::new)
+    }
+
+    /// Returns a square root, or `None` if the element is not a square.
+    ///
+    /// Uses the complex method: a is a square exactly when its norm N is a
+    /// square in Fp, and then x0^2 = (a0 ± sqrt(N)) / 2 and x1 = a1 / (2 x0).
+    /// Of the two roots ±x, the one whose first nonzero coefficient has the
+    /// smaller canonical value is returned, so base-field squares get the root
+    /// `GoldilocksField::sqrt` picks.
+    pub fn sqrt(&self) -> Option<Self> {
+        let [a0, a1] = self.value;
+        if a1 == GoldilocksField::ZERO {
+            // a0 is a square in Fp, or a0 / 7 is and the root is a multiple of u.
+            return match a0.sqrt() {
+                Some(x0) => Some(Self::from_base(x0)),
+                None => {
+                    let x1 = (a0 / Self::NONRESIDUE).sqrt()?;
+                    Some(Self::new([GoldilocksField::ZERO, x1]))
+                }
+            };
+        }
+        let n = self.norm().sqrt()?;
+        // The two candidates multiply to 7 a1^2 / 4, a non-square, so exactly
+        // one of them is a square and both are nonzero.
+        let half = GoldilocksField::from_canonical(crate::MODULUS / 2 + 1);
+        let x0 = match ((a0 + n) * half).sqrt() {
+            Some(x0) => x0,
+            None => ((a0 - n) * half).sqrt()?,
+        };
+        Some(Self::new([x0, a1 / x0.double()]))
+    }
+}
+
+impl IsField for GoldilocksQuadraticExtension {
+    type BaseType = [GoldilocksField; 2];
+
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        [a[0] + b[0], a[1] + b[1]]
+    }
+
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        [a[0] - b[0], a[1] - b[1]]
+    }
+
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        [-a[0], -a[1]]
+    }
+
+    /// Karatsuba: with v0 = a0 b0 and v1 = a1 b1, the product is
+    /// v0 + 7 v1 + ((a0 + a1)(b0 + b1) - v0 - v1) u. Three base products,
+    /// with each coefficient accumulated unreduced and reduced once.
+    fn mul(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let v0 = WideSum::product(a[0], b[0]);
+        let v1 = WideSum::product(a[1], b[1]);
+        let cross = WideSum::product(a[0] + a[1], b[0] + b[1]) - v0 - v1;
+        [(v0 + wide_mul_by_nonresidue(v1)).reduce(), cross.reduce()]
+    }
+
+    /// (a0 + a1 u)^2 = a0^2 + 7 a1^2 + 2 a0 a1 u.
+    fn square(a: &Self::BaseType) -> Self::BaseType {
+        [a[0].square() + mul_by_nonresidue(a[1].square()), (a[0] * a[1]).double()]
+    }
+
+    /// Multiplies the conjugate by the inverse of the norm, which lies in Fp
+    /// and is nonzero unless a is zero.
+    fn inv(a: &Self::BaseType) -> Result<Self::BaseType, FieldError> {
+        let norm = GoldilocksQuadraticExtension::new(*a).norm();
+        let norm_inv = GoldilocksField::checked_div(&GoldilocksField::ONE, &norm)?;
+        Ok([a[0] * norm_inv, -(a[1] * norm_inv)])
+    }
+
+    fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        match <Self as IsField>::inv(b) {
+            Ok(inverse) => Self::mul(a, &inverse),
+            Err(_) => panic!("GoldilocksQuadraticExtension::div: divisor is zero"),
+        }
+    }
+
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a == b
+    }
+
+    fn zero() -> Self::BaseType {
+        [GoldilocksField::ZERO; 2]
+    }
+
+    fn one() -> Self::BaseType {
+        [GoldilocksField::ONE, GoldilocksField::ZERO]
+    }
+
+    fn from_u64(x: u64) -> Self::BaseType {
+        [GoldilocksField::new(x), GoldilocksField::ZERO]
+    }
+
+    fn from_base_type(x: Self::BaseType) -> Self::BaseType {
+        x
+    }
+}
+
+/// p^2 - 1 = (p - 1)(p + 1) has two-adicity 33, one more than p - 1, so Fp^2
+/// has a root of unity of order 2^33: a square root of the base field's
+/// 2^32-th root w. Since w is not a square in Fp but w / 7 is, it is
+/// s * u for s = sqrt(w / 7).
+impl IsFFTField for GoldilocksQuadraticExtension {
+    const TWO_ADICITY: u64 = crate::TWO_ADICITY as u64 + 1;
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType =
+        [GoldilocksField::ZERO, GoldilocksField::from_canonical(0x26af_ae5b_e30b_5912)];
+
+    fn field_name() -> &'static str {
+        "goldilocks64_quadratic"
+    }
+}
+
+impl_extension_boilerplate!(GoldilocksQuadraticExtension, 2);
+impl_subfield!(GoldilocksQuadraticExtension, 2);
+
+/// (a0 + a1 u)(b0 + b1 u) = a0 b0 + 7 a1 b1 + (a0 b1 + a1 b0) u, with four
+/// base products; the reference for the Karatsuba `mul`.
+#[cfg(test)]
+fn schoolbook_mul(a: &[GoldilocksField; 2], b: &[GoldilocksField; 2]) -> [GoldilocksField; 2] {
+    [a[0] * b[0] + mul_by_nonresidue(a[1] * b[1]), a[0] * b[1] + a[1] * b[0]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+
+    type Fp2 = GoldilocksQuadraticExtension;
+
+    fn random(rng: &mut SplitMix64) -> Fp2 {
+        Fp2::new([rng.next_field(), rng.next_field()])
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        let mut rng = SplitMix64(0x78);
+        let edges = [0, 1, 2, crate::EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1].map(GoldilocksField::new);
+        for a0 in edges {
+            for a1 in edges {
+                let a = [a0, a1];
+                let b = [a1, -a0];
+                assert_eq!(<Fp2 as IsField>::mul(&a, &b), schoolbook_mul(&a, &b));
+                assert_eq!(<Fp2 as IsField>::mul(&a, &a), schoolbook_mul(&a, &a));
+            }
+        }
+        for _ in 0..100_000 {
+            let (a, b) = (random(&mut rng), random(&mut rng));
+            assert_eq!((a * b).value, schoolbook_mul(&a.value, &b.value));
+        }
+    }
+
+    #[test]
+    fn mul_by_nonresidue_matches_generic_mul() {
+        let mut rng = SplitMix64(0x77);
+        let edges = [0, 1, 2, crate::EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1];
+        let samples: Vec<u64> = (0..1000).map(|_| rng.next_element()).collect();
+        for x in edges.into_iter().chain(samples).map(GoldilocksField::new) {
+            assert_eq!(mul_by_nonresidue(x), x * Fp2::NONRESIDUE, "{x}");
+        }
+    }
+
+    #[test]
+    fn seven_is_a_non_residue() {
+        assert_eq!(Fp2::NONRESIDUE.legendre_symbol(), -1);
+        assert!(Fp2::NONRESIDUE.sqrt().is_none());
+    }
+
+    #[test]
+    fn u_squares_to_seven() {
+        let u = Fp2::new([GoldilocksField::ZERO, GoldilocksField::ONE]);
+        assert_eq!(u * u, Fp2::from_base(Fp2::NONRESIDUE));
+        assert_eq!(u.square(), Fp2::from_base(Fp2::NONRESIDUE));
+        assert_eq!(u.conjugate(), -u);
+    }
+
+    #[test]
+    fn field_axioms_hold_on_random_elements() {
+        let mut rng = SplitMix64(0x0f02);
+        for _ in 0..1000 {
+            let (a, b, c) = (random(&mut rng), random(&mut rng), random(&mut rng));
+            assert_eq!(a + b, b + a);
+            assert_eq!(a * b, b * a);
+            assert_eq!((a + b) + c, a + (b + c));
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a + Fp2::ZERO, a);
+            assert_eq!(a * Fp2::ONE, a);
+            assert_eq!(a + (-a), Fp2::ZERO);
+            assert_eq!(a - b, a + (-b));
+            assert_eq!(a.square(), a * a);
+        }
+    }
+
+    #[test]
+    fn base_field_embeds_as_a_subfield() {
+        let mut rng = SplitMix64(0x0f03);
+        for _ in 0..100 {
+            let (x, y) = (rng.next_field(), rng.next_field());
+            assert_eq!(Fp2::from_base(x) + Fp2::from_base(y), Fp2::from_base(x + y));
+            assert_eq!(Fp2::from_base(x) * Fp2::from_base(y), Fp2::from_base(x * y));
+            assert_eq!(Fp2::from(x).conjugate(), Fp2::from_base(x));
+        }
+        assert_eq!(Fp2::from_base(GoldilocksField::ONE), Fp2::ONE);
+    }
+
+    #[test]
+    fn conjugate_is_the_frobenius_map() {
+        // a^p = conj(a), and a * conj(a) lies in the base field.
+        let mut rng = SplitMix64(0x0f04);
+        for _ in 0..20 {
+            let a = random(&mut rng);
+            assert_eq!(Fp2::new(<Fp2 as IsField>::pow(&a.value, MODULUS)), a.conjugate());
+            assert_eq!((a * a.conjugate()).coefficients()[1], GoldilocksField::ZERO);
+        }
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let mut rng = SplitMix64(0x0f05);
+        for _ in 0..1000 {
+            let a = random(&mut rng);
+            if a == Fp2::ZERO {
+                continue;
+            }
+            let inverse = a.inv().unwrap();
+            assert_eq!(a * inverse, Fp2::ONE);
+            let b = random(&mut rng);
+            assert_eq!((b / a) * a, b);
+        }
+        let pure_u = Fp2::new([GoldilocksField::ZERO, GoldilocksField::new(5)]);
+        assert_eq!(pure_u * pure_u.inv().unwrap(), Fp2::ONE);
+    }
+
+    #[test]
+    fn two_adic_root_has_order_2_to_the_33() {
+        let root = Fp2::new(Fp2::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY);
+        let base_root = GoldilocksField::primitive_root_of_unity(crate::TWO_ADICITY).unwrap();
+        assert_eq!(root.square(), Fp2::from_base(base_root));
+        let mut power = root;
+        for _ in 0..32 {
+            power = power.square();
+        }
+        assert_eq!(power, Fp2::from_base(GoldilocksField::NEG_ONE));
+        assert_eq!(power.square(), Fp2::ONE);
+        assert_eq!(Fp2::TWO_ADICITY, 33);
+        // p^2 - 1 has exactly 33 factors of two.
+        let order = (MODULUS as u128 - 1) * (MODULUS as u128 + 1);
+        assert_eq!(order.trailing_zeros(), 33);
+
+        let sub_root = Fp2::new(*Fp2::get_primitive_root_of_unity(10).unwrap().value());
+        assert_eq!(sub_root, Fp2::from_base(GoldilocksField::primitive_root_of_unity(10).unwrap()));
+    }
+
+    #[test]
+    fn sqrt_of_squares_round_trips() {
+        let mut rng = SplitMix64(0x76);
+        for _ in 0..200 {
+            let x = random(&mut rng);
+            let root = x.square().sqrt().unwrap();
+            assert!(root == x || root == -x);
+            assert_eq!(root.square(), x.square());
+            assert_eq!(root, (-x).square().sqrt().unwrap());
+        }
+        assert_eq!(Fp2::ZERO.sqrt(), Some(Fp2::ZERO));
+        let u = Fp2::new([GoldilocksField::ZERO, GoldilocksField::ONE]);
+        assert_eq!(Fp2::from_base(Fp2::NONRESIDUE).sqrt(), Some(u));
+    }
+
+    #[test]
+    fn sqrt_of_base_squares_matches_the_base_field() {
+        let mut rng = SplitMix64(0x77);
+        for _ in 0..200 {
+            let x = rng.next_field();
+            // Every base element is a square in Fp^2.
+            let root = Fp2::from_base(x).sqrt().unwrap();
+            assert_eq!(root.square(), Fp2::from_base(x));
+            match x.sqrt() {
+                Some(base_root) => assert_eq!(root, Fp2::from_base(base_root)),
+                None => assert_eq!(root.coefficients()[0], GoldilocksField::ZERO),
+            }
+        }
+    }
+
+    #[test]
+    fn non_squares_have_no_sqrt() {
+        let mut rng = SplitMix64(0x78);
+        let mut found = 0;
+        while found < 50 {
+            let x = random(&mut rng);
+            // x is a square exactly when x^((p^2 - 1) / 2) = 1, i.e. its norm
+            // is a square in Fp.
+            if x.norm().is_quadratic_residue() {
+                assert!(x.sqrt().is_some());
+                continue;
+            }
+            assert_eq!(x.sqrt(), None);
+            found += 1;
+        }
+        // u has norm -7, a non-square since -1 is a square and 7 is not.
+        let u = Fp2::new([GoldilocksField::ZERO, GoldilocksField::ONE]);
+        assert!(!u.norm().is_quadratic_residue());
+        // u^((p^2 - 1) / 2) = y^(p + 1) for y = u^((p - 1) / 2).
+        let y = Fp2::new(<Fp2 as IsField>::pow(&u.value, (MODULUS - 1) / 2));
+        assert_eq!(y.frobenius(1) * y, -Fp2::ONE);
+        assert_eq!(u.sqrt(), None);
+    }
+
+    #[test]
+    fn zero_has_no_inverse() {
+        assert!(matches!(Fp2::ZERO.inv(), Err(FieldError::InvZeroError)));
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor is zero")]
+    fn division_by_zero_panics() {
+        let _ = Fp2::ONE / Fp2::ZERO;
+    }
+
+    #[test]
+    fn byte_conversion_round_trips() {
+        let a = Fp2::new([GoldilocksField::new(0x0102_0304_0506_0708), GoldilocksField::new(MODULUS - 1)]);
+        let le = a.to_bytes_le();
+        assert_eq!(le.len(), 16);
+        assert_eq!(le[..8], 0x0102_0304_0506_0708u64.to_le_bytes());
+        assert_eq!(le[8..], (MODULUS - 1).to_le_bytes());
+        assert_eq!(Fp2::from_bytes_le(&le).unwrap(), a);
+        assert_eq!(Fp2::from_bytes_be(&a.to_bytes_be()).unwrap(), a);
+        assert_eq!(Fp2::deserialize(&a.as_bytes()).unwrap(), a);
+        assert!(matches!(Fp2::from_bytes_le(&le[..15]), Err(ByteConversionError::FromLEBytesError)));
+        assert!(matches!(Fp2::from_bytes_be(&[0; 8]), Err(ByteConversionError::FromBEBytesError)));
+    }
+}
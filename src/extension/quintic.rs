@@ -0,0 +1,357 @@
+//! The quintic extension Fp[z] / (z^5 - 3), the field of the EcGFp5 curve.
+
+use lambdaworks_math::{
+    errors::{ByteConversionError, DeserializationError},
+    field::errors::FieldError,
+    field::traits::IsField,
+    traits::{AsBytes, ByteConversion, Deserializable},
+};
+
+use super::{impl_extension_boilerplate, impl_subfield};
+use crate::GoldilocksField;
+
+/// gamma^j for gamma = 3^((p - 1) / 5), a primitive fifth root of unity.
+/// Since z^p = z * 3^((p - 1) / 5), the k-th Frobenius power maps the
+/// coefficient of z^i to its product with gamma^(i * k mod 5).
+const FROBENIUS_COEFFS: [GoldilocksField; 5] = [
+    GoldilocksField::from_canonical(1),
+    GoldilocksField::from_canonical(0x0e73_6627_a0ae_b983),
+    GoldilocksField::from_canonical(0xdb8e_dc80_2dc0_b266),
+    GoldilocksField::from_canonical(0x02ef_b5c2_a6f3_5241),
+    GoldilocksField::from_canonical(0x130e_0794_8a9d_41d6),
+];
+
+/// An element a0 + a1 z + a2 z^2 + a3 z^3 + a4 z^4 of Fp^5, with z^5 = 3.
+///
+/// Like `GoldilocksField`, the type is both the element and the lambdaworks
+/// field marker, with the coefficient array as `IsField::BaseType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GoldilocksQuinticExtension {
+    value: [GoldilocksField; 5],
+}
+
+impl GoldilocksQuinticExtension {
+    pub const ZERO: Self = Self::new([GoldilocksField::ZERO; 5]);
+    pub const ONE: Self = Self::from_base(GoldilocksField::ONE);
+    /// The constant z^5 reduces to. 3^((p - 1) / 5) != 1, so 3 is not a fifth
+    /// power and z^5 - 3 is irreducible.
+    pub const NONRESIDUE: GoldilocksField = GoldilocksField::from_canonical(3);
+
+    /// Creates sum a_i z^i from [a0, a1, a2, a3, a4].
+    pub const fn new(value: [GoldilocksField; 5]) -> Self {
+        GoldilocksQuinticExtension { value }
+    }
+
+    /// Embeds a base-field element as the constant polynomial.
+    pub const fn from_base(x: GoldilocksField) -> Self {
+        let mut value = [GoldilocksField::ZERO; 5];
+        value[0] = x;
+        Self::new(value)
+    }
+
+    /// Returns [a0, a1, a2, a3, a4].
+    pub const fn coefficients(&self) -> [GoldilocksField; 5] {
+        self.value
+    }
+
+    pub fn square(&self) -> Self {
+        Self::new(<Self as IsField>::square(&self.value))
+    }
+
+    /// Fails with `FieldError::InvZeroError` for zero.
+    pub fn inv(&self) -> Result<Self, FieldError> {
+        <Self as IsField>::inv(&self.value).map(Self::new)
+    }
+
+    /// Returns self^(p^power), multiplying the coefficient of z^i by
+    /// gamma^(i * power).
+    pub fn frobenius(&self, power: usize) -> Self {
+        Self::new(frobenius(&self.value, power % 5))
+    }
+
+    /// Returns the product of the five conjugates self^(p^k), an element of
+    /// Fp.
+    pub fn norm(&self) -> GoldilocksField {
+        norm_and_cofactor(&self.value).0
+    }
+
+    /// Returns the sum of the five conjugates, 5 a0, as the conjugates of each
+    /// z^i with 0 < i < 5 sum to zero.
+    pub fn trace(&self) -> GoldilocksField {
+        self.value[0].mul_u32(5)
+    }
+
+    /// Whether the element is a square, counting zero as one. Since
+    /// (p^5 - 1) / 2 = (p^4 + ... + 1) * (p - 1) / 2, this is the quadratic
+    /// character of the norm in Fp.
+    pub fn is_square(&self) -> bool {
+        self.norm().is_quadratic_residue()
+    }
+
+    /// Returns a square root of the element, or `None` if it is not a square.
+    ///
+    /// With R = p^4 + p^3 + p^2 + p + 1, x^R = N(x) and
+    /// v = x^((R + 1) / 2) satisfies v^2 = N(x) * x, so sqrt(x) = v / sqrt(N(x))
+    /// with the base-field root. The exponent splits as
+    /// (R + 1) / 2 = 1 + p * (1 + p^2) * (p + 1) / 2, so v costs one u64
+    /// power and two Frobenius maps. Which of the two roots is returned follows
+    /// from the base-field `sqrt` convention.
+    pub fn sqrt(&self) -> Option<Self> {
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        let base_root = self.norm().sqrt()?;
+        let y = <Self as IsField>::pow(&self.value, crate::MODULUS / 2 + 1);
+        let w = <Self as IsField>::mul(&y, &frobenius(&y, 2));
+        let v = <Self as IsField>::mul(&self.value, &frobenius(&w, 1));
+        let scale = GoldilocksField::ONE / base_root;
+        Some(Self::new(v.map(|c| c * scale)))
+    }
+}
+
+/// Multiplies by the non-residue 3 as x + x + x.
+fn mul_by_nonresidue(x: GoldilocksField) -> GoldilocksField {
+    x.mul_by_3()
+}
+
+/// a^(p^power), for power in 0..5.
+fn frobenius(a: &[GoldilocksField; 5], power: usize) -> [GoldilocksField; 5] {
+    core::array::from_fn(|i| a[i] * FROBENIUS_COEFFS[i * power % 5])
+}
+
+/// Returns the norm N(a) = a * a^p * a^(p^2) * a^(p^3) * a^(p^4), which lies
+/// in Fp, together with t = a^p * ... * a^(p^4). Only the constant term of
+/// a * t is computed.
+fn norm_and_cofactor(a: &[GoldilocksField; 5]) -> (GoldilocksField, [GoldilocksField; 5]) {
+    let mul = <GoldilocksQuinticExtension as IsField>::mul;
+    let t01 = mul(&frobenius(a, 1), &frobenius(a, 2));
+    let t = mul(&t01, &frobenius(&t01, 2));
+    let cross = a[1] * t[4] + a[2] * t[3] + a[3] * t[2] + a[4] * t[1];
+    (a[0] * t[0] + mul_by_nonresidue(cross), t)
+}
+
+impl IsField for GoldilocksQuinticExtension {
+    type BaseType = [GoldilocksField; 5];
+
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        core::array::from_fn(|i| a[i] + b[i])
+    }
+
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        core::array::from_fn(|i| a[i] - b[i])
+    }
+
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        core::array::from_fn(|i| -a[i])
+    }
+
+    /// Schoolbook product; the terms of degree 5 + k fold back onto z^k
+    /// multiplied by 3.
+    fn mul(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let mut low = [GoldilocksField::ZERO; 5];
+        let mut high = [GoldilocksField::ZERO; 4];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                if i + j < 5 {
+                    low[i + j] += *x * *y;
+                } else {
+                    high[i + j - 5] += *x * *y;
+                }
+            }
+        }
+        for (l, h) in low.iter_mut().zip(high) {
+            *l += mul_by_nonresidue(h);
+        }
+        low
+    }
+
+    /// Inverts through the norm: with N(a) = a * t, a^-1 = t / N(a).
+    fn inv(a: &Self::BaseType) -> Result<Self::BaseType, FieldError> {
+        let (norm, t) = norm_and_cofactor(a);
+        let norm_inv = GoldilocksField::checked_div(&GoldilocksField::ONE, &norm)?;
+        Ok(t.map(|c| c * norm_inv))
+    }
+
+    fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        match <Self as IsField>::inv(b) {
+            Ok(inverse) => Self::mul(a, &inverse),
+            Err(_) => panic!("GoldilocksQuinticExtension::div: divisor is zero"),
+        }
+    }
+
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a == b
+    }
+
+    fn zero() -> Self::BaseType {
+        [GoldilocksField::ZERO; 5]
+    }
+
+    fn one() -> Self::BaseType {
+        Self::from_u64(1)
+    }
+
+    fn from_u64(x: u64) -> Self::BaseType {
+        GoldilocksQuinticExtension::from_base(GoldilocksField::new(x)).value
+    }
+
+    fn from_base_type(x: Self::BaseType) -> Self::BaseType {
+        x
+    }
+}
+
+impl_extension_boilerplate!(GoldilocksQuinticExtension, 5);
+impl_subfield!(GoldilocksQuinticExtension, 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+
+    type Fp5 = GoldilocksQuinticExtension;
+
+    fn random(rng: &mut SplitMix64) -> Fp5 {
+        Fp5::new(core::array::from_fn(|_| rng.next_field()))
+    }
+
+    fn z_pow(k: usize) -> Fp5 {
+        let mut value = [GoldilocksField::ZERO; 5];
+        value[k] = GoldilocksField::ONE;
+        Fp5::new(value)
+    }
+
+    /// Multiplies as polynomials over the integers, then reduces mod z^5 - 3
+    /// and mod p, using only u128 arithmetic.
+    fn naive_mul(a: &Fp5, b: &Fp5) -> Fp5 {
+        let p = MODULUS as u128;
+        let (a, b) = (a.coefficients().map(|c| c.to_canonical_u64() as u128), b.coefficients().map(|c| c.to_canonical_u64() as u128));
+        let mut product = [0u128; 9];
+        for i in 0..5 {
+            for j in 0..5 {
+                product[i + j] = (product[i + j] + a[i] * b[j] % p) % p;
+            }
+        }
+        for k in (5..9).rev() {
+            product[k - 5] = (product[k - 5] + 3 * product[k]) % p;
+        }
+        Fp5::new(core::array::from_fn(|k| GoldilocksField::new(product[k] as u64)))
+    }
+
+    #[test]
+    fn mul_by_nonresidue_matches_generic_mul() {
+        let mut rng = SplitMix64(0x77);
+        let edges = [0, 1, 2, crate::EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1];
+        let samples: Vec<u64> = (0..1000).map(|_| rng.next_element()).collect();
+        for x in edges.into_iter().chain(samples).map(GoldilocksField::new) {
+            assert_eq!(mul_by_nonresidue(x), x * Fp5::NONRESIDUE, "{x}");
+        }
+    }
+
+    #[test]
+    fn z_to_the_fifth_is_three() {
+        let z = z_pow(1);
+        assert_eq!(z * z * z * z * z, Fp5::from_base(Fp5::NONRESIDUE));
+        assert_eq!(z * z_pow(4), Fp5::from_base(GoldilocksField::new(3)));
+        assert_eq!(z_pow(2) * z_pow(4), Fp5::from_base(GoldilocksField::new(3)) * z);
+        assert_ne!(Fp5::NONRESIDUE.pow_u64((MODULUS - 1) / 5), GoldilocksField::ONE);
+    }
+
+    #[test]
+    fn frobenius_coefficients_are_powers_of_gamma() {
+        let gamma = Fp5::NONRESIDUE.pow_u64((MODULUS - 1) / 5);
+        for (j, c) in FROBENIUS_COEFFS.iter().enumerate() {
+            assert_eq!(*c, gamma.pow_u64(j as u64));
+        }
+        let mut rng = SplitMix64(0x0f50);
+        let a = random(&mut rng);
+        assert_eq!(Fp5::new(frobenius(&a.value, 1)), Fp5::new(<Fp5 as IsField>::pow(&a.value, MODULUS)));
+    }
+
+    #[test]
+    fn field_axioms_hold_on_random_elements() {
+        let mut rng = SplitMix64(0x0f51);
+        for _ in 0..1000 {
+            let (a, b, c) = (random(&mut rng), random(&mut rng), random(&mut rng));
+            assert_eq!(a + b, b + a);
+            assert_eq!(a * b, b * a);
+            assert_eq!((a + b) + c, a + (b + c));
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a + Fp5::ZERO, a);
+            assert_eq!(a * Fp5::ONE, a);
+            assert_eq!(a + (-a), Fp5::ZERO);
+            assert_eq!(a.square(), a * a);
+        }
+    }
+
+    #[test]
+    fn mul_matches_naive_polynomial_reduction() {
+        let mut rng = SplitMix64(0x0f52);
+        for _ in 0..5000 {
+            let (a, b) = (random(&mut rng), random(&mut rng));
+            assert_eq!(a * b, naive_mul(&a, &b));
+        }
+        let max = Fp5::new([GoldilocksField::NEG_ONE; 5]);
+        assert_eq!(max * max, naive_mul(&max, &max));
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let mut rng = SplitMix64(0x0f53);
+        for _ in 0..1000 {
+            let a = random(&mut rng);
+            let inverse = a.inv().unwrap();
+            assert_eq!(a * inverse, Fp5::ONE);
+            assert_eq!(inverse.inv().unwrap(), a);
+        }
+        for k in 0..5 {
+            assert_eq!(z_pow(k) * z_pow(k).inv().unwrap(), Fp5::ONE);
+        }
+        let base = Fp5::from_base(GoldilocksField::new(12345));
+        assert_eq!(base.inv().unwrap(), Fp5::from_base(GoldilocksField::ONE / GoldilocksField::new(12345)));
+        assert!(matches!(Fp5::ZERO.inv(), Err(FieldError::InvZeroError)));
+    }
+
+    #[test]
+    fn sqrt_of_squares_round_trips() {
+        let mut rng = SplitMix64(0x0f54);
+        let mut non_squares = 0;
+        for _ in 0..200 {
+            let a = random(&mut rng);
+            let root = a.square().sqrt().unwrap();
+            assert!(root == a || root == -a);
+            assert!(a.square().is_square());
+            match a.sqrt() {
+                Some(r) => assert_eq!(r.square(), a),
+                None => {
+                    assert!(!a.is_square());
+                    non_squares += 1;
+                }
+            }
+        }
+        assert!((60..140).contains(&non_squares));
+        assert_eq!(Fp5::ZERO.sqrt(), Some(Fp5::ZERO));
+        // z has norm 3, a square in Fp, so it is a square.
+        assert_eq!(z_pow(1).sqrt().unwrap().square(), z_pow(1));
+        // A base-field element is a square in an odd-degree extension iff it
+        // is one in Fp.
+        assert!(!Fp5::from_base(GoldilocksField::GENERATOR).is_square());
+        assert_eq!(Fp5::from_base(GoldilocksField::GENERATOR).sqrt(), None);
+    }
+
+    #[test]
+    fn encoding_is_five_little_endian_limbs() {
+        let a = Fp5::new(core::array::from_fn(|i| GoldilocksField::new(0x1111 * (i as u64 + 1))));
+        let le = a.to_bytes_le();
+        assert_eq!(le.len(), 40);
+        for (i, limb) in le.chunks(8).enumerate() {
+            assert_eq!(limb, (0x1111 * (i as u64 + 1)).to_le_bytes());
+        }
+        assert_eq!(Fp5::from_bytes_le(&le).unwrap(), a);
+        assert_eq!(Fp5::from_bytes_be(&a.to_bytes_be()).unwrap(), a);
+        assert_eq!(Fp5::deserialize(&a.as_bytes()).unwrap(), a);
+        assert!(Fp5::from_bytes_le(&le[..39]).is_err());
+        assert_eq!(Fp5::from(GoldilocksField::new(9)), Fp5::from_base(GoldilocksField::new(9)));
+    }
+}
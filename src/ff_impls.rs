@@ -0,0 +1,220 @@
+//! `ff::Field` and `ff::PrimeField` impls, behind the `ff` feature.
+//!
+//! `Repr` is the canonical value as 8 little-endian bytes, the layout of
+//! `AsBytes`, and `from_repr` rejects values ≥ p. The ff constants are
+//! spelled out as literals because they must be usable in const context; the
+//! tests check each against the crate's own constants.
+
+use ff::{Field, PrimeField};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption};
+
+use crate::{GoldilocksField, MODULUS};
+
+impl ConstantTimeEq for GoldilocksField {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.value.ct_eq(&other.value)
+    }
+}
+
+impl ConditionallySelectable for GoldilocksField {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        GoldilocksField { value: u64::conditional_select(&a.value, &b.value, choice) }
+    }
+}
+
+impl Field for GoldilocksField {
+    const ZERO: Self = GoldilocksField::ZERO;
+    const ONE: Self = GoldilocksField::ONE;
+
+    /// Draws a uniform element by rejection sampling u64 draws below p.
+    fn random(mut rng: impl RngCore) -> Self {
+        loop {
+            let x = rng.next_u64();
+            if x < MODULUS {
+                return GoldilocksField::from_canonical(x);
+            }
+        }
+    }
+
+    fn square(&self) -> Self {
+        GoldilocksField::square(self)
+    }
+
+    fn double(&self) -> Self {
+        GoldilocksField::double(self)
+    }
+
+    /// a^(p - 2), which is none for zero.
+    fn invert(&self) -> CtOption<Self> {
+        let is_nonzero = !self.ct_eq(&GoldilocksField::ZERO);
+        CtOption::new(self.pow_u64(MODULUS - 2), is_nonzero)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        match GoldilocksField::sqrt(self) {
+            Some(root) => CtOption::new(root, Choice::from(1)),
+            None => CtOption::new(GoldilocksField::ZERO, Choice::from(0)),
+        }
+    }
+
+    /// ff asks for `(true, 0)` when num = 0, even if div = 0; otherwise this
+    /// is the inherent `sqrt_ratio`.
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        if *num == GoldilocksField::ZERO {
+            return (Choice::from(1), GoldilocksField::ZERO);
+        }
+        let (is_square, root) = GoldilocksField::sqrt_ratio(num, div);
+        (Choice::from(is_square as u8), root)
+    }
+}
+
+impl PrimeField for GoldilocksField {
+    type Repr = [u8; 8];
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let value = u64::from_le_bytes(repr);
+        let is_canonical = value.ct_lt(&MODULUS);
+        // `from_canonical` expects a value below p, so a rejected one is
+        // replaced by zero before conversion.
+        let value = u64::conditional_select(&0, &value, is_canonical);
+        CtOption::new(GoldilocksField::from_canonical(value), is_canonical)
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.to_canonical_u64().to_le_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.to_canonical_u64() & 1) as u8)
+    }
+
+    const MODULUS: &'static str = "0xffffffff00000001";
+    const NUM_BITS: u32 = 64;
+    const CAPACITY: u32 = 63;
+    const TWO_INV: Self = GoldilocksField::from_canonical(0x7fff_ffff_8000_0001);
+    const MULTIPLICATIVE_GENERATOR: Self = GoldilocksField::MULTIPLICATIVE_GENERATOR;
+    const S: u32 = 32;
+    const ROOT_OF_UNITY: Self = crate::TWO_ADIC_ROOT_OF_UNITY;
+    const ROOT_OF_UNITY_INV: Self = GoldilocksField::from_canonical(0x76b6_b635_b6fc_8719);
+    const DELTA: Self = GoldilocksField::from_canonical(0xaa5b_2509_f86b_b4d4);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::{MULTIPLICATIVE_ODD_ORDER, TWO_ADICITY, TWO_ADIC_ROOT_OF_UNITY};
+
+    impl RngCore for SplitMix64 {
+        fn next_u32(&mut self) -> u32 {
+            SplitMix64::next_u64(self) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            SplitMix64::next_u64(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                chunk.copy_from_slice(&SplitMix64::next_u64(self).to_le_bytes()[..chunk.len()]);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    fn ff_sqrt_ratio(num: &GoldilocksField, div: &GoldilocksField) -> (bool, GoldilocksField) {
+        let (is_square, root) = <GoldilocksField as Field>::sqrt_ratio(num, div);
+        (is_square.into(), root)
+    }
+
+    #[test]
+    fn constants_agree_with_the_crate() {
+        let g = GoldilocksField::MULTIPLICATIVE_GENERATOR;
+        assert_eq!(<GoldilocksField as PrimeField>::MULTIPLICATIVE_GENERATOR, GoldilocksField::from(7u64));
+        assert_eq!(<GoldilocksField as PrimeField>::MODULUS, format!("{MODULUS:#x}"));
+        assert_eq!(GoldilocksField::NUM_BITS, u64::BITS - (MODULUS - 1).leading_zeros());
+        assert_eq!(GoldilocksField::CAPACITY, GoldilocksField::NUM_BITS - 1);
+        assert_eq!(GoldilocksField::S, TWO_ADICITY);
+        assert_eq!(MULTIPLICATIVE_ODD_ORDER << GoldilocksField::S, MODULUS - 1);
+        assert_eq!(GoldilocksField::TWO_INV * GoldilocksField::TWO, GoldilocksField::ONE);
+
+        let root = GoldilocksField::ROOT_OF_UNITY;
+        assert_eq!(root, TWO_ADIC_ROOT_OF_UNITY);
+        assert_eq!(root, g.pow_u64(MULTIPLICATIVE_ODD_ORDER));
+        assert_eq!(root.pow_u64(1 << GoldilocksField::S), GoldilocksField::ONE);
+        assert_eq!(root.pow_u64(1 << (GoldilocksField::S - 1)), GoldilocksField::NEG_ONE);
+        assert_eq!(root * GoldilocksField::ROOT_OF_UNITY_INV, GoldilocksField::ONE);
+        assert_eq!(GoldilocksField::DELTA, g.pow_u64(1 << GoldilocksField::S));
+        assert_eq!(GoldilocksField::DELTA.pow_u64(MULTIPLICATIVE_ODD_ORDER), GoldilocksField::ONE);
+    }
+
+    #[test]
+    fn repr_round_trips_and_rejects_non_canonical_encodings() {
+        let mut rng = SplitMix64(0xff97);
+        for _ in 0..1000 {
+            let x = rng.next_field();
+            let repr = x.to_repr();
+            assert_eq!(repr, x.to_canonical_u64().to_le_bytes());
+            assert_eq!(GoldilocksField::from_repr(repr).unwrap(), x);
+            assert_eq!(bool::from(x.is_odd()), x.to_canonical_u64() % 2 == 1);
+        }
+        assert_eq!(GoldilocksField::from_repr((MODULUS - 1).to_le_bytes()).unwrap(), GoldilocksField::NEG_ONE);
+        for value in [MODULUS, MODULUS + 1, u64::MAX] {
+            assert!(bool::from(GoldilocksField::from_repr(value.to_le_bytes()).is_none()));
+            assert_eq!(GoldilocksField::from_repr_vartime(value.to_le_bytes()), None);
+        }
+    }
+
+    #[test]
+    fn field_operations_match_the_inherent_ones() {
+        let mut rng = SplitMix64(0xf1e1d);
+        for _ in 0..500 {
+            let x = rng.next_field();
+            assert_eq!(Field::square(&x), x * x);
+            assert_eq!(Field::double(&x), x + x);
+            if x != GoldilocksField::ZERO {
+                assert_eq!(x.invert().unwrap() * x, GoldilocksField::ONE);
+            }
+            let square = x.square();
+            assert_eq!(Field::sqrt(&square).unwrap(), GoldilocksField::sqrt(&square).unwrap());
+        }
+        assert!(bool::from(GoldilocksField::ZERO.invert().is_none()));
+        assert!(bool::from(Field::sqrt(&GoldilocksField::MULTIPLICATIVE_GENERATOR).is_none()));
+        assert_eq!(Field::sqrt(&GoldilocksField::ZERO).unwrap(), GoldilocksField::ZERO);
+        assert_eq!(GoldilocksField::conditional_select(&GoldilocksField::ONE, &GoldilocksField::TWO, Choice::from(1)), GoldilocksField::TWO);
+    }
+
+    #[test]
+    fn sqrt_ratio_follows_ff_semantics() {
+        let (zero, one, seven) = (GoldilocksField::ZERO, GoldilocksField::ONE, GoldilocksField::MULTIPLICATIVE_GENERATOR);
+        assert_eq!(ff_sqrt_ratio(&zero, &zero), (true, zero));
+        assert_eq!(ff_sqrt_ratio(&zero, &seven), (true, zero));
+        assert_eq!(ff_sqrt_ratio(&one, &zero), (false, zero));
+
+        let (is_square, root) = ff_sqrt_ratio(&GoldilocksField::from(9u64), &GoldilocksField::from(4u64));
+        assert!(is_square);
+        assert_eq!(root.square() * GoldilocksField::from(4u64), GoldilocksField::from(9u64));
+
+        // A non-square ratio yields sqrt(7 * num / div).
+        let div = GoldilocksField::from(3u64);
+        let (is_square, root) = ff_sqrt_ratio(&seven, &div);
+        assert!(!is_square);
+        assert_eq!(root.square() * div, seven * seven);
+    }
+
+    #[test]
+    fn random_draws_canonical_elements_deterministically() {
+        let a: Vec<_> = (0..100).map(|i| GoldilocksField::random(SplitMix64(i))).collect();
+        let b: Vec<_> = (0..100).map(|i| GoldilocksField::random(SplitMix64(i))).collect();
+        assert_eq!(a, b);
+        let mut rng = SplitMix64(7);
+        let draws: Vec<_> = (0..100).map(|_| GoldilocksField::random(&mut rng)).collect();
+        assert!(draws.iter().all(|x| x.to_canonical_u64() < MODULUS));
+        assert!(draws.windows(2).any(|w| w[0] != w[1]));
+    }
+}
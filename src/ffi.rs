@@ -0,0 +1,211 @@
+//! C interface, behind the `capi` feature.
+//!
+//! Elements cross the boundary as canonical `uint64_t` values; any input of p
+//! or more is rejected with `NonCanonical`. Every function returns a
+//! `GoldilocksStatus` and writes its results through caller-owned pointers, so
+//! no memory is allocated for the caller and none needs freeing. A panic is
+//! caught before it reaches the caller and reported as `Panic`.
+//!
+//! The header `include/goldilocks.h` is generated from this module with
+//! `cbindgen --config cbindgen.toml --output include/goldilocks.h`.
+
+use std::panic::{catch_unwind, UnwindSafe};
+use std::slice;
+
+use crate::ntt::{intt_in_place, ntt_in_place};
+use crate::{batch_inverse, GoldilocksField, MODULUS};
+
+/// Result of a `goldilocks_*` call. Outputs are written only on `Ok`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoldilocksStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// An input value was p or more.
+    NonCanonical = 2,
+    /// Zero was inverted.
+    ZeroInverse = 3,
+    /// The buffer length is not supported, e.g. an NTT length that is not a
+    /// power of two or exceeds 2^32.
+    InvalidLength = 4,
+    /// The library panicked; outputs are unspecified.
+    Panic = 5,
+}
+
+/// Runs `f`, reporting a panic as `Panic` instead of unwinding into C.
+fn guard(f: impl FnOnce() -> GoldilocksStatus + UnwindSafe) -> GoldilocksStatus {
+    catch_unwind(f).unwrap_or(GoldilocksStatus::Panic)
+}
+
+fn element(value: u64) -> Result<GoldilocksField, GoldilocksStatus> {
+    if value >= MODULUS {
+        return Err(GoldilocksStatus::NonCanonical);
+    }
+    Ok(GoldilocksField::from_canonical(value))
+}
+
+/// Writes `x` through `out`, which has already been checked for null.
+unsafe fn write(out: *mut u64, x: GoldilocksField) -> GoldilocksStatus {
+    // SAFETY: the caller of the public function guarantees `out` is valid.
+    unsafe { out.write(x.to_canonical_u64()) };
+    GoldilocksStatus::Ok
+}
+
+/// Applies a binary operation to two canonical values.
+unsafe fn binary(a: u64, b: u64, out: *mut u64, op: fn(GoldilocksField, GoldilocksField) -> GoldilocksField) -> GoldilocksStatus {
+    guard(|| {
+        if out.is_null() {
+            return GoldilocksStatus::NullPointer;
+        }
+        match (element(a), element(b)) {
+            // SAFETY: `out` is non-null and valid per the public function.
+            (Ok(a), Ok(b)) => unsafe { write(out, op(a, b)) },
+            (Err(status), _) | (_, Err(status)) => status,
+        }
+    })
+}
+
+/// Runs `f` on the elements of a caller-owned buffer and writes the results
+/// back. On any status other than `Ok` the buffer is left unchanged.
+unsafe fn with_buffer(
+    values: *mut u64,
+    len: usize,
+    f: impl FnOnce(&mut [GoldilocksField]) -> Result<(), GoldilocksStatus> + UnwindSafe,
+) -> GoldilocksStatus {
+    guard(|| {
+        if values.is_null() {
+            return GoldilocksStatus::NullPointer;
+        }
+        // SAFETY: the caller guarantees `values` is aligned and valid for
+        // `len` reads and writes, with no other access during the call.
+        let raw = unsafe { slice::from_raw_parts_mut(values, len) };
+        let mut elements = match raw.iter().map(|&x| element(x)).collect::<Result<Vec<_>, _>>() {
+            Ok(elements) => elements,
+            Err(status) => return status,
+        };
+        if let Err(status) = f(&mut elements) {
+            return status;
+        }
+        for (out, x) in raw.iter_mut().zip(&elements) {
+            *out = x.to_canonical_u64();
+        }
+        GoldilocksStatus::Ok
+    })
+}
+
+/// Writes (a + b) mod p to `*out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing one `uint64_t`, aligned to 8
+/// bytes. It stays owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn goldilocks_add(a: u64, b: u64, out: *mut u64) -> GoldilocksStatus {
+    unsafe { binary(a, b, out, |a, b| a + b) }
+}
+
+/// Writes (a - b) mod p to `*out`.
+///
+/// # Safety
+///
+/// As for `goldilocks_add`.
+#[no_mangle]
+pub unsafe extern "C" fn goldilocks_sub(a: u64, b: u64, out: *mut u64) -> GoldilocksStatus {
+    unsafe { binary(a, b, out, |a, b| a - b) }
+}
+
+/// Writes (a * b) mod p to `*out`.
+///
+/// # Safety
+///
+/// As for `goldilocks_add`.
+#[no_mangle]
+pub unsafe extern "C" fn goldilocks_mul(a: u64, b: u64, out: *mut u64) -> GoldilocksStatus {
+    unsafe { binary(a, b, out, |a, b| a * b) }
+}
+
+/// Writes a^-1 mod p to `*out`, or returns `ZeroInverse` for a = 0.
+///
+/// # Safety
+///
+/// As for `goldilocks_add`.
+#[no_mangle]
+pub unsafe extern "C" fn goldilocks_inv(a: u64, out: *mut u64) -> GoldilocksStatus {
+    guard(|| {
+        if out.is_null() {
+            return GoldilocksStatus::NullPointer;
+        }
+        match element(a) {
+            Ok(a) if a == GoldilocksField::ZERO => GoldilocksStatus::ZeroInverse,
+            // SAFETY: `out` is non-null and valid per the contract above.
+            Ok(a) => unsafe { write(out, GoldilocksField::ONE / a) },
+            Err(status) => status,
+        }
+    })
+}
+
+/// Replaces the `len` values at `values` by their NTT, or by their inverse
+/// NTT (including the scaling by 1/len) when `inverse` is true. Both sides
+/// are in natural order, as in `ntt::ntt_in_place`. `len` must be a power of
+/// two no larger than 2^32.
+///
+/// # Safety
+///
+/// `values` must be null or point to `len` initialized `uint64_t`s, aligned
+/// to 8 bytes, valid for reads and writes and not accessed by anything else
+/// until the call returns. The buffer stays owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn goldilocks_ntt(values: *mut u64, len: usize, inverse: bool) -> GoldilocksStatus {
+    unsafe {
+        with_buffer(values, len, move |elements| {
+            let result = if inverse { intt_in_place(elements) } else { ntt_in_place(elements) };
+            result.map_err(|_| GoldilocksStatus::InvalidLength)
+        })
+    }
+}
+
+/// Replaces each of the `len` values at `values` by its inverse, using one
+/// field inversion for the whole buffer. Returns `ZeroInverse`, leaving the
+/// buffer unchanged, if any value is zero. An empty buffer is accepted.
+///
+/// # Safety
+///
+/// As for `goldilocks_ntt`.
+#[no_mangle]
+pub unsafe extern "C" fn goldilocks_batch_inverse(values: *mut u64, len: usize) -> GoldilocksStatus {
+    unsafe {
+        with_buffer(values, len, |elements| {
+            let inverses = batch_inverse(elements).map_err(|_| GoldilocksStatus::ZeroInverse)?;
+            elements.copy_from_slice(&inverses);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+
+    #[test]
+    fn panics_are_reported_as_a_status() {
+        assert_eq!(guard(|| panic!("boom")), GoldilocksStatus::Panic);
+        assert_eq!(guard(|| GoldilocksStatus::Ok), GoldilocksStatus::Ok);
+        let mut values = [1u64, 2];
+        let status = unsafe { with_buffer(values.as_mut_ptr(), values.len(), |_| panic!("boom")) };
+        assert_eq!(status, GoldilocksStatus::Panic);
+        assert_eq!(values, [1, 2]);
+    }
+
+    #[test]
+    fn buffers_round_trip_through_elements() {
+        let mut rng = SplitMix64(0xcab1);
+        let mut values: Vec<u64> = (0..100).map(|_| rng.next_element()).collect();
+        let original = values.clone();
+        let status = unsafe { with_buffer(values.as_mut_ptr(), values.len(), |_| Ok(())) };
+        assert_eq!(status, GoldilocksStatus::Ok);
+        assert_eq!(values, original);
+    }
+}
@@ -0,0 +1,93 @@
+//! Exponentiation of a fixed base with precomputed window tables.
+
+use crate::GoldilocksField;
+
+/// Precomputed powers of a fixed base, so that each exponentiation costs one
+/// table lookup and at most one multiplication per window and no squarings.
+///
+/// For a window of w bits the 64-bit exponent is split into ceil(64 / w)
+/// digits, and window i stores base^(d * 2^(w * i)) for every digit d, for a
+/// total of ceil(64 / w) * 2^w elements.
+#[derive(Debug, Clone)]
+pub struct FixedBasePow {
+    window_bits: usize,
+    tables: Vec<Vec<GoldilocksField>>,
+}
+
+impl FixedBasePow {
+    /// Precomputes the tables for `base`.
+    ///
+    /// Panics unless 1 <= window_bits <= 16.
+    pub fn new(base: GoldilocksField, window_bits: usize) -> Self {
+        assert!((1..=16).contains(&window_bits), "FixedBasePow: window_bits must be in 1..=16");
+        let windows = 64usize.div_ceil(window_bits);
+        let mut tables = Vec::with_capacity(windows);
+        // window_base = base^(2^(w * i)) for the current window i.
+        let mut window_base = base;
+        for _ in 0..windows {
+            let mut table = Vec::with_capacity(1 << window_bits);
+            let mut power = GoldilocksField::ONE;
+            for _ in 0..1 << window_bits {
+                table.push(power);
+                power *= window_base;
+            }
+            // The loop ends with power = window_base^(2^w), the next window's base.
+            window_base = power;
+            tables.push(table);
+        }
+        FixedBasePow { window_bits, tables }
+    }
+
+    /// Returns base^exponent.
+    pub fn pow(&self, exponent: u64) -> GoldilocksField {
+        let mask = (1u64 << self.window_bits) - 1;
+        let mut acc = GoldilocksField::ONE;
+        for (i, table) in self.tables.iter().enumerate() {
+            let digit = (exponent >> (i * self.window_bits)) & mask;
+            if digit != 0 {
+                acc *= table[digit as usize];
+            }
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MODULUS;
+
+    #[test]
+    fn fixed_base_pow_matches_pow_u64() {
+        let base = GoldilocksField::new(0x0123_4567_89ab_cdef);
+        let mut state = 0x00c0_ffeeu64;
+        let mut exponents = vec![0, 1, 2, MODULUS - 2, MODULUS - 1, u64::MAX];
+        for _ in 0..200 {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            exponents.push(state);
+        }
+        for window_bits in [1, 3, 4, 7, 8, 16] {
+            let table = FixedBasePow::new(base, window_bits);
+            for &e in &exponents {
+                assert_eq!(table.pow(e), base.pow_u64(e), "window {window_bits}, exponent {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_base_pow_of_generator_and_zero() {
+        let generator = FixedBasePow::new(GoldilocksField::GENERATOR, 8);
+        assert_eq!(generator.pow(MODULUS - 1), GoldilocksField::ONE);
+        assert_eq!(generator.pow((MODULUS - 1) / 2), GoldilocksField::NEG_ONE);
+
+        let zero = FixedBasePow::new(GoldilocksField::ZERO, 4);
+        assert_eq!(zero.pow(0), GoldilocksField::ONE);
+        assert_eq!(zero.pow(5), GoldilocksField::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "window_bits")]
+    fn fixed_base_pow_rejects_empty_windows() {
+        FixedBasePow::new(GoldilocksField::ONE, 0);
+    }
+}
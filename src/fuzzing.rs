@@ -0,0 +1,114 @@
+//! `arbitrary` support for structured fuzzing, behind the `arbitrary` feature.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{GoldilocksCubicExtension, GoldilocksField, GoldilocksQuadraticExtension, GoldilocksQuinticExtension};
+
+/// Consumes 8 bytes as a little-endian u64 and reduces it modulo p, so every
+/// input maps to a valid element. Missing bytes read as zero, as for the
+/// integer impls.
+impl<'a> Arbitrary<'a> for GoldilocksField {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(GoldilocksField::new(u.arbitrary()?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (8, Some(8))
+    }
+}
+
+macro_rules! impl_extension_arbitrary {
+    ($ty:ident, $degree:literal) => {
+        /// Consumes 8 bytes per coefficient, lowest degree first.
+        impl<'a> Arbitrary<'a> for $ty {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let mut coefficients = [GoldilocksField::ZERO; $degree];
+                for c in coefficients.iter_mut() {
+                    *c = u.arbitrary()?;
+                }
+                Ok(Self::new(coefficients))
+            }
+
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                (8 * $degree, Some(8 * $degree))
+            }
+        }
+    };
+}
+
+impl_extension_arbitrary!(GoldilocksQuadraticExtension, 2);
+impl_extension_arbitrary!(GoldilocksCubicExtension, 3);
+impl_extension_arbitrary!(GoldilocksQuinticExtension, 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+    use lambdaworks_math::traits::{AsBytes, Deserializable};
+
+    fn fuzz_input(seed: u64, len: usize) -> Vec<u8> {
+        let mut rng = SplitMix64(seed);
+        let mut bytes: Vec<u8> = (0..len).map(|_| rng.next_u64() as u8).collect();
+        // Make sure the encodings just below and above p are covered.
+        bytes[..8].copy_from_slice(&(MODULUS - 1).to_le_bytes());
+        bytes[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+        bytes[16..24].copy_from_slice(&MODULUS.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn arbitrary_elements_are_canonical_and_round_trip() {
+        let bytes = fuzz_input(84, 8 * 1000);
+        let mut u = Unstructured::new(&bytes);
+        let mut count = 0;
+        while !u.is_empty() {
+            let x: GoldilocksField = u.arbitrary().unwrap();
+            assert!(x.value < MODULUS);
+            assert!(x.to_canonical_u64() < MODULUS);
+            assert_eq!(GoldilocksField::deserialize(&x.as_bytes()).unwrap(), x);
+            count += 1;
+        }
+        assert_eq!(count, 1000);
+
+        let mut u = Unstructured::new(&bytes[..24]);
+        let edges: [GoldilocksField; 3] = core::array::from_fn(|_| u.arbitrary().unwrap());
+        assert_eq!(edges, [MODULUS - 1, u64::MAX - MODULUS, 0].map(GoldilocksField::new));
+    }
+
+    #[test]
+    fn arbitrary_extension_elements_round_trip() {
+        let bytes = fuzz_input(85, 8 * 3000);
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..100 {
+            let a: GoldilocksQuadraticExtension = u.arbitrary().unwrap();
+            assert!(a.coefficients().iter().all(|c| c.value < MODULUS));
+            assert_eq!(GoldilocksQuadraticExtension::deserialize(&a.as_bytes()).unwrap(), a);
+            let b: GoldilocksCubicExtension = u.arbitrary().unwrap();
+            assert!(b.coefficients().iter().all(|c| c.value < MODULUS));
+            assert_eq!(GoldilocksCubicExtension::deserialize(&b.as_bytes()).unwrap(), b);
+            let c: GoldilocksQuinticExtension = u.arbitrary().unwrap();
+            assert!(c.coefficients().iter().all(|c| c.value < MODULUS));
+            assert_eq!(GoldilocksQuinticExtension::deserialize(&c.as_bytes()).unwrap(), c);
+        }
+    }
+
+    #[test]
+    fn size_hints_are_exact() {
+        let check = |hint: (usize, Option<usize>), consumed: usize| assert_eq!(hint, (consumed, Some(consumed)));
+        let bytes = [0x5a; 64];
+        let mut u = Unstructured::new(&bytes);
+        GoldilocksField::arbitrary(&mut u).unwrap();
+        check(GoldilocksField::size_hint(0), 64 - u.len());
+
+        let mut u = Unstructured::new(&bytes);
+        GoldilocksQuadraticExtension::arbitrary(&mut u).unwrap();
+        check(GoldilocksQuadraticExtension::size_hint(0), 64 - u.len());
+        let mut u = Unstructured::new(&bytes);
+        GoldilocksCubicExtension::arbitrary(&mut u).unwrap();
+        check(GoldilocksCubicExtension::size_hint(0), 64 - u.len());
+        let mut u = Unstructured::new(&bytes);
+        GoldilocksQuinticExtension::arbitrary(&mut u).unwrap();
+        check(GoldilocksQuinticExtension::size_hint(0), 64 - u.len());
+    }
+}
@@ -0,0 +1,13 @@
+//! Field-native hash functions over Goldilocks.
+
+pub mod monolith;
+pub mod poseidon;
+pub mod poseidon2;
+pub mod rescue;
+mod sponge;
+pub mod tip5;
+
+#[cfg(test)]
+mod test_utils;
+
+pub use sponge::{AbsorbMode, Padding, Permutation, Sponge};
@@ -0,0 +1,235 @@
+//! The Monolith-64 permutation over Goldilocks (Grassi, Khovratovich,
+//! Lueftenegger, Rechberger, Schofnegger, Walch) with state width 12.
+//!
+//! After an initial concrete layer, each of the 6 rounds applies the bars
+//! layer (an 8-bit chi-like S-box on the bytes of the first 4 elements), the
+//! bricks layer (x_i += x_(i-1)^2) and the concrete layer (a circulant MDS
+//! matrix), followed by round constants in all but the last round. The
+//! round constants are the specification's SHAKE128 stream, and the
+//! permutation reproduces the reference implementation's test vector.
+
+use super::{Permutation, Sponge};
+use crate::{reduce_u128, GoldilocksField};
+
+pub const WIDTH: usize = 12;
+pub const RATE: usize = 8;
+pub const CAPACITY: usize = WIDTH - RATE;
+pub const DIGEST_LEN: usize = 4;
+pub const ROUNDS: usize = 6;
+/// Number of state elements that go through the bars layer.
+pub const BARS: usize = 4;
+
+/// First row of the circulant MDS matrix of the concrete layer.
+pub const MDS_FIRST_ROW: [u64; WIDTH] = [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8];
+
+/// Round constants of the first ROUNDS - 1 rounds: 64-bit little-endian
+/// words below p from SHAKE128("Monolith" || 12 || 6 || p as 8 little-endian
+/// bytes || the 8 limb sizes 8, ..., 8), in order.
+#[rustfmt::skip]
+pub const ROUND_CONSTANTS: [[u64; WIDTH]; ROUNDS - 1] = [
+    [
+        0xbcaf2516e5926dcf, 0x4ec5a76bce1e7676, 0x9d804725bebb56ab, 0x2ec05fca215a5be3,
+        0xe16274e4acab86a0, 0x80b0fddcc3c4380f, 0xc87c769ad77ffece, 0x37f85ec9117d287c,
+        0x3b8d825b014c458d, 0xb7a01d0cb850d75e, 0x1333b751bac704bd, 0x7b7ef14183d47b6f,
+    ],
+    [
+        0x2114517643e3b286, 0x542d15ea3cd12ade, 0xe847d363f17a93e9, 0x24f0421c6ff41c56,
+        0x66e3eda93e2ca216, 0xfb88d475279cb568, 0x7f421c6269938a22, 0xdbb973acce857401,
+        0xe172409cb1563a6a, 0x996f729f6340447d, 0x925c579738b6fa4a, 0x752e9ec9e0b34686,
+    ],
+    [
+        0xdb419e0bd38469bd, 0xba41cee828bd26d8, 0xd6630f8f0969db39, 0x2340e955ae2f0d94,
+        0x282f553d35872e2e, 0x77f7c3ff1ae496b3, 0xf5f2efab64bc5eef, 0x47b23a00830284f4,
+        0x0e18a2d2242486fa, 0x3d101838a773dab0, 0x47d686fd16856524, 0x3eb2d254189b3534,
+    ],
+    [
+        0xfe886e291ca8c5bd, 0xb97ec74df1e4b0b6, 0x574fdef3a600e370, 0x8ad61c6f132d4feb,
+        0x41e69ca4ecc7e8c7, 0x151ad562e1f90ca4, 0x747c051439a5603c, 0x990151d3e52d502c,
+        0x532c7f258282ea12, 0x065e62cb34275dd5, 0x5288008954f5d0b2, 0xee7c3407cf3d6e02,
+    ],
+    [
+        0xda07029808bad5de, 0x7bebdf38dcc7a673, 0x20a3f252688c312d, 0x9c5248f7bbf8d188,
+        0xcf1cf778994382d4, 0x8c434b1738b8338c, 0xfe504398813b67a8, 0xe879562fdef813b9,
+        0xd4666793b2a2f191, 0xd9096b87de22de01, 0xcaf4cea5f22abf34, 0x3128d1e75d0204fa,
+    ],
+];
+
+/// Rotates every byte of `v` left by `n` bits.
+fn rotate_bytes_left(v: u64, n: u32) -> u64 {
+    let high = ((0xffu64 << (8 - n)) & 0xff) * 0x0101_0101_0101_0101;
+    ((v & high) >> (8 - n)) | ((v & !high) << n)
+}
+
+/// The bar S-box on each byte y of the canonical value:
+/// (y ^ (!(y <<< 1) & (y <<< 2) & (y <<< 3))) <<< 1. It fixes 0x00 and 0xff,
+/// so outputs stay below p.
+fn bar(x: GoldilocksField) -> GoldilocksField {
+    let x = x.to_canonical_u64();
+    let t = x ^ (!rotate_bytes_left(x, 1) & rotate_bytes_left(x, 2) & rotate_bytes_left(x, 3));
+    GoldilocksField::from_canonical(rotate_bytes_left(t, 1))
+}
+
+/// x_i += x_(i-1)^2 for i >= 1, using the inputs of the layer.
+fn bricks(state: &mut [GoldilocksField; WIDTH]) {
+    for i in (1..WIDTH).rev() {
+        state[i] += state[i - 1] * state[i - 1];
+    }
+}
+
+/// state <- M * state with M[i][j] = MDS_FIRST_ROW[(j - i) mod 12]; the small
+/// entries let the products accumulate in a u128 before reducing.
+fn concrete(state: &mut [GoldilocksField; WIDTH]) {
+    let input = state.map(|x| x.to_canonical_u64() as u128);
+    for (i, out) in state.iter_mut().enumerate() {
+        let acc = input.iter().enumerate().map(|(j, x)| *x * MDS_FIRST_ROW[(j + WIDTH - i) % WIDTH] as u128).sum();
+        *out = GoldilocksField::from_canonical(reduce_u128(acc));
+    }
+}
+
+fn round(state: &mut [GoldilocksField; WIDTH]) {
+    for x in &mut state[..BARS] {
+        *x = bar(*x);
+    }
+    bricks(state);
+    concrete(state);
+}
+
+/// Applies the Monolith-64 permutation in place.
+pub fn permutation(state: &mut [GoldilocksField; WIDTH]) {
+    concrete(state);
+    for constants in &ROUND_CONSTANTS {
+        round(state);
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x += GoldilocksField::from_canonical(*c);
+        }
+    }
+    round(state);
+}
+
+/// Monolith-64 as a [`Permutation`] for [`Sponge`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Monolith;
+
+impl Permutation<WIDTH> for Monolith {
+    fn permute(&self, state: &mut [GoldilocksField; WIDTH]) {
+        permutation(state);
+    }
+}
+
+/// The sponge behind `hash`: overwrite absorption with 10* padding.
+pub type MonolithSponge = Sponge<Monolith, RATE, CAPACITY, WIDTH>;
+
+/// Hashes two digests into one, for Merkle tree nodes: the state is
+/// left || right padded with zeros, and the digest is the first four elements
+/// after one permutation.
+pub fn hash_two_to_one(
+    left: &[GoldilocksField; DIGEST_LEN],
+    right: &[GoldilocksField; DIGEST_LEN],
+) -> [GoldilocksField; DIGEST_LEN] {
+    let mut state = [GoldilocksField::ZERO; WIDTH];
+    state[..DIGEST_LEN].copy_from_slice(left);
+    state[DIGEST_LEN..2 * DIGEST_LEN].copy_from_slice(right);
+    permutation(&mut state);
+    let mut digest = [GoldilocksField::ZERO; DIGEST_LEN];
+    digest.copy_from_slice(&state[..DIGEST_LEN]);
+    digest
+}
+
+/// Sponge hash: the input is padded with a one and then zeros to a multiple
+/// of `RATE`, each block overwrites the rate part of the state before a
+/// permutation, and the digest is the first four elements.
+pub fn hash(inputs: &[GoldilocksField]) -> [GoldilocksField; DIGEST_LEN] {
+    let mut sponge = MonolithSponge::new(Monolith);
+    sponge.absorb(inputs);
+    sponge.squeeze_array()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::test_utils::{assert_matrix_product, elements, permuted};
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+
+    /// The bar S-box one byte at a time.
+    fn naive_bar(x: u64) -> u64 {
+        let bytes = x.to_le_bytes().map(|y| {
+            let t = y ^ (!y.rotate_left(1) & y.rotate_left(2) & y.rotate_left(3));
+            t.rotate_left(1)
+        });
+        u64::from_le_bytes(bytes)
+    }
+
+    #[test]
+    fn bar_is_a_byte_permutation_fixing_the_extremes() {
+        let mut seen = [false; 256];
+        for y in 0..=255u64 {
+            seen[naive_bar(y) as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+        assert_eq!(naive_bar(0), 0);
+        assert_eq!(naive_bar(u64::MAX), u64::MAX);
+
+        let mut rng = SplitMix64(63);
+        for x in [0, 1, 0xff, MODULUS - 1].into_iter().chain((0..10_000).map(|_| rng.next_element())) {
+            let out = bar(GoldilocksField::from_canonical(x)).to_canonical_u64();
+            assert_eq!(out, naive_bar(x), "{x:#x}");
+            assert!(out < MODULUS);
+        }
+    }
+
+    #[test]
+    fn concrete_matches_the_matrix_product() {
+        assert_matrix_product(concrete, |r, c| GoldilocksField::new(MDS_FIRST_ROW[(c + WIDTH - r) % WIDTH]));
+    }
+
+    #[test]
+    fn round_constants_rederive() {
+        use sha3::digest::{ExtendableOutput, Update, XofReader};
+        let mut shake = sha3::Shake128::default();
+        shake.update(b"Monolith");
+        shake.update(&[WIDTH as u8, ROUNDS as u8]);
+        shake.update(&MODULUS.to_le_bytes());
+        shake.update(&[8; 8]);
+        let mut reader = shake.finalize_xof();
+        for (round, constants) in ROUND_CONSTANTS.iter().enumerate() {
+            for (i, constant) in constants.iter().enumerate() {
+                let value = loop {
+                    let mut word = [0u8; 8];
+                    reader.read(&mut word);
+                    let value = u64::from_le_bytes(word);
+                    if value < MODULUS {
+                        break value;
+                    }
+                };
+                assert_eq!(value, *constant, "round {round}, constant {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn permutation_matches_the_reference_implementation() {
+        // The Goldilocks width-12 test vector of the Monolith reference
+        // implementation.
+        assert_eq!(
+            permuted(permutation, core::array::from_fn(|i| i as u64)),
+            elements([
+                5867581605548782913, 588867029099903233, 6043817495575026667, 805786589926590032,
+                9919982299747097782, 6718641691835914685, 7951881005429661950, 15453177927755089358,
+                974633365445157727, 9654662171963364206, 6281307445101925412, 13745376999934453119,
+            ])
+        );
+    }
+
+    #[test]
+    fn hash_known_answers() {
+        // Regression values for the sponge and compression built on the
+        // permutation checked above.
+        let inputs: Vec<GoldilocksField> = (0..10u64).map(GoldilocksField::new).collect();
+        assert_eq!(hash(&inputs), elements([0xf134bfc8a60b5f9d, 0x8c48960653366aa2, 0x7c9bc51c2990f7cf, 0x34e1a7d64716fac7]));
+        assert_eq!(
+            hash_two_to_one(&elements([1, 2, 3, 4]), &elements([5, 6, 7, 8])),
+            elements([0x794c4b4308cb8286, 0xe6ca7b9c49970427, 0x89b2e0614bc0af93, 0xd0f63984b0d43850])
+        );
+    }
+}
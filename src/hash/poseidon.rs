@@ -0,0 +1,468 @@
+//! The Poseidon permutation with the Goldilocks parameters used by plonky2:
+//! the x^7 S-box, 4 + 4 full rounds around 22 partial rounds, and widths 8
+//! and 12.
+//!
+//! Width 12 matches plonky2's `Poseidon` for `GoldilocksField` bit for bit.
+//! Width 8 takes its round constants from the same table and uses the
+//! power-of-two circulant [4, 1, 2, 256, 16, 8, 1, 1] as its MDS matrix.
+
+use super::{AbsorbMode, Padding, Permutation, Sponge};
+use crate::{reduce_u128, GoldilocksField};
+
+/// Full rounds, split evenly before and after the partial rounds.
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 22;
+pub const ROUNDS: usize = FULL_ROUNDS + PARTIAL_ROUNDS;
+
+/// Number of capacity elements, and of elements in a digest.
+pub const CAPACITY: usize = 4;
+pub const DIGEST_LEN: usize = 4;
+
+/// First row of the width-12 circulant MDS matrix.
+pub const MDS_CIRC_12: [u64; 12] = [17, 15, 41, 16, 2, 28, 13, 13, 39, 18, 34, 20];
+/// Diagonal added to the width-12 circulant.
+pub const MDS_DIAG_12: [u64; 12] = [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+/// First row of the width-8 circulant MDS matrix.
+pub const MDS_CIRC_8: [u64; 8] = [4, 1, 2, 256, 16, 8, 1, 1];
+/// Diagonal added to the width-8 circulant.
+pub const MDS_DIAG_8: [u64; 8] = [0; 8];
+
+/// Round constants: round r adds entries WIDTH * r .. WIDTH * (r + 1), so
+/// width 8 uses the first 240. These are the first 360 outputs of
+/// `ChaCha8Rng::seed_from_u64(0)` sampled uniformly below p, as in plonky2.
+#[rustfmt::skip]
+pub const ROUND_CONSTANTS: [u64; 12 * ROUNDS] = [
+    0xb585f766f2144405, 0x7746a55f43921ad7, 0xb2fb0d31cee799b4, 0x0f6760a4803427d7,
+    0xe10d666650f4e012, 0x8cae14cb07d09bf1, 0xd438539c95f63e9f, 0xef781c7ce35b4c3d,
+    0xcdc4a239b0c44426, 0x277fa208bf337bff, 0xe17653a29da578a1, 0xc54302f225db2c76,
+    0x86287821f722c881, 0x59cd1a8a41c18e55, 0xc3b919ad495dc574, 0xa484c4c5ef6a0781,
+    0x308bbd23dc5416cc, 0x6e4a40c18f30c09c, 0x9a2eedb70d8f8cfa, 0xe360c6e0ae486f38,
+    0xd5c7718fbfc647fb, 0xc35eae071903ff0b, 0x849c2656969c4be7, 0xc0572c8c08cbbbad,
+    0xe9fa634a21de0082, 0xf56f6d48959a600d, 0xf7d713e806391165, 0x8297132b32825daf,
+    0xad6805e0e30b2c8a, 0xac51d9f5fcf8535e, 0x502ad7dc18c2ad87, 0x57a1550c110b3041,
+    0x66bbd30e6ce0e583, 0x0da2abef589d644e, 0xf061274fdb150d61, 0x28b8ec3ae9c29633,
+    0x92a756e67e2b9413, 0x70e741ebfee96586, 0x019d5ee2af82ec1c, 0x6f6f2ed772466352,
+    0x7cf416cfe7e14ca1, 0x61df517b86a46439, 0x85dc499b11d77b75, 0x4b959b48b9c10733,
+    0xe8be3e5da8043e57, 0xf5c0bc1de6da8699, 0x40b12cbf09ef74bf, 0xa637093ecb2ad631,
+    0x3cc3f892184df408, 0x2e479dc157bf31bb, 0x6f49de07a6234346, 0x213ce7bede378d7b,
+    0x5b0431345d4dea83, 0xa2de45780344d6a1, 0x7103aaf94a7bf308, 0x5326fc0d97279301,
+    0xa9ceb74fec024747, 0x27f8ec88bb21b1a3, 0xfceb4fda1ded0893, 0xfac6ff1346a41675,
+    0x7131aa45268d7d8c, 0x9351036095630f9f, 0xad535b24afc26bfb, 0x4627f5c6993e44be,
+    0x645cf794b8f1cc58, 0x241c70ed0af61617, 0xacb8e076647905f1, 0x3737e9db4c4f474d,
+    0xe7ea5e33e75fffb6, 0x90dee49fc9bfc23a, 0xd1b1edf76bc09c92, 0x0b65481ba645c602,
+    0x99ad1aab0814283b, 0x438a7c91d416ca4d, 0xb60de3bcc5ea751c, 0xc99cab6aef6f58bc,
+    0x69a5ed92a72ee4ff, 0x5e7b329c1ed4ad71, 0x5fc0ac0800144885, 0x32db829239774eca,
+    0x0ade699c5830f310, 0x7cc5583b10415f21, 0x85df9ed2e166d64f, 0x6604df4fee32bcb1,
+    0xeb84f608da56ef48, 0xda608834c40e603d, 0x8f97fe408061f183, 0xa93f485c96f37b89,
+    0x6704e8ee8f18d563, 0xcee3e9ac1e072119, 0x510d0e65e2b470c1, 0xf6323f486b9038f0,
+    0x0b508cdeffa5ceef, 0xf2417089e4fb3cbd, 0x60e75c2890d15730, 0xa6217d8bf660f29c,
+    0x7159cd30c3ac118e, 0x839b4e8fafead540, 0x0d3f3e5e82920adc, 0x8f7d83bddee7bba8,
+    0x780f2243ea071d06, 0xeb915845f3de1634, 0xd19e120d26b6f386, 0x016ee53a7e5fecc6,
+    0xcb5fd54e7933e477, 0xacb8417879fd449f, 0x9c22190be7f74732, 0x5d693c1ba3ba3621,
+    0xdcef0797c2b69ec7, 0x3d639263da827b13, 0xe273fd971bc8d0e7, 0x418f02702d227ed5,
+    0x8c25fda3b503038c, 0x2cbaed4daec8c07c, 0x5f58e6afcdd6ddc2, 0x284650ac5e1b0eba,
+    0x635b337ee819dab5, 0x9f9a036ed4f2d49f, 0xb93e260cae5c170e, 0xb0a7eae879ddb76d,
+    0xd0762cbc8ca6570c, 0x34c6efb812b04bf5, 0x40bf0ab5fa14c112, 0xb6b570fc7c5740d3,
+    0x5a27b9002de33454, 0xb1a5b165b6d2b2d2, 0x8722e0ace9d1be22, 0x788ee3b37e5680fb,
+    0x14a726661551e284, 0x98b7672f9ef3b419, 0xbb93ae776bb30e3a, 0x28fd3b046380f850,
+    0x30a4680593258387, 0x337dc00c61bd9ce1, 0xd5eca244c7a4ff1d, 0x7762638264d279bd,
+    0xc1e434bedeefd767, 0x0299351a53b8ec22, 0xb2d456e4ad251b80, 0x3e9ed1fda49cea0b,
+    0x2972a92ba450bed8, 0x20216dd77be493de, 0xadffe8cf28449ec6, 0x1c4dbb1c4c27d243,
+    0x15a16a8a8322d458, 0x388a128b7fd9a609, 0x2300e5d6baedf0fb, 0x2f63aa8647e15104,
+    0xf1c36ce86ecec269, 0x27181125183970c9, 0xe584029370dca96d, 0x4d9bbc3e02f1cfb2,
+    0xea35bc29692af6f8, 0x18e21b4beabb4137, 0x1e3b9fc625b554f4, 0x25d64362697828fd,
+    0x5a3f1bb1c53a9645, 0xdb7f023869fb8d38, 0xb462065911d4e1fc, 0x49c24ae4437d8030,
+    0xd793862c112b0566, 0xaadd1106730d8feb, 0xc43b6e0e97b0d568, 0xe29024c18ee6fca2,
+    0x5e50c27535b88c66, 0x10383f20a4ff9a87, 0x38e8ee9d71a45af8, 0xdd5118375bf1a9b9,
+    0x775005982d74d7f7, 0x86ab99b4dde6c8b0, 0xb1204f603f51c080, 0xef61ac8470250ecf,
+    0x1bbcd90f132c603f, 0x0cd1dabd964db557, 0x11a3ae5beb9d1ec9, 0xf755bfeea585d11d,
+    0xa3b83250268ea4d7, 0x516306f4927c93af, 0xddb4ac49c9efa1da, 0x64bb6dec369d4418,
+    0xf9cc95c22b4c1fcc, 0x08d37f755f4ae9f6, 0xeec49b613478675b, 0xf143933aed25e0b0,
+    0xe4c5dd8255dfc622, 0xe7ad7756f193198e, 0x92c2318b87fff9cb, 0x739c25f8fd73596d,
+    0x5636cac9f16dfed0, 0xdd8f909a938e0172, 0xc6401fe115063f5b, 0x8ad97b33f1ac1455,
+    0x0c49366bb25e8513, 0x0784d3d2f1698309, 0x530fb67ea1809a81, 0x410492299bb01f49,
+    0x139542347424b9ac, 0x9cb0bd5ea1a1115e, 0x02e3f615c38f49a1, 0x985d4f4a9c5291ef,
+    0x775b9feafdcd26e7, 0x304265a6384f0f2d, 0x593664c39773012c, 0x4f0a2e5fb028f2ce,
+    0xdd611f1000c17442, 0xd8185f9adfea4fd0, 0xef87139ca9a3ab1e, 0x3ba71336c34ee133,
+    0x7d3a455d56b70238, 0x660d32e130182684, 0x297a863f48cd1f43, 0x90e0a736a751ebb7,
+    0x549f80ce550c4fd3, 0x0f73b2922f38bd64, 0x16bf1f73fb7a9c3f, 0x6d1f5a59005bec17,
+    0x02ff876fa5ef97c4, 0xc5cb72a2a51159b0, 0x8470f39d2d5c900e, 0x25abb3f1d39fcb76,
+    0x23eb8cc9b372442f, 0xd687ba55c64f6364, 0xda8d9e90fd8ff158, 0xe3cbdc7d2fe45ea7,
+    0xb9a8c9b3aee52297, 0xc0d28a5c10960bd3, 0x45d7ac9b68f71a34, 0xeeb76e397069e804,
+    0x3d06c8bd1514e2d9, 0x9c9c98207cb10767, 0x65700b51aedfb5ef, 0x911f451539869408,
+    0x7ae6849fbc3a0ec6, 0x3bb340eba06afe7e, 0xb46e9d8b682ea65e, 0x8dcf22f9a3b34356,
+    0x77bdaeda586257a7, 0xf19e400a5104d20d, 0xc368a348e46d950f, 0x9ef1cd60e679f284,
+    0xe89cd854d5d01d33, 0x5cd377dc8bb882a2, 0xa7b0fb7883eee860, 0x7684403ec392950d,
+    0x5fa3f06f4fed3b52, 0x8df57ac11bc04831, 0x2db01efa1e1e1897, 0x54846de4aadb9ca2,
+    0xba6745385893c784, 0x541d496344d2c75b, 0xe909678474e687fe, 0xdfe89923f6c9c2ff,
+    0xece5a71e0cfedc75, 0x5ff98fd5d51fe610, 0x83e8941918964615, 0x5922040b47f150c1,
+    0xf97d750e3dd94521, 0x5080d4c2b86f56d7, 0xa7de115b56c78d70, 0x6a9242ac87538194,
+    0xf7856ef7f9173e44, 0x2265fc92feb0dc09, 0x17dfc8e4f7ba8a57, 0x9001a64209f21db8,
+    0x90004c1371b893c5, 0xb932b7cf752e5545, 0xa0b1df81b6fe59fc, 0x8ef1dd26770af2c2,
+    0x0541a4f9cfbeed35, 0x9e61106178bfc530, 0xb3767e80935d8af2, 0x0098d5782065af06,
+    0x31d191cd5c1466c7, 0x410fefafa319ac9d, 0xbdf8f242e316c4ab, 0x9e8cd55b57637ed0,
+    0xde122bebe9a39368, 0x4d001fd58f002526, 0xca6637000eb4a9f8, 0x2f2339d624f91f78,
+    0x6d1a7918c80df518, 0xdf9a4939342308e9, 0xebc2151ee6c8398c, 0x03cc2ba8a1116515,
+    0xd341d037e840cf83, 0x387cb5d25af4afcc, 0xbba2515f22909e87, 0x7248fe7705f38e47,
+    0x4d61e56a525d225a, 0x262e963c8da05d3d, 0x59e89b094d220ec2, 0x055d5b52b78b9c5e,
+    0x82b27eb33514ef99, 0xd30094ca96b7ce7b, 0xcf5cb381cd0a1535, 0xfeed4db6919e5a7c,
+    0x41703f53753be59f, 0x5eeea940fcde8b6f, 0x4cd1f1b175100206, 0x4a20358574454ec0,
+    0x1478d361dbbf9fac, 0x6f02dc07d141875c, 0x296a202ed8e556a2, 0x2afd67999bf32ee5,
+    0x7acfd96efa95491d, 0x6798ba0c0abb2c6d, 0x34c6f57b26c92122, 0x5736e1bad206b5de,
+    0x20057d2a0056521b, 0x3dea5bd5d0578bd7, 0x16e50d897d4634ac, 0x29bff3ecb9b7a6e3,
+    0x475cd3205a3bdcde, 0x18a42105c31b7e88, 0x023e7414af663068, 0x15147108121967d7,
+    0xe4a3dff1d7d6fef9, 0x01a8d1a588085737, 0x11b4c74eda62beef, 0xe587cc0d69a73346,
+    0x1ff7327017aa2a6e, 0x594e29c42473d06b, 0xf6f31db1899b12d5, 0xc02ac5e47312d3ca,
+    0xe70201e960cb78b8, 0x6f90ff3b6a65f108, 0x42747a7245e7fa84, 0xd1f507e43ab749b2,
+    0x1c86d265f15750cd, 0x3996ce73dd832c1c, 0x8e7fba02983224bd, 0xba0dec7103255dd4,
+    0x9e9cbd781628fc5b, 0xdae8645996edd6a5, 0xdebe0853b1a1d378, 0xa49229d24d014343,
+    0x7be5b9ffda905e1c, 0xa3c95eaec244aa30, 0x0230bca8f4df0544, 0x4135c2bebfe148c6,
+    0x166fc0cc438a3c72, 0x3762b59a8ae83efa, 0xe8928a4c89114750, 0x2a440b51a4945ee5,
+    0x80cefd2b7d99ff83, 0xbb9879c6e61fd62a, 0x6e7c8f1a84265034, 0x164bb2de1bbeddc8,
+    0xf3c12fe54d5c653b, 0x40b9e922ed9771e2, 0x551f5b0fbe7b1840, 0x25032aa7c4cb1811,
+    0xaaed34074b164346, 0x8ffd96bbf9c9c81d, 0x70fc91eb5937085c, 0x7f795e2a5f915440,
+    0x4543d9df5476d3cb, 0xf172d73e004fc90d, 0xdfd1c4febcc81238, 0xbc8dfb627fe558fc,
+];
+
+fn mds<const WIDTH: usize>() -> (&'static [u64], &'static [u64]) {
+    if WIDTH == 8 {
+        (&MDS_CIRC_8, &MDS_DIAG_8)
+    } else {
+        (&MDS_CIRC_12, &MDS_DIAG_12)
+    }
+}
+
+fn sbox(x: GoldilocksField) -> GoldilocksField {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    x * x2 * x4
+}
+
+/// state <- M * state for the circulant-plus-diagonal MDS matrix. The small
+/// coefficients let the 12 products accumulate in a u128 before one reduction.
+fn mds_layer<const WIDTH: usize>(state: &mut [GoldilocksField; WIDTH]) {
+    let (circ, diag) = mds::<WIDTH>();
+    let input = state.map(|x| x.to_canonical_u64() as u128);
+    for (r, out) in state.iter_mut().enumerate() {
+        let mut acc = input[r] * diag[r] as u128;
+        for (i, c) in circ.iter().enumerate() {
+            acc += input[(i + r) % WIDTH] * *c as u128;
+        }
+        *out = GoldilocksField::from_canonical(reduce_u128(acc));
+    }
+}
+
+fn add_round_constants<const WIDTH: usize>(state: &mut [GoldilocksField; WIDTH], round: usize) {
+    let constants = &ROUND_CONSTANTS[WIDTH * round..WIDTH * (round + 1)];
+    for (x, c) in state.iter_mut().zip(constants) {
+        *x += GoldilocksField::from_canonical(*c);
+    }
+}
+
+/// Applies the Poseidon permutation in place. Only widths 8 and 12 are
+/// defined; other widths fail to compile.
+pub fn poseidon_permutation<const WIDTH: usize>(state: &mut [GoldilocksField; WIDTH]) {
+    const { assert!(WIDTH == 8 || WIDTH == 12, "Poseidon is defined for widths 8 and 12") };
+    let half_full = FULL_ROUNDS / 2;
+    for round in 0..ROUNDS {
+        add_round_constants(state, round);
+        if round < half_full || round >= half_full + PARTIAL_ROUNDS {
+            for x in state.iter_mut() {
+                *x = sbox(*x);
+            }
+        } else {
+            state[0] = sbox(state[0]);
+        }
+        mds_layer(state);
+    }
+}
+
+/// Poseidon as a [`Permutation`] for [`Sponge`], at widths 8 and 12.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poseidon;
+
+impl Permutation<8> for Poseidon {
+    fn permute(&self, state: &mut [GoldilocksField; 8]) {
+        poseidon_permutation(state);
+    }
+}
+
+impl Permutation<12> for Poseidon {
+    fn permute(&self, state: &mut [GoldilocksField; 12]) {
+        poseidon_permutation(state);
+    }
+}
+
+/// The sponges behind `hash_n_to_m`, used with overwrite absorption and no
+/// padding.
+pub type PoseidonSponge8 = Sponge<Poseidon, 4, CAPACITY, 8>;
+pub type PoseidonSponge12 = Sponge<Poseidon, 8, CAPACITY, 12>;
+
+/// Sponge hash in overwrite mode without padding, like plonky2's
+/// `hash_n_to_m_no_pad`: inputs replace the first WIDTH - 4 elements of the
+/// state a chunk at a time, and `num_outputs` elements are read back from the
+/// same positions. Callers hashing inputs of different lengths must
+/// domain-separate them themselves.
+pub fn hash_n_to_m<const WIDTH: usize>(inputs: &[GoldilocksField], num_outputs: usize) -> Vec<GoldilocksField> {
+    const { assert!(WIDTH == 8 || WIDTH == 12, "Poseidon is defined for widths 8 and 12") };
+    if WIDTH == 8 {
+        let mut sponge = PoseidonSponge8::with_options(Poseidon, AbsorbMode::Overwrite, Padding::None);
+        sponge.absorb(inputs);
+        sponge.squeeze(num_outputs)
+    } else {
+        let mut sponge = PoseidonSponge12::with_options(Poseidon, AbsorbMode::Overwrite, Padding::None);
+        sponge.absorb(inputs);
+        sponge.squeeze(num_outputs)
+    }
+}
+
+/// Hashes two digests into one, for Merkle tree nodes: the state is
+/// left || right padded with zeros, and the digest is the first four elements
+/// after one permutation.
+pub fn hash_two_to_one<const WIDTH: usize>(
+    left: &[GoldilocksField; DIGEST_LEN],
+    right: &[GoldilocksField; DIGEST_LEN],
+) -> [GoldilocksField; DIGEST_LEN] {
+    let mut state = [GoldilocksField::ZERO; WIDTH];
+    state[..DIGEST_LEN].copy_from_slice(left);
+    state[DIGEST_LEN..2 * DIGEST_LEN].copy_from_slice(right);
+    poseidon_permutation(&mut state);
+    let mut digest = [GoldilocksField::ZERO; DIGEST_LEN];
+    digest.copy_from_slice(&state[..DIGEST_LEN]);
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::test_utils::{assert_matrix_product, elements, permuted};
+    use crate::MODULUS;
+
+    #[test]
+    fn width_12_matches_plonky2() {
+        // Test vectors from plonky2's poseidon_goldilocks tests.
+        let iota: [u64; 12] = core::array::from_fn(|i| i as u64);
+        let vectors: [([u64; 12], [u64; 12]); 3] = [
+            (
+                [0; 12],
+                [
+                    0x3c18a9786cb0b359, 0xc4055e3364a246c3, 0x7953db0ab48808f4, 0xc71603f33a1144ca,
+                    0xd7709673896996dc, 0x46a84e87642f44ed, 0xd032648251ee0b3c, 0x1c687363b207df62,
+                    0xdf8565563e8045fe, 0x40f5b37ff4254dae, 0xd070f637b431067c, 0x1792b1c4342109d7,
+                ],
+            ),
+            (
+                iota,
+                [
+                    0xd64e1e3efc5b8e9e, 0x53666633020aaa47, 0xd40285597c6a8825, 0x613a4f81e81231d2,
+                    0x414754bfebd051f0, 0xcb1f8980294a023f, 0x6eb2a9e4d54a9d0f, 0x1902bc3af467e056,
+                    0xf045d5eafdc6021f, 0xe4150f77caaa3be5, 0xc9bfd01d39b50cce, 0x5c0a27fcb0e1459b,
+                ],
+            ),
+            (
+                [MODULUS - 1; 12],
+                [
+                    0xbe0085cfc57a8357, 0xd95af71847d05c09, 0xcf55a13d33c1c953, 0x95803a74f4530e82,
+                    0xfcd99eb30a135df1, 0xe095905e913a3029, 0xde0392461b42919b, 0x7d3260e24e81d031,
+                    0x10d3d0465d9deaa0, 0xa87571083dfc2a47, 0xe18263681e9958f8, 0xe28e96f1ae5e60d3,
+                ],
+            ),
+        ];
+        for (input, expected) in vectors {
+            assert_eq!(permuted(poseidon_permutation, input), elements(expected), "input {input:x?}");
+        }
+    }
+
+    #[test]
+    fn width_8_known_answers() {
+        // Computed with an independent model of the parameters above; there is
+        // no current upstream width-8 instance to compare against.
+        assert_eq!(
+            permuted(poseidon_permutation, [0; 8]),
+            elements([
+                0x4221145f6f3afe33, 0xea725f6a00a0f7a9, 0x956d643c63282941, 0xe63292c16a248ec2,
+                0xe70c96ce27ffef9d, 0xd7a47ddd3e9bbd00, 0xa9268be3865a7476, 0xdd44f93f440b242a,
+            ])
+        );
+        assert_eq!(
+            permuted(poseidon_permutation, core::array::from_fn(|i| i as u64)),
+            elements([
+                0xc136f8693348feb8, 0xe6547a16f560f0ca, 0xc095991af10622c1, 0xb9b81ceaea911eab,
+                0xc70bdc7ea89188d0, 0x2033daa3b367fbe7, 0x14aafc4b79bbc1e5, 0x421b8d2a969b225f,
+            ])
+        );
+    }
+
+    #[test]
+    fn sponge_known_answers() {
+        let inputs: Vec<GoldilocksField> = (0..10u64).map(GoldilocksField::new).collect();
+        let long = hash_n_to_m::<12>(&inputs, 10);
+        assert_eq!(
+            long,
+            elements([
+                0xf2c9b602d5dc31f4, 0x914f17a76f289ecc, 0x0bcae24b87e24834, 0xd1fa70baefe919e0,
+                0x8ad1ea061682be8b, 0xbd88b7bc4056f6ec, 0xfa7595add22a1ecb, 0x7168c60611a007b0,
+                0x09f26802265334fc, 0x2215d55274a55485,
+            ])
+        );
+        // Shorter outputs are prefixes of longer ones.
+        assert_eq!(hash_n_to_m::<12>(&inputs, 4), long[..4]);
+        assert_eq!(
+            hash_n_to_m::<8>(&inputs, 4),
+            elements([0xbd70b368d51a0fe6, 0x5da52cde8e3bf449, 0xaff5d2b82777b996, 0x1e72a442acbebdf8])
+        );
+        assert!(hash_n_to_m::<12>(&inputs, 0).is_empty());
+    }
+
+    #[test]
+    fn two_to_one_known_answers() {
+        let left = elements([1, 2, 3, 4]);
+        let right = elements([5, 6, 7, 8]);
+        assert_eq!(
+            hash_two_to_one::<12>(&left, &right),
+            elements([0xd110aa6a46373941, 0x8f238fcceb658894, 0x9cd4f8353866fb4f, 0x274913f0007aa232])
+        );
+        assert_eq!(
+            hash_two_to_one::<8>(&left, &right),
+            elements([0xe125c58ec60562e3, 0x4fb07ef35e8d7992, 0xf46ea99f2db1e7a2, 0xa3b0bbb616708a94])
+        );
+        assert_ne!(hash_two_to_one::<12>(&left, &right), hash_two_to_one::<12>(&right, &left));
+    }
+
+    /// ChaCha8 as in `rand_chacha` 0.3, seeded through `seed_from_u64`, with
+    /// `rand` 0.8's `gen_range(0..MODULUS)` on top.
+    struct ChaCha8 {
+        key: [u32; 8],
+        counter: u64,
+        block: [u32; 16],
+        index: usize,
+    }
+
+    impl ChaCha8 {
+        fn seed_from_u64(mut state: u64) -> Self {
+            let mut key = [0u32; 8];
+            for word in key.iter_mut() {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(11634580027462260723);
+                let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+                *word = xorshifted.rotate_right((state >> 59) as u32);
+            }
+            ChaCha8 { key, counter: 0, block: [0; 16], index: 16 }
+        }
+
+        fn refill(&mut self) {
+            let mut input = [0u32; 16];
+            input[..4].copy_from_slice(&[0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574]);
+            input[4..12].copy_from_slice(&self.key);
+            input[12] = self.counter as u32;
+            input[13] = (self.counter >> 32) as u32;
+            let mut s = input;
+            let mut quarter = |a: usize, b: usize, c: usize, d: usize| {
+                s[a] = s[a].wrapping_add(s[b]);
+                s[d] = (s[d] ^ s[a]).rotate_left(16);
+                s[c] = s[c].wrapping_add(s[d]);
+                s[b] = (s[b] ^ s[c]).rotate_left(12);
+                s[a] = s[a].wrapping_add(s[b]);
+                s[d] = (s[d] ^ s[a]).rotate_left(8);
+                s[c] = s[c].wrapping_add(s[d]);
+                s[b] = (s[b] ^ s[c]).rotate_left(7);
+            };
+            for _ in 0..4 {
+                quarter(0, 4, 8, 12);
+                quarter(1, 5, 9, 13);
+                quarter(2, 6, 10, 14);
+                quarter(3, 7, 11, 15);
+                quarter(0, 5, 10, 15);
+                quarter(1, 6, 11, 12);
+                quarter(2, 7, 8, 13);
+                quarter(3, 4, 9, 14);
+            }
+            for (out, (x, y)) in self.block.iter_mut().zip(s.iter().zip(&input)) {
+                *out = x.wrapping_add(*y);
+            }
+            self.counter += 1;
+            self.index = 0;
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            if self.index == 16 {
+                self.refill();
+            }
+            self.index += 1;
+            self.block[self.index - 1]
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let lo = self.next_u32() as u64;
+            lo | (self.next_u32() as u64) << 32
+        }
+
+        fn below_modulus(&mut self) -> u64 {
+            // Widening multiply with rejection; since MODULUS has no leading
+            // zeros, the low half is accepted when it is below MODULUS.
+            loop {
+                let product = self.next_u64() as u128 * MODULUS as u128;
+                if (product as u64) < MODULUS {
+                    return (product >> 64) as u64;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_constants_rederive() {
+        let mut rng = ChaCha8::seed_from_u64(0);
+        for (i, c) in ROUND_CONSTANTS.iter().enumerate() {
+            assert_eq!(rng.below_modulus(), *c, "constant {i}");
+        }
+    }
+
+    fn determinant(mut m: Vec<Vec<GoldilocksField>>) -> GoldilocksField {
+        let n = m.len();
+        let mut det = GoldilocksField::ONE;
+        for i in 0..n {
+            let Some(pivot) = (i..n).find(|r| m[*r][i] != GoldilocksField::ZERO) else {
+                return GoldilocksField::ZERO;
+            };
+            if pivot != i {
+                m.swap(i, pivot);
+                det = -det;
+            }
+            det *= m[i][i];
+            let inv = GoldilocksField::ONE / m[i][i];
+            let (upper, lower) = m.split_at_mut(i + 1);
+            for row in lower {
+                let factor = row[i] * inv;
+                for (x, y) in row[i..].iter_mut().zip(&upper[i][i..]) {
+                    *x -= factor * *y;
+                }
+            }
+        }
+        det
+    }
+
+    /// All k-element subsets of 0..n, in increasing order.
+    fn subsets(n: usize, k: u32) -> impl Iterator<Item = Vec<usize>> {
+        (0u32..1 << n).filter(move |m| m.count_ones() == k).map(move |m| (0..n).filter(|i| m >> i & 1 == 1).collect())
+    }
+
+    #[test]
+    fn width_8_matrix_is_mds() {
+        // Every square submatrix of an MDS matrix is nonsingular.
+        let entry = |r: usize, c: usize| GoldilocksField::new(MDS_CIRC_8[(c + 8 - r) % 8] + MDS_DIAG_8[r] * (r == c) as u64);
+        for k in 1..=8 {
+            for rows in subsets(8, k) {
+                for cols in subsets(8, k) {
+                    let minor = rows.iter().map(|r| cols.iter().map(|c| entry(*r, *c)).collect()).collect();
+                    assert_ne!(determinant(minor), GoldilocksField::ZERO, "rows {rows:?}, cols {cols:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mds_layer_matches_the_matrix_product() {
+        assert_matrix_product(mds_layer::<12>, |r, c| {
+            GoldilocksField::new(MDS_CIRC_12[(c + 12 - r) % 12]) + GoldilocksField::new(MDS_DIAG_12[r] * (r == c) as u64)
+        });
+    }
+}
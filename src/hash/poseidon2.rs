@@ -0,0 +1,424 @@
+//! The Poseidon2 permutation over Goldilocks for widths 8 and 12, with the
+//! x^7 S-box and 4 + 4 external rounds around 22 internal rounds.
+//!
+//! The external layer is the block-circulant circ(2 M4, M4, ..., M4) built
+//! from the paper's 4x4 matrix M4, and the internal layer is J + diag(d),
+//! with J the all-ones matrix. These are the parameters of the HorizenLabs
+//! reference implementation, which plonky3 also uses: the round constants
+//! come from the Grain LFSR of the Poseidon reference scripts with field = 1,
+//! sbox = 0, n = 64, t = WIDTH, R_F = 8, R_P = 22, t values per external
+//! round and one per internal round, and d is its MATRIX_DIAG_8_GOLDILOCKS /
+//! MATRIX_DIAG_12_GOLDILOCKS. The permutation reproduces the reference test
+//! vectors.
+
+use super::{Permutation, Sponge};
+use crate::GoldilocksField;
+
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 22;
+
+/// Number of capacity elements, and of elements in a digest.
+pub const CAPACITY: usize = 4;
+pub const DIGEST_LEN: usize = 4;
+
+/// Constants of the external rounds of the width-8 instance, four before and
+/// four after the internal rounds.
+#[rustfmt::skip]
+pub const EXTERNAL_CONSTANTS_8: [[u64; 8]; FULL_ROUNDS] = [
+    [
+        0xdd5743e7f2a5a5d9, 0xcb3a864e58ada44b, 0xffa2449ed32f8cdc, 0x42025f65d6bd13ee,
+        0x7889175e25506323, 0x34b98bb03d24b737, 0xbdcc535ecc4faa2a, 0x5b20ad869fc0d033,
+    ],
+    [
+        0xf1dda5b9259dfcb4, 0x27515210be112d59, 0x4227d1718c766c3f, 0x26d333161a5bd794,
+        0x49b938957bf4b026, 0x4a56b5938b213669, 0x1120426b48c8353d, 0x6b323c3f10a56cad,
+    ],
+    [
+        0xce57d6245ddca6b2, 0xb1fc8d402bba1eb1, 0xb5c5096ca959bd04, 0x6db55cd306d31f7f,
+        0xc49d293a81cb9641, 0x1ce55a4fe979719f, 0xa92e60a9d178a4d1, 0x002cc64973bcfd8c,
+    ],
+    [
+        0xcea721cce82fb11b, 0xe5b55eb8098ece81, 0x4e30525c6f1ddd66, 0x43c6702827070987,
+        0xaca68430a7b5762a, 0x3674238634df9c93, 0x88cee1c825e33433, 0xde99ae8d74b57176,
+    ],
+    [
+        0x014ef1197d341346, 0x9725e20825d07394, 0xfdb25aef2c5bae3b, 0xbe5402dc598c971e,
+        0x93a5711f04cdca3d, 0xc45a9a5b2f8fb97b, 0xfe8946a924933545, 0x2af997a27369091c,
+    ],
+    [
+        0xaa62c88e0b294011, 0x058eb9d810ce9f74, 0xb3cb23eced349ae4, 0xa3648177a77b4a84,
+        0x43153d905992d95d, 0xf4e2a97cda44aa4b, 0x5baa2702b908682f, 0x082923bdf4f750d1,
+    ],
+    [
+        0x98ae09a325893803, 0xf8a6475077968838, 0xceb0735bf00b2c5f, 0x0a1a5d953888e072,
+        0x2fcb190489f94475, 0xb5be06270dec69fc, 0x739cb934b09acf8b, 0x537750b75ec7f25b,
+    ],
+    [
+        0xe9dd318bae1f3961, 0xf7462137299efe1a, 0xb1f6b8eee9adb940, 0xbdebcc8a809dfe6b,
+        0x40fc1f791b178113, 0x3ac1c3362d014864, 0x9a016184bdb8aeba, 0x95f2394459fbc25e,
+    ],
+];
+
+/// Constants of the internal rounds of the width-8 instance, added to the
+/// first state element.
+#[rustfmt::skip]
+pub const INTERNAL_CONSTANTS_8: [u64; PARTIAL_ROUNDS] = [
+    0x488897d85ff51f56, 0x1140737ccb162218, 0xa7eeb9215866ed35, 0x9bd2976fee49fcc9,
+    0xc0c8f0de580a3fcc, 0x4fb2dae6ee8fc793, 0x343a89f35f37395b, 0x223b525a77ca72c8,
+    0x56ccb62574aaa918, 0xc4d507d8027af9ed, 0xa080673cf0b7e95c, 0xf0184884eb70dcf8,
+    0x044f10b0cb3d5c69, 0xe9e3f7993938f186, 0x1b761c80e772f459, 0x606cec607a1b5fac,
+    0x14a0c2e1d45f03cd, 0x4eace8855398574f, 0xf905ca7103eff3e6, 0xf8c8f8d20862c059,
+    0xb524fe8bdd678e5a, 0xfbb7865901a1ec41,
+];
+
+/// The diagonal d of the width-8 internal matrix J + diag(d).
+#[rustfmt::skip]
+pub const INTERNAL_DIAG_8: [u64; 8] = [
+    0xa98811a1fed4e3a5, 0x1cc48b54f377e2a0, 0xe40cd4f6c5609a26, 0x11de79ebca97a4a3,
+    0x9177c73d8b7e929c, 0x2a6fe8085797e791, 0x3de6e93329f8d5ad, 0x3f7af9125da962fe,
+];
+
+/// Constants of the external rounds of the width-12 instance.
+#[rustfmt::skip]
+pub const EXTERNAL_CONSTANTS_12: [[u64; 12]; FULL_ROUNDS] = [
+    [
+        0x13dcf33aba214f46, 0x30b3b654a1da6d83, 0x1fc634ada6159b56, 0x937459964dc03466,
+        0xedd2ef2ca7949924, 0xede9affde0e22f68, 0x8515b9d6bac9282d, 0x6b5c07b4e9e900d8,
+        0x1ec66368838c8a08, 0x9042367d80d1fbab, 0x400283564a3c3799, 0x4a00be0466bca75e,
+    ],
+    [
+        0x7913beee58e3817f, 0xf545e88532237d90, 0x22f8cb8736042005, 0x6f04990e247a2623,
+        0xfe22e87ba37c38cd, 0xd20e32c85ffe2815, 0x117227674048fe73, 0x4e9fb7ea98a6b145,
+        0xe0866c232b8af08b, 0x00bbc77916884964, 0x7031c0fb990d7116, 0x240a9e87cf35108f,
+    ],
+    [
+        0x2e6363a5a12244b3, 0x5e1c3787d1b5011c, 0x4132660e2a196e8b, 0x3a013b648d3d4327,
+        0xf79839f49888ea43, 0xfe85658ebafe1439, 0xb6889825a14240bd, 0x578453605541382b,
+        0x4508cda8f6b63ce9, 0x9c3ef35848684c91, 0x0812bde23c87178c, 0xfe49638f7f722c14,
+    ],
+    [
+        0x8e3f688ce885cbf5, 0xb8e110acf746a87d, 0xb4b2e8973a6dabef, 0x9e714c5da3d462ec,
+        0x6438f9033d3d0c15, 0x24312f7cf1a27199, 0x23f843bb47acbf71, 0x9183f11a34be9f01,
+        0x839062fbb9d45dbf, 0x24b56e7e6c2e43fa, 0xe1683da61c962a72, 0xa95c63971a19bfa7,
+    ],
+    [
+        0xc68be7c94882a24d, 0xaf996d5d5cdaedd9, 0x9717f025e7daf6a5, 0x6436679e6e7216f4,
+        0x8a223d99047af267, 0xbb512e35a133ba9a, 0xfbbf44097671aa03, 0xf04058ebf6811e61,
+        0x5cca84703fac7ffb, 0x9b55c7945de6469f, 0x8e05bf09808e934f, 0x2ea900de876307d7,
+    ],
+    [
+        0x7748fff2b38dfb89, 0x6b99a676dd3b5d81, 0xac4bb7c627cf7c13, 0xadb6ebe5e9e2f5ba,
+        0x2d33378cafa24ae3, 0x1e5b73807543f8c2, 0x09208814bfebb10f, 0x782e64b6bb5b93dd,
+        0xadd5a48eac90b50f, 0xadd4c54c736ea4b1, 0xd58dbb86ed817fd8, 0x6d5ed1a533f34ddd,
+    ],
+    [
+        0x28686aa3e36b7cb9, 0x591abd3476689f36, 0x047d766678f13875, 0xa2a11112625f5b49,
+        0x21fd10a3f8304958, 0xf9b40711443b0280, 0xd2697eb8b2bde88e, 0x3493790b51731b3f,
+        0x11caf9dd73764023, 0x7acfb8f72878164e, 0x744ec4db23cefc26, 0x1e00e58f422c6340,
+    ],
+    [
+        0x21dd28d906a62dda, 0xf32a46ab5f465b5f, 0xbfce13201f3f7e6b, 0xf30d2e7adb5304e2,
+        0xecdf4ee4abad48e9, 0xf94e82182d395019, 0x4ee52e3744d887c5, 0xa1341c7cac0083b2,
+        0x2302fb26c30c834a, 0xaea3c587273bf7d3, 0xf798e24961823ec7, 0x962deba3e9a2cd94,
+    ],
+];
+
+/// Constants of the internal rounds of the width-12 instance.
+#[rustfmt::skip]
+pub const INTERNAL_CONSTANTS_12: [u64; PARTIAL_ROUNDS] = [
+    0x4adf842aa75d4316, 0xf8fbb871aa4ab4eb, 0x68e85b6eb2dd6aeb, 0x07a0b06b2d270380,
+    0xd94e0228bd282de4, 0x8bdd91d3250c5278, 0x209c68b88bba778f, 0xb5e18cdab77f3877,
+    0xb296a3e808da93fa, 0x8370ecbda11a327e, 0x3f9075283775dad8, 0xb78095bb23c6aa84,
+    0x3f36b9fe72ad4e5f, 0x69bc96780b10b553, 0x3f1d341f2eb7b881, 0x4e939e9815838818,
+    0xda366b3ae2a31604, 0xbc89db1e7287d509, 0x6102f411f9ef5659, 0x58725c5e7ac1f0ab,
+    0x0df5856c798883e7, 0xf7bb62a8da4c961b,
+];
+
+/// The diagonal d of the width-12 internal matrix J + diag(d).
+#[rustfmt::skip]
+pub const INTERNAL_DIAG_12: [u64; 12] = [
+    0xc3b6c08e23ba9300, 0xd84b5de94a324fb6, 0x0d0c371c5b35b84f, 0x7964f570e7188037,
+    0x5daf18bbd996604b, 0x6743bc47b9595257, 0x5528b9362c59bb70, 0xac45e25b7127b68b,
+    0xa2077d7dfbb606b5, 0xf3faac6faee378ae, 0x0c6388b51545e883, 0xd27dbb6944917b60,
+];
+
+/// (external constants, flattened round by round; internal constants; d).
+fn constants<const WIDTH: usize>() -> (&'static [u64], &'static [u64], &'static [u64]) {
+    if WIDTH == 8 {
+        (EXTERNAL_CONSTANTS_8.as_flattened(), &INTERNAL_CONSTANTS_8, &INTERNAL_DIAG_8)
+    } else {
+        (EXTERNAL_CONSTANTS_12.as_flattened(), &INTERNAL_CONSTANTS_12, &INTERNAL_DIAG_12)
+    }
+}
+
+fn sbox(x: GoldilocksField) -> GoldilocksField {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    x * x2 * x4
+}
+
+/// Multiplies four elements by M4 = [5 7 1 3; 4 6 1 1; 1 3 5 7; 1 1 4 6]
+/// with additions only.
+fn apply_m4(x: &mut [GoldilocksField]) {
+    let t0 = x[0] + x[1];
+    let t1 = x[2] + x[3];
+    let t2 = x[1] + x[1] + t1;
+    let t3 = x[3] + x[3] + t0;
+    let t4 = t1 + t1 + t1 + t1 + t3;
+    let t5 = t0 + t0 + t0 + t0 + t2;
+    x[0] = t3 + t5;
+    x[1] = t5;
+    x[2] = t2 + t4;
+    x[3] = t4;
+}
+
+/// state <- circ(2 M4, M4, ..., M4) * state: M4 on every block of four, then
+/// each element gains the sum of its position across the blocks.
+fn external_layer<const WIDTH: usize>(state: &mut [GoldilocksField; WIDTH]) {
+    for block in state.chunks_exact_mut(4) {
+        apply_m4(block);
+    }
+    let mut sums = [GoldilocksField::ZERO; 4];
+    for block in state.chunks_exact(4) {
+        for (sum, x) in sums.iter_mut().zip(block) {
+            *sum += *x;
+        }
+    }
+    for (i, x) in state.iter_mut().enumerate() {
+        *x += sums[i % 4];
+    }
+}
+
+/// state <- (J + diag(d)) * state, so each element becomes d_i x_i + sum(x).
+fn internal_layer<const WIDTH: usize>(state: &mut [GoldilocksField; WIDTH], diag: &[u64]) {
+    let sum = state.iter().fold(GoldilocksField::ZERO, |acc, x| acc + *x);
+    for (x, d) in state.iter_mut().zip(diag) {
+        *x = *x * GoldilocksField::from_canonical(*d) + sum;
+    }
+}
+
+fn external_round<const WIDTH: usize>(state: &mut [GoldilocksField; WIDTH], constants: &[u64]) {
+    for (x, c) in state.iter_mut().zip(constants) {
+        *x = sbox(*x + GoldilocksField::from_canonical(*c));
+    }
+    external_layer(state);
+}
+
+/// Applies the Poseidon2 permutation in place. Only widths 8 and 12 are
+/// defined; other widths fail to compile.
+pub fn permute<const WIDTH: usize>(state: &mut [GoldilocksField; WIDTH]) {
+    const { assert!(WIDTH == 8 || WIDTH == 12, "Poseidon2 is defined for widths 8 and 12") };
+    let (external, internal, diag) = constants::<WIDTH>();
+    let half_full = FULL_ROUNDS / 2;
+
+    external_layer(state);
+    for round in external.chunks_exact(WIDTH).take(half_full) {
+        external_round(state, round);
+    }
+    for c in internal {
+        state[0] = sbox(state[0] + GoldilocksField::from_canonical(*c));
+        internal_layer(state, diag);
+    }
+    for round in external.chunks_exact(WIDTH).skip(half_full) {
+        external_round(state, round);
+    }
+}
+
+/// Poseidon2 as a [`Permutation`] for [`Sponge`], at widths 8 and 12.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poseidon2;
+
+impl Permutation<8> for Poseidon2 {
+    fn permute(&self, state: &mut [GoldilocksField; 8]) {
+        permute(state);
+    }
+}
+
+impl Permutation<12> for Poseidon2 {
+    fn permute(&self, state: &mut [GoldilocksField; 12]) {
+        permute(state);
+    }
+}
+
+/// The sponges behind `hash`: overwrite absorption with 10* padding.
+pub type Poseidon2Sponge8 = Sponge<Poseidon2, 4, CAPACITY, 8>;
+pub type Poseidon2Sponge12 = Sponge<Poseidon2, 8, CAPACITY, 12>;
+
+/// Sponge hash with rate WIDTH - 4: the input is padded with a one and then
+/// zeros to a multiple of the rate, each block overwrites the rate part of
+/// the state before a permutation, and the digest is the first four elements.
+pub fn hash<const WIDTH: usize>(inputs: &[GoldilocksField]) -> [GoldilocksField; DIGEST_LEN] {
+    const { assert!(WIDTH == 8 || WIDTH == 12, "Poseidon2 is defined for widths 8 and 12") };
+    if WIDTH == 8 {
+        let mut sponge = Poseidon2Sponge8::new(Poseidon2);
+        sponge.absorb(inputs);
+        sponge.squeeze_array()
+    } else {
+        let mut sponge = Poseidon2Sponge12::new(Poseidon2);
+        sponge.absorb(inputs);
+        sponge.squeeze_array()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::test_utils::{assert_matrix_product, elements, permuted};
+    use crate::MODULUS;
+
+    #[test]
+    fn width_8_matches_plonky3() {
+        // plonky3's test_poseidon2_width_8_range.
+        assert_eq!(
+            permuted(permute, core::array::from_fn(|i| i as u64)),
+            elements([
+                0xc5fb1cfe0b4697bb, 0x4a4a32ff849af473, 0xd2fd266077f8efba, 0xf4ad9b74e833916d,
+                0xe6648eb0acc11463, 0x8d5529a930d75194, 0xe8c993aa10da6c90, 0xa73104a95b68031c,
+            ])
+        );
+    }
+
+    #[test]
+    fn width_12_matches_the_reference_implementation() {
+        // The Goldilocks width-12 vector of the HorizenLabs reference
+        // implementation (the zkhash crate).
+        assert_eq!(
+            permuted(permute, core::array::from_fn(|i| i as u64)),
+            elements([
+                0x01eaef96bdf1c0c1, 0x1f0d2cc525b2540c, 0x6282c1dfe1e0358d, 0xe780d721f698e1e6,
+                0x280c0b6f753d833b, 0x1b942dd5023156ab, 0x43f0df3fcccb8398, 0xe8e8190585489025,
+                0x56bdbf72f77ada22, 0x7911c32bf9dcd705, 0xec467926508fbe67, 0x6a50450ddf85a6ed,
+            ])
+        );
+    }
+
+    #[test]
+    fn hash_known_answers() {
+        // Regression values for the sponge on top of the permutation checked
+        // above; the reference implementation defines no sponge.
+        let inputs: Vec<GoldilocksField> = (0..10u64).map(GoldilocksField::new).collect();
+        assert_eq!(
+            hash::<8>(&[]),
+            elements([0xc98c1e187287596a, 0xa11d5c8446aca0b2, 0xd90ac148ad1f1abc, 0x69b6ae94b681571e])
+        );
+        assert_eq!(
+            hash::<8>(&inputs),
+            elements([0x3efe38f07d9ca91c, 0x1a75ded5854a3249, 0xa085f302340a18d6, 0x2eb3153a1bf8b363])
+        );
+        assert_eq!(
+            hash::<12>(&[]),
+            elements([0x3e2b889f0a54fc9f, 0xa54cad539f0eca43, 0x27c8807497b88387, 0xb54b13af72e56451])
+        );
+        assert_eq!(
+            hash::<12>(&inputs),
+            elements([0x4baae641e83e46d9, 0xf1e799f5da44917a, 0x87e0ba5d8558b3e6, 0xa7063f5c90caa1d9])
+        );
+    }
+
+    #[test]
+    fn padding_separates_trailing_zeros() {
+        let short = [GoldilocksField::ONE; 3];
+        let long = [GoldilocksField::ONE, GoldilocksField::ONE, GoldilocksField::ONE, GoldilocksField::ZERO];
+        assert_ne!(hash::<12>(&short), hash::<12>(&long));
+        assert_ne!(hash::<8>(&short), hash::<8>(&long));
+    }
+
+    #[test]
+    fn linear_layers_match_their_matrices() {
+        const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+        // Diagonal blocks are 2 M4, the others M4.
+        assert_matrix_product(external_layer::<12>, |r, c| {
+            GoldilocksField::new(if r / 4 == c / 4 { 2 } else { 1 } * M4[r % 4][c % 4])
+        });
+        assert_matrix_product(
+            |state: &mut [GoldilocksField; 12]| internal_layer(state, &INTERNAL_DIAG_12),
+            |r, c| GoldilocksField::ONE + GoldilocksField::new(INTERNAL_DIAG_12[r] * (r == c) as u64),
+        );
+    }
+
+    /// The Grain LFSR of the Poseidon reference scripts, yielding field
+    /// elements by rejection sampling of 64-bit big-endian values.
+    struct Grain {
+        state: u128,
+    }
+
+    impl Grain {
+        fn new(width: u64) -> Self {
+            // field = 1 (2 bits), sbox = 0 (4), n = 64 (12), t (12), R_F (10),
+            // R_P (10), then 30 ones; bit 0 of the sequence is the top bit.
+            let init = [(1, 2), (0, 4), (64, 12), (width, 12), (FULL_ROUNDS as u64, 10), (PARTIAL_ROUNDS as u64, 10)];
+            let mut state = 0u128;
+            for (value, bits) in init {
+                state = state << bits | value as u128;
+            }
+            state = state << 30 | ((1 << 30) - 1);
+            let mut grain = Grain { state };
+            for _ in 0..160 {
+                grain.step();
+            }
+            grain
+        }
+
+        /// Shifts in b_80 = b_62 + b_51 + b_38 + b_23 + b_13 + b_0 and returns it.
+        fn step(&mut self) -> u64 {
+            let bit = |i: u32| (self.state >> (79 - i)) as u64 & 1;
+            let new = bit(62) ^ bit(51) ^ bit(38) ^ bit(23) ^ bit(13) ^ bit(0);
+            self.state = (self.state << 1 | new as u128) & ((1 << 80) - 1);
+            new
+        }
+
+        /// Of each pair of bits, the second is kept when the first is one.
+        fn next_bit(&mut self) -> u64 {
+            loop {
+                let keep = self.step();
+                let bit = self.step();
+                if keep == 1 {
+                    return bit;
+                }
+            }
+        }
+
+        fn next_element(&mut self) -> u64 {
+            loop {
+                let value = (0..64).fold(0, |acc, _| acc << 1 | self.next_bit());
+                if value < MODULUS {
+                    return value;
+                }
+            }
+        }
+    }
+
+    fn check_round_constants<const WIDTH: usize>() {
+        let (external, internal, _) = constants::<WIDTH>();
+        let mut grain = Grain::new(WIDTH as u64);
+        let mut external = external.chunks_exact(WIDTH);
+        let mut internal = internal.iter();
+        let internal_rounds = FULL_ROUNDS / 2..FULL_ROUNDS / 2 + PARTIAL_ROUNDS;
+        for round in 0..FULL_ROUNDS + PARTIAL_ROUNDS {
+            if internal_rounds.contains(&round) {
+                assert_eq!(grain.next_element(), *internal.next().unwrap(), "width {WIDTH}, round {round}");
+            } else {
+                for c in external.next().unwrap() {
+                    assert_eq!(grain.next_element(), *c, "width {WIDTH}, round {round}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_constants_rederive() {
+        check_round_constants::<8>();
+        check_round_constants::<12>();
+    }
+
+    #[test]
+    fn internal_matrices_are_invertible() {
+        // det(J + D) = det(D) (1 + sum(1 / d_i)) for nonzero d_i.
+        for diag in [&INTERNAL_DIAG_8[..], &INTERNAL_DIAG_12[..]] {
+            let d: Vec<GoldilocksField> = diag.iter().map(|x| GoldilocksField::new(*x)).collect();
+            assert!(d.iter().all(|x| *x != GoldilocksField::ZERO));
+            let trace = d.iter().fold(GoldilocksField::ONE, |acc, x| acc + GoldilocksField::ONE / *x);
+            assert_ne!(trace, GoldilocksField::ZERO);
+        }
+    }
+}
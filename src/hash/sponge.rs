@@ -0,0 +1,239 @@
+//! A sponge construction over any permutation of field elements.
+
+use crate::GoldilocksField;
+
+/// A permutation of WIDTH field elements.
+pub trait Permutation<const WIDTH: usize> {
+    fn permute(&self, state: &mut [GoldilocksField; WIDTH]);
+}
+
+/// How an input block enters the rate part of the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbsorbMode {
+    /// The block replaces the rate elements, as in plonky2.
+    Overwrite,
+    /// The block is added to the rate elements.
+    Add,
+}
+
+/// How the input is terminated before the first squeeze.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    /// Append a one and then zeros up to the end of the block, so inputs that
+    /// differ only in trailing zeros hash differently. A full last block is
+    /// followed by a block of padding alone.
+    TenStar,
+    /// Absorb a short last block as it is and nothing after a full one. Only
+    /// safe for inputs whose length is fixed by the context.
+    None,
+}
+
+/// A sponge with RATE rate elements and CAPACITY capacity elements over a
+/// WIDTH-element permutation; WIDTH must be RATE + CAPACITY.
+///
+/// Squeezing reads the rate elements in order and permutes only when more
+/// are needed. Absorbing after squeezing starts a new message on the current
+/// state.
+#[derive(Debug, Clone)]
+pub struct Sponge<P, const RATE: usize, const CAPACITY: usize, const WIDTH: usize> {
+    permutation: P,
+    state: [GoldilocksField; WIDTH],
+    pending: Vec<GoldilocksField>,
+    /// Position in the rate part of the next squeezed element, while squeezing.
+    squeeze_index: Option<usize>,
+    mode: AbsorbMode,
+    padding: Padding,
+}
+
+impl<P: Permutation<WIDTH>, const RATE: usize, const CAPACITY: usize, const WIDTH: usize>
+    Sponge<P, RATE, CAPACITY, WIDTH>
+{
+    /// A sponge with a zero state, overwrite absorption and 10* padding.
+    pub fn new(permutation: P) -> Self {
+        Self::with_options(permutation, AbsorbMode::Overwrite, Padding::TenStar)
+    }
+
+    pub fn with_options(permutation: P, mode: AbsorbMode, padding: Padding) -> Self {
+        const {
+            assert!(RATE > 0, "Sponge: RATE must be positive");
+            assert!(RATE + CAPACITY == WIDTH, "Sponge: WIDTH must be RATE + CAPACITY");
+        };
+        Sponge {
+            permutation,
+            state: [GoldilocksField::ZERO; WIDTH],
+            pending: Vec::with_capacity(RATE),
+            squeeze_index: None,
+            mode,
+            padding,
+        }
+    }
+
+    pub fn absorb(&mut self, input: &[GoldilocksField]) {
+        self.squeeze_index = None;
+        for x in input {
+            self.pending.push(*x);
+            if self.pending.len() == RATE {
+                self.absorb_pending();
+            }
+        }
+    }
+
+    pub fn squeeze(&mut self, n: usize) -> Vec<GoldilocksField> {
+        let mut index = match self.squeeze_index {
+            Some(index) => index,
+            None => {
+                self.finish_absorbing();
+                0
+            }
+        };
+        let mut outputs = Vec::with_capacity(n);
+        while outputs.len() < n {
+            if index == RATE {
+                self.permutation.permute(&mut self.state);
+                index = 0;
+            }
+            outputs.push(self.state[index]);
+            index += 1;
+        }
+        self.squeeze_index = Some(index);
+        outputs
+    }
+
+    /// Squeezes exactly N elements.
+    pub fn squeeze_array<const N: usize>(&mut self) -> [GoldilocksField; N] {
+        let mut out = [GoldilocksField::ZERO; N];
+        out.copy_from_slice(&self.squeeze(N));
+        out
+    }
+
+    fn finish_absorbing(&mut self) {
+        if self.padding == Padding::TenStar {
+            self.pending.push(GoldilocksField::ONE);
+            self.pending.resize(RATE, GoldilocksField::ZERO);
+        }
+        if !self.pending.is_empty() {
+            self.absorb_pending();
+        }
+    }
+
+    /// Writes the pending elements, at most a block, into the rate part and
+    /// permutes.
+    fn absorb_pending(&mut self) {
+        for (x, input) in self.state.iter_mut().zip(&self.pending) {
+            *x = match self.mode {
+                AbsorbMode::Overwrite => *input,
+                AbsorbMode::Add => *x + *input,
+            };
+        }
+        self.pending.clear();
+        self.permutation.permute(&mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_elements, SplitMix64};
+
+    /// x -> (x + 1) rotated by one position, cheap and easy to follow by hand.
+    struct Rotate;
+
+    impl Permutation<4> for Rotate {
+        fn permute(&self, state: &mut [GoldilocksField; 4]) {
+            for x in state.iter_mut() {
+                *x += GoldilocksField::ONE;
+            }
+            state.rotate_left(1);
+        }
+    }
+
+    fn sponge(padding: Padding) -> Sponge<Rotate, 3, 1, 4> {
+        Sponge::with_options(Rotate, AbsorbMode::Overwrite, padding)
+    }
+
+    #[test]
+    fn multi_block_absorption_matches_manual_permutations() {
+        let input = random_elements(&mut SplitMix64(64), 7);
+        let mut s = sponge(Padding::TenStar);
+        s.absorb(&input);
+
+        let mut state = [GoldilocksField::ZERO; 4];
+        let mut padded = input.clone();
+        padded.extend([GoldilocksField::ONE, GoldilocksField::ZERO]);
+        for block in padded.chunks_exact(3) {
+            state[..3].copy_from_slice(block);
+            Rotate.permute(&mut state);
+        }
+        assert_eq!(s.squeeze(3), state[..3]);
+    }
+
+    #[test]
+    fn absorption_is_independent_of_chunking() {
+        let input = random_elements(&mut SplitMix64(65), 20);
+        for mode in [AbsorbMode::Overwrite, AbsorbMode::Add] {
+            for padding in [Padding::TenStar, Padding::None] {
+                let mut whole = Sponge::<Rotate, 3, 1, 4>::with_options(Rotate, mode, padding);
+                whole.absorb(&input);
+                let mut pieces = Sponge::<Rotate, 3, 1, 4>::with_options(Rotate, mode, padding);
+                for chunk in [&input[..1], &input[1..5], &input[5..6], &input[6..]] {
+                    pieces.absorb(chunk);
+                }
+                assert_eq!(whole.squeeze(5), pieces.squeeze(5), "{mode:?}, {padding:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn squeezing_across_permutation_boundaries() {
+        let input = random_elements(&mut SplitMix64(66), 4);
+        let mut once = sponge(Padding::TenStar);
+        once.absorb(&input);
+        let all = once.squeeze(10);
+
+        let mut split = sponge(Padding::TenStar);
+        split.absorb(&input);
+        let mut parts = split.squeeze(2);
+        parts.extend(split.squeeze(0));
+        parts.extend(split.squeeze(4));
+        parts.extend(split.squeeze(4));
+        assert_eq!(parts, all);
+
+        // Blocks [i0, i1, i2] and [i3, 1, 0], then one permutation per three
+        // further outputs.
+        let mut state = [GoldilocksField::ZERO; 4];
+        for block in [[input[0], input[1], input[2]], [input[3], GoldilocksField::ONE, GoldilocksField::ZERO]] {
+            state[..3].copy_from_slice(&block);
+            Rotate.permute(&mut state);
+        }
+        for outputs in all.chunks(3) {
+            assert_eq!(outputs, &state[..outputs.len()]);
+            Rotate.permute(&mut state);
+        }
+    }
+
+    #[test]
+    fn padding_separates_trailing_zeros() {
+        let digest = |input: &[GoldilocksField], padding| {
+            let mut s = sponge(padding);
+            s.absorb(input);
+            s.squeeze(3)
+        };
+        let short = [GoldilocksField::ONE];
+        let long = [GoldilocksField::ONE, GoldilocksField::ZERO];
+        assert_ne!(digest(&short, Padding::TenStar), digest(&long, Padding::TenStar));
+        assert_eq!(digest(&short, Padding::None), digest(&long, Padding::None));
+        // Without padding, nothing absorbed means nothing permuted.
+        assert_eq!(digest(&[], Padding::None), [GoldilocksField::ZERO; 3]);
+    }
+
+    #[test]
+    fn add_mode_accumulates_into_the_state() {
+        let input = [GoldilocksField::new(5), GoldilocksField::new(6), GoldilocksField::new(7)];
+        let mut s = Sponge::<Rotate, 3, 1, 4>::with_options(Rotate, AbsorbMode::Add, Padding::None);
+        s.absorb(&input);
+        s.absorb(&input);
+        // [5, 6, 7, 0] -> [7, 8, 1, 6]; adding the block gives [12, 14, 8, 6],
+        // which permutes to [15, 9, 7, 13].
+        assert_eq!(s.squeeze(3), [15u64, 9, 7].map(GoldilocksField::new));
+    }
+}
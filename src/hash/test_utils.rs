@@ -0,0 +1,31 @@
+//! Helpers shared by the unit tests of the hash modules.
+
+use crate::{GoldilocksField, MODULUS};
+
+pub(super) fn elements<const N: usize>(values: [u64; N]) -> [GoldilocksField; N] {
+    values.map(GoldilocksField::from_canonical)
+}
+
+pub(super) fn permuted<const WIDTH: usize>(
+    permutation: fn(&mut [GoldilocksField; WIDTH]),
+    input: [u64; WIDTH],
+) -> [GoldilocksField; WIDTH] {
+    let mut state = elements(input);
+    permutation(&mut state);
+    state
+}
+
+/// Checks that `layer` multiplies the state by the matrix whose row r,
+/// column c entry is `entry(r, c)`, on an input of values just below p.
+pub(super) fn assert_matrix_product<const WIDTH: usize>(
+    layer: impl Fn(&mut [GoldilocksField; WIDTH]),
+    entry: impl Fn(usize, usize) -> GoldilocksField,
+) {
+    let input: [GoldilocksField; WIDTH] = core::array::from_fn(|i| GoldilocksField::new(MODULUS - 1 - 3 * i as u64));
+    let mut state = input;
+    layer(&mut state);
+    for (r, actual) in state.iter().enumerate() {
+        let expected = input.iter().enumerate().fold(GoldilocksField::ZERO, |acc, (c, x)| acc + *x * entry(r, c));
+        assert_eq!(*actual, expected, "row {r}");
+    }
+}
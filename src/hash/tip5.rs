@@ -0,0 +1,271 @@
+//! The Tip5 permutation and hash (Szepieniec, Lemmens, Sauer, Threadbare,
+//! Al-Kindi), designed for this field and used by Triton VM.
+//!
+//! The state has 16 elements, of which 10 are rate and 6 capacity, and a
+//! digest has 5. Each of the 5 rounds applies the S-box layer (split-and-lookup
+//! on the first 4 elements, x^7 on the other 12), the circulant MDS matrix
+//! and the round constants.
+//!
+//! The lookup table, MDS column and round constants are those of the
+//! specification, and the hashing modes reproduce the test vectors of
+//! twenty-first, the Tip5 implementation Triton VM uses.
+
+use super::{Permutation, Sponge};
+use crate::{reduce_u128, GoldilocksField, MODULUS};
+
+pub const STATE_SIZE: usize = 16;
+pub const RATE: usize = 10;
+pub const CAPACITY: usize = STATE_SIZE - RATE;
+pub const DIGEST_LEN: usize = 5;
+pub const ROUNDS: usize = 5;
+/// Number of state elements that go through split-and-lookup.
+pub const SPLIT_AND_LOOKUP: usize = 4;
+
+/// The byte S-box x -> (x + 1)^3 - 1 mod 257, a permutation of 0..=255
+/// that fixes 0 and 255.
+#[rustfmt::skip]
+pub const LOOKUP_TABLE: [u8; 256] = [
+      0,   7,  26,  63, 124, 215,  85, 254, 214, 228,  45, 185, 140, 173,  33, 240,
+     29, 177, 176,  32,   8, 110,  87, 202, 204,  99, 150, 106, 230,  14, 235, 128,
+    213, 239, 212, 138,  23, 130, 208,   6,  44,  71,  93, 116, 146, 189, 251,  81,
+    199,  97,  38,  28,  73, 179,  95,  84, 152,  48,  35, 119,  49,  88, 242,   3,
+    148, 169,  72, 120,  62, 161, 166,  83, 175, 191, 137,  19, 100, 129, 112,  55,
+    221, 102, 218,  61, 151, 237,  68, 164,  17, 147,  46, 234, 203, 216,  22, 141,
+     65,  57, 123,  12, 244,  54, 219, 231,  96,  77, 180, 154,   5, 253, 133, 165,
+     98, 195, 205, 134, 245,  30,   9, 188,  59, 142, 186, 197, 181, 144,  92,  31,
+    224, 163, 111,  74,  58,  69, 113, 196,  67, 246, 225,  10, 121,  50,  60, 157,
+     90, 122,   2, 250, 101,  75, 178, 159,  24,  36, 201,  11, 243, 132, 198, 190,
+    114, 233,  39,  52,  21, 209, 108, 238,  91, 187,  18, 104, 194,  37, 153,  34,
+    200, 143, 126, 155, 236, 118,  64,  80, 172,  89,  94, 193, 135, 183,  86, 107,
+    252,  13, 167, 206, 136, 220, 207, 103, 171, 160,  76, 182, 227, 217, 158,  56,
+    174,   4,  66, 109, 139, 162, 184, 211, 249,  47, 125, 232, 117,  43,  16,  42,
+    127,  20, 241,  25, 149, 105, 156,  51,  53, 168, 145, 247, 223,  79,  78, 226,
+     15, 222,  82, 115,  70, 210,  27,  41,   1, 170,  40, 131, 192, 229, 248, 255,
+];
+
+/// First column of the circulant MDS matrix.
+pub const MDS_FIRST_COLUMN: [u64; STATE_SIZE] = [
+    61402, 1108, 28750, 33823, 7454, 43244, 53865, 12034, 56951, 27521, 41351, 40901, 12021, 59689, 26798, 17845,
+];
+
+/// Round constants, 16 per round, as canonical values. Constant i is the
+/// first 16 bytes of BLAKE3("Tip5" || i), read little-endian and reduced
+/// mod p, taken as a Montgomery representation.
+#[rustfmt::skip]
+pub const ROUND_CONSTANTS: [u64; ROUNDS * STATE_SIZE] = [
+    0xbd2a3deb61ab60de, 0xea7df21ad9547ed2, 0x900b3677a1de063f, 0x1b46887e876c8677,
+    0xd364d977889cfb97, 0xdc8dfac843699f02, 0x375c405d7190db58, 0x27924006d2b0d4b1,
+    0x78dd1172d483cd38, 0x3346c66244882a56, 0xb0249b279f498aa5, 0x94cd51be79338d4d,
+    0xb0e0dc7052c5b218, 0xf8dcc4d248adad95, 0x68e3c635fec868b7, 0xd7d06b3ffb6b0d8c,
+    0xf3500dea20ef032a, 0x4865bf175bba5803, 0xd5f7fe3027287a27, 0xa57333f44e193412,
+    0x8726e153a977eae2, 0x3014a98463fc191b, 0xba145461af39b212, 0x03ab70105933202f,
+    0x3d90b7eebfcf71e5, 0x386322b1cc520bfd, 0x27c2c8daf774f675, 0x4fcb83f50309bc6a,
+    0x5e6d5ce8275f3cb3, 0xecc2f6592c8f905c, 0x837f532461e609b4, 0xb2b1f6b95c92c93c,
+    0xc0027af556411dc1, 0x16e18c885fc2a26c, 0x8880ef183d9f2bf3, 0xb2930bdb5ca88c45,
+    0x9c2ec8322e1c1553, 0xe5b05eaf3220a674, 0xa49cc6ae4b861c4e, 0x11708e0aeb86ebd7,
+    0xc09de92bbc3902e0, 0x929b3c79516bcbc1, 0xe006e5bf738f27d1, 0x2d9e1ec0eac8ea38,
+    0x0984d8d94bf937c5, 0x4959273c220e6747, 0xfe1d934207e796fa, 0x2b9b9298f2f6dd73,
+    0x07a1f5a67d6e3a41, 0x4407593ee73743d9, 0x9f054720ef802e59, 0x78d4b711336e6aa6,
+    0xadc638aef3c8b228, 0xa4d6d3e86afb2114, 0x9d4808e725531968, 0x369804df3866d0ef,
+    0xe6dbd9a9d2215024, 0x8ed22ca212ee85b2, 0x397bb882fcd23eb6, 0xeb8f8786d7277531,
+    0x9999d4cdaff543b5, 0xf382a61217f192d6, 0x49c37260b026adc1, 0x3ff8918ce35c1019,
+    0x2e7df8b76080bd07, 0xf5dbac250b8a28b9, 0x853c3727ae9da4cc, 0xb2f1f5f3d9e5a26d,
+    0x3fce22012d337847, 0x6b5a3e6db7eee347, 0x171582cd59dde50d, 0xc0c0b3095ee62a8a,
+    0x665b25c6f6a203d2, 0x3099aed93b6ae69f, 0x801df6092be69c38, 0x8066ad0cdfff43cd,
+    0x8af9d44a5f4fdc6b, 0xd80219cd97c0d762, 0x10c9ceba14148ebb, 0x539bd4c3f2f24474,
+];
+
+/// 2^-64 mod p = 2^128 mod p, which undoes the Montgomery factor.
+const MONTGOMERY_R_INV: u64 = MODULUS - (1 << 32);
+
+/// The split-and-lookup S-box: the Montgomery representation x * 2^64 mod p
+/// is split into bytes, each byte goes through `LOOKUP_TABLE`, and the bytes
+/// are read back as a Montgomery representation. Since the table fixes 0 and
+/// 255, the result is again below p.
+fn split_and_lookup(x: GoldilocksField) -> GoldilocksField {
+    let raw = reduce_u128((x.to_canonical_u64() as u128) << 64);
+    let bytes = raw.to_le_bytes().map(|b| LOOKUP_TABLE[b as usize]);
+    let raw = u64::from_le_bytes(bytes);
+    GoldilocksField::from_canonical(reduce_u128(raw as u128 * MONTGOMERY_R_INV as u128))
+}
+
+fn power_map(x: GoldilocksField) -> GoldilocksField {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    x * x2 * x4
+}
+
+/// state <- C * state for the circulant C with first column
+/// `MDS_FIRST_COLUMN`; the entries are below 2^16, so the 16 products fit in a
+/// u128 before reducing.
+fn mds_layer(state: &mut [GoldilocksField; STATE_SIZE]) {
+    let input = state.map(|x| x.to_canonical_u64() as u128);
+    for (i, out) in state.iter_mut().enumerate() {
+        let acc = input
+            .iter()
+            .enumerate()
+            .map(|(j, x)| *x * MDS_FIRST_COLUMN[(i + STATE_SIZE - j) % STATE_SIZE] as u128)
+            .sum();
+        *out = GoldilocksField::from_canonical(reduce_u128(acc));
+    }
+}
+
+/// Applies the Tip5 permutation in place.
+pub fn permutation(state: &mut [GoldilocksField; STATE_SIZE]) {
+    for constants in ROUND_CONSTANTS.chunks_exact(STATE_SIZE) {
+        let (lookups, powers) = state.split_at_mut(SPLIT_AND_LOOKUP);
+        for x in lookups {
+            *x = split_and_lookup(*x);
+        }
+        for x in powers {
+            *x = power_map(*x);
+        }
+        mds_layer(state);
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x += GoldilocksField::from_canonical(*c);
+        }
+    }
+}
+
+/// Tip5 as a [`Permutation`] for [`Sponge`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tip5;
+
+impl Permutation<STATE_SIZE> for Tip5 {
+    fn permute(&self, state: &mut [GoldilocksField; STATE_SIZE]) {
+        permutation(state);
+    }
+}
+
+/// The variable-length sponge: overwrite absorption with 10* padding.
+pub type Tip5Sponge = Sponge<Tip5, RATE, CAPACITY, STATE_SIZE>;
+
+fn digest(state: &[GoldilocksField; STATE_SIZE]) -> [GoldilocksField; DIGEST_LEN] {
+    let mut digest = [GoldilocksField::ZERO; DIGEST_LEN];
+    digest.copy_from_slice(&state[..DIGEST_LEN]);
+    digest
+}
+
+/// Fixed-length mode for exactly `RATE` elements: the capacity is set to
+/// ones, which separates it from the variable-length domain, and a single
+/// permutation follows.
+pub fn hash_10(input: &[GoldilocksField; RATE]) -> [GoldilocksField; DIGEST_LEN] {
+    let mut state = [GoldilocksField::ONE; STATE_SIZE];
+    state[..RATE].copy_from_slice(input);
+    permutation(&mut state);
+    digest(&state)
+}
+
+/// Hashes two digests into one, for Merkle tree nodes; `hash_10` of
+/// left || right.
+pub fn hash_pair(
+    left: &[GoldilocksField; DIGEST_LEN],
+    right: &[GoldilocksField; DIGEST_LEN],
+) -> [GoldilocksField; DIGEST_LEN] {
+    let mut input = [GoldilocksField::ZERO; RATE];
+    input[..DIGEST_LEN].copy_from_slice(left);
+    input[DIGEST_LEN..].copy_from_slice(right);
+    hash_10(&input)
+}
+
+/// Variable-length mode: the input is padded with a one and then zeros to a
+/// multiple of `RATE`, and each block overwrites the rate part of a state
+/// whose capacity starts at zero before a permutation.
+pub fn hash_varlen(input: &[GoldilocksField]) -> [GoldilocksField; DIGEST_LEN] {
+    let mut sponge = Tip5Sponge::new(Tip5);
+    sponge.absorb(input);
+    sponge.squeeze_array()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::test_utils::{assert_matrix_product, elements};
+    use crate::test_utils::SplitMix64;
+    use crate::EPSILON;
+
+    /// Split-and-lookup computed directly: Montgomery form by u128 remainder,
+    /// the cube map evaluated per byte, and the Montgomery factor removed with
+    /// an inversion.
+    fn naive_split_and_lookup(x: GoldilocksField) -> GoldilocksField {
+        let p = MODULUS as u128;
+        let raw = ((x.to_canonical_u64() as u128) << 64) % p;
+        let mut out = 0u64;
+        for i in 0..8 {
+            let byte = (raw >> (8 * i)) as u64 & 0xff;
+            let cube = (byte + 1).pow(3) % 257 - 1;
+            out |= cube << (8 * i);
+        }
+        let r = GoldilocksField::from_canonical(((1u128 << 64) % p) as u64);
+        GoldilocksField::new(out) / r
+    }
+
+    #[test]
+    fn lookup_table_is_the_offset_cube_map() {
+        for (i, entry) in LOOKUP_TABLE.iter().enumerate() {
+            assert_eq!(*entry as u64, (i as u64 + 1).pow(3) % 257 - 1, "entry {i}");
+        }
+        let mut seen = [false; 256];
+        for entry in LOOKUP_TABLE {
+            seen[entry as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn split_and_lookup_matches_the_naive_version() {
+        let mut rng = SplitMix64(62);
+        let edges = [0, 1, 2, 255, 256, EPSILON, 1 << 32, MODULUS - 2, MODULUS - 1].map(GoldilocksField::from_canonical);
+        for x in edges.into_iter().chain((0..10_000).map(|_| rng.next_field())) {
+            assert_eq!(split_and_lookup(x), naive_split_and_lookup(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn mds_layer_matches_the_matrix_product() {
+        assert_matrix_product(mds_layer, |r, c| GoldilocksField::new(MDS_FIRST_COLUMN[(r + STATE_SIZE - c) % STATE_SIZE]));
+    }
+
+    // The two tests below are twenty-first's hash10_test_vectors and
+    // hash_varlen_test_vectors, Triton VM's own Tip5 known answers.
+
+    #[test]
+    fn hash_10_matches_twenty_first() {
+        let mut preimage = [GoldilocksField::ZERO; RATE];
+        for i in 0..6 {
+            let digest = hash_10(&preimage);
+            preimage[i..DIGEST_LEN + i].copy_from_slice(&digest);
+        }
+        assert_eq!(
+            hash_10(&preimage),
+            elements([10869784347448351760, 1853783032222938415, 6856460589287344822, 17178399545409290325, 7650660984651717733])
+        );
+    }
+
+    #[test]
+    fn hash_varlen_matches_twenty_first() {
+        let mut digest_sum = [GoldilocksField::ZERO; DIGEST_LEN];
+        for len in 0..20u64 {
+            let preimage: Vec<GoldilocksField> = (0..len).map(GoldilocksField::new).collect();
+            for (sum, x) in digest_sum.iter_mut().zip(hash_varlen(&preimage)) {
+                *sum += x;
+            }
+        }
+        assert_eq!(
+            digest_sum,
+            elements([7610004073009036015, 5725198067541094245, 4721320565792709122, 1732504843634706218, 259800783350288362])
+        );
+    }
+
+    #[test]
+    fn hash_pair_is_hash_10_of_the_concatenation() {
+        let (left, right) = (elements([1, 2, 3, 4, 5]), elements([6, 7, 8, 9, 10]));
+        assert_eq!(hash_pair(&left, &right), hash_10(&elements(core::array::from_fn(|i| i as u64 + 1))));
+    }
+
+    #[test]
+    fn fixed_and_variable_length_domains_differ() {
+        let input = elements(core::array::from_fn(|i| i as u64));
+        assert_ne!(hash_10(&input), hash_varlen(&input));
+    }
+}
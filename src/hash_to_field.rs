@@ -0,0 +1,173 @@
+//! `hash_to_field` from RFC 9380: messages to field elements under a domain
+//! separation tag, through `expand_message_xmd`.
+//!
+//! The expander hashes with SHA-256 under the `sha256` feature and otherwise
+//! with BLAKE3 under `blake3`. SHA-256 takes precedence when both are enabled,
+//! so the output matches the RFC's SHA-256 suites.
+
+use crate::GoldilocksField;
+
+/// Output size of the hash, b_in_bytes in the RFC.
+const HASH_LEN: usize = 32;
+/// Input block size of the hash, s_in_bytes in the RFC.
+const BLOCK_LEN: usize = 64;
+/// Bytes drawn per element, L = ceil((ceil(log2(p)) + k) / 8) for k = 64.
+const ELEMENT_LEN: usize = 16;
+
+#[cfg(feature = "sha256")]
+fn hash(parts: &[&[u8]]) -> [u8; HASH_LEN] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    let mut output = [0; HASH_LEN];
+    output.copy_from_slice(&hasher.finalize()[..]);
+    output
+}
+
+#[cfg(not(feature = "sha256"))]
+fn hash(parts: &[&[u8]]) -> [u8; HASH_LEN] {
+    let mut hasher = blake3::Hasher::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    *hasher.finalize().as_bytes()
+}
+
+/// Expands `msg` into `len` pseudorandom bytes bound to `dst`, following
+/// expand_message_xmd. A DST longer than 255 bytes is first replaced by
+/// H("H2C-OVERSIZE-DST-" || DST), as the RFC requires.
+///
+/// Panics if `len` exceeds 255 * 32 = 8160 bytes.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    let blocks = len.div_ceil(HASH_LEN);
+    assert!(blocks <= 255, "expand_message_xmd: at most 8160 bytes can be requested");
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = hash(&[b"H2C-OVERSIZE-DST-", dst]);
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b0 = hash(&[&[0; BLOCK_LEN], msg, &(len as u16).to_be_bytes(), &[0], dst, &dst_len]);
+    let mut output = Vec::with_capacity(blocks * HASH_LEN);
+    let mut b = hash(&[&b0, &[1], dst, &dst_len]);
+    output.extend_from_slice(&b);
+    for i in 2..=blocks {
+        let mixed: [u8; HASH_LEN] = core::array::from_fn(|j| b0[j] ^ b[j]);
+        b = hash(&[&mixed, &[i as u8], dst, &dst_len]);
+        output.extend_from_slice(&b);
+    }
+    output.truncate(len);
+    output
+}
+
+/// Hashes `msg` to `count` field elements under the domain separation tag
+/// `dst`. Each element takes 16 expanded bytes, read big-endian as the RFC's
+/// OS2IP does and reduced like `from_random_bytes`, so every element is
+/// within 2^-64 of uniform.
+///
+/// Panics if `count` exceeds 510, the most one expansion can supply.
+pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<GoldilocksField> {
+    if count == 0 {
+        return Vec::new();
+    }
+    expand_message_xmd(msg, dst, count * ELEMENT_LEN)
+        .chunks_exact(ELEMENT_LEN)
+        .map(|chunk| GoldilocksField::from_u128(u128::from_be_bytes(chunk.try_into().unwrap())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    #[cfg(feature = "sha256")]
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    // Test vectors from RFC 9380, appendix K.1 and K.2.
+    #[cfg(feature = "sha256")]
+    #[test]
+    fn expand_message_xmd_matches_the_rfc_vectors() {
+        assert_eq!(
+            hex(&expand_message_xmd(b"", DST, 0x20)),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            hex(&expand_message_xmd(b"abc", DST, 0x20)),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+        let long_dst = [&b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-"[..], &[b'1'; 208]].concat();
+        assert_eq!(long_dst.len(), 256);
+        assert_eq!(
+            hex(&expand_message_xmd(b"", &long_dst, 0x20)),
+            "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3"
+        );
+    }
+
+    // Expected values come from a Python model of the construction over
+    // hashlib's SHA-256.
+    #[cfg(feature = "sha256")]
+    #[test]
+    fn hash_to_field_known_answers() {
+        assert_eq!(
+            hash_to_field(b"abc", DST, 4),
+            [0xcc7a8c8cfe681b92, 0x6c6defac9e7cba4b, 0xa00b09338a7aee4d, 0xf257a6f65d86b101].map(GoldilocksField::new)
+        );
+    }
+
+    #[test]
+    fn hash_to_field_is_deterministic_and_reduces_each_chunk() {
+        let elements = hash_to_field(b"message", DST, 7);
+        assert_eq!(elements, hash_to_field(b"message", DST, 7));
+        let bytes = expand_message_xmd(b"message", DST, 7 * 16);
+        for (element, chunk) in elements.iter().zip(bytes.chunks(16)) {
+            let expected = u128::from_be_bytes(chunk.try_into().unwrap()) % crate::MODULUS as u128;
+            assert_eq!(element.to_canonical_u64() as u128, expected);
+        }
+        assert_ne!(hash_to_field(b"message", DST, 7), hash_to_field(b"messagf", DST, 7));
+    }
+
+    #[test]
+    fn different_dsts_give_unrelated_outputs() {
+        let a = hash_to_field(b"message", b"protocol-a", 16);
+        let b = hash_to_field(b"message", b"protocol-b", 16);
+        assert!(a.iter().all(|x| !b.contains(x)));
+        // The DST is length-suffixed, so a prefix of it separates too.
+        assert_ne!(hash_to_field(b"message", b"protocol-", 1), hash_to_field(b"message", b"protocol-a", 1));
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert!(hash_to_field(b"message", DST, 0).is_empty());
+        assert!(expand_message_xmd(b"message", DST, 0).is_empty());
+        assert_eq!(hash_to_field(b"message", DST, 510).len(), 510);
+
+        let long_message = vec![0xa5; 1 << 20];
+        let elements = hash_to_field(&long_message, DST, 3);
+        assert_eq!(elements, hash_to_field(&long_message, DST, 3));
+        assert_ne!(elements, hash_to_field(&long_message[1..], DST, 3));
+
+        // A DST over 255 bytes is replaced by its oversize-DST digest.
+        let long_dst = vec![b'x'; 300];
+        let digest = hash(&[b"H2C-OVERSIZE-DST-", &long_dst]);
+        assert_eq!(hash_to_field(b"message", &long_dst, 2), hash_to_field(b"message", &digest, 2));
+        let max_dst = vec![b'x'; 255];
+        assert_ne!(hash_to_field(b"message", &max_dst, 2), hash_to_field(b"message", &long_dst, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "8160 bytes")]
+    fn hash_to_field_rejects_oversized_requests() {
+        hash_to_field(b"message", DST, 511);
+    }
+}
@@ -0,0 +1,390 @@
+//! Merkle commitments to vectors of field elements.
+
+use std::collections::BTreeMap;
+
+use crate::hash::poseidon::{self, PoseidonSponge12};
+use crate::hash::Sponge;
+use crate::GoldilocksField;
+
+/// A node of the tree.
+pub type Digest = [GoldilocksField; 4];
+
+/// Digest of the leaves added to reach a power-of-two leaf count. Hashers
+/// must not map any leaf to it; the all-zero digest has no known preimage
+/// under a cryptographic hash.
+pub const EMPTY_LEAF: Digest = [GoldilocksField::ZERO; 4];
+
+/// Hashing used by `MerkleTree`: leaves are hashed into digests, and two
+/// children are compressed into their parent.
+pub trait MerkleHasher {
+    fn hash_leaf(&self, leaf: &[GoldilocksField]) -> Digest;
+    fn compress(&self, left: &Digest, right: &Digest) -> Digest;
+}
+
+/// Width-12 Poseidon: leaves go through the 10*-padded sponge, so leaves of
+/// different lengths never collide, and nodes through `hash_two_to_one`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoseidonHasher;
+
+impl MerkleHasher for PoseidonHasher {
+    fn hash_leaf(&self, leaf: &[GoldilocksField]) -> Digest {
+        let mut sponge: PoseidonSponge12 = Sponge::new(poseidon::Poseidon);
+        sponge.absorb(leaf);
+        sponge.squeeze_array()
+    }
+
+    fn compress(&self, left: &Digest, right: &Digest) -> Digest {
+        poseidon::hash_two_to_one::<12>(left, right)
+    }
+}
+
+/// A binary Merkle tree over hashed leaves. Leaf counts that are not a power
+/// of two are padded with `EMPTY_LEAF` digests on the right.
+#[derive(Debug, Clone)]
+pub struct MerkleTree<H> {
+    hasher: H,
+    leaf_count: usize,
+    /// layers[0] holds the padded leaf digests and the last layer the root.
+    layers: Vec<Vec<Digest>>,
+}
+
+/// An authentication path: the sibling of every node from the leaf up to,
+/// but not including, the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<H> {
+    hasher: H,
+    pub siblings: Vec<Digest>,
+}
+
+/// Authentication paths of several leaves at once, sharing the nodes they
+/// have in common. `nodes` lists, level by level from the leaves up and left
+/// to right within a level, every sibling that cannot be computed from the
+/// opened leaves themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleMultiProof<H> {
+    hasher: H,
+    pub height: usize,
+    pub nodes: Vec<Digest>,
+}
+
+impl<H: MerkleHasher + Clone> MerkleTree<H> {
+    /// Hashes every leaf and builds the tree above them.
+    ///
+    /// Panics if `leaves` is empty.
+    pub fn build(leaves: &[Vec<GoldilocksField>], hasher: H) -> Self {
+        assert!(!leaves.is_empty(), "MerkleTree::build: no leaves");
+        let mut layer: Vec<Digest> = leaves.iter().map(|leaf| hasher.hash_leaf(leaf)).collect();
+        layer.resize(leaves.len().next_power_of_two(), EMPTY_LEAF);
+
+        let mut layers = vec![layer];
+        while layers.last().expect("at least one layer").len() > 1 {
+            let below = layers.last().expect("at least one layer");
+            let above = below.chunks_exact(2).map(|pair| hasher.compress(&pair[0], &pair[1])).collect();
+            layers.push(above);
+        }
+        MerkleTree { hasher, leaf_count: leaves.len(), layers }
+    }
+
+    pub fn root(&self) -> Digest {
+        self.layers.last().expect("at least one layer")[0]
+    }
+
+    /// Number of leaves, not counting the padding.
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Number of levels below the root.
+    pub fn height(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// The authentication path of leaf `index`.
+    ///
+    /// Panics if `index` is not below `leaf_count()`.
+    pub fn open(&self, index: usize) -> MerkleProof<H> {
+        assert!(index < self.leaf_count, "MerkleTree::open: index {index} out of range for {} leaves", self.leaf_count);
+        let siblings = self.layers[..self.height()]
+            .iter()
+            .enumerate()
+            .map(|(level, layer)| layer[(index >> level) ^ 1])
+            .collect();
+        MerkleProof { hasher: self.hasher.clone(), siblings }
+    }
+
+    /// A combined proof for every leaf in `indices`. The order of the indices
+    /// and any repetitions do not affect the proof.
+    ///
+    /// Panics if an index is not below `leaf_count()`.
+    pub fn open_batch(&self, indices: &[usize]) -> MerkleMultiProof<H> {
+        let mut known: Vec<usize> = indices.to_vec();
+        known.sort_unstable();
+        known.dedup();
+        if let Some(&last) = known.last() {
+            assert!(last < self.leaf_count, "MerkleTree::open_batch: index {last} out of range for {} leaves", self.leaf_count);
+        }
+
+        let mut nodes = Vec::new();
+        for layer in &self.layers[..self.height()] {
+            let mut parents = Vec::with_capacity(known.len());
+            let mut i = 0;
+            while i < known.len() {
+                let index = known[i];
+                if known.get(i + 1) == Some(&(index ^ 1)) {
+                    i += 2;
+                } else {
+                    nodes.push(layer[index ^ 1]);
+                    i += 1;
+                }
+                parents.push(index >> 1);
+            }
+            known = parents;
+        }
+        MerkleMultiProof { hasher: self.hasher.clone(), height: self.height(), nodes }
+    }
+}
+
+impl<H: MerkleHasher> MerkleProof<H> {
+    /// A proof from its siblings, leaf level first, e.g. one received over
+    /// the wire.
+    pub fn new(hasher: H, siblings: Vec<Digest>) -> Self {
+        MerkleProof { hasher, siblings }
+    }
+
+    /// Whether `leaf` sits at `index` of the tree with this `root`. A proof
+    /// with `usize::BITS` or more siblings cannot come from a real tree and
+    /// is rejected.
+    pub fn verify(&self, root: &Digest, index: usize, leaf: &[GoldilocksField]) -> bool {
+        if self.siblings.len() >= usize::BITS as usize || index >> self.siblings.len() != 0 {
+            return false;
+        }
+        let mut node = self.hasher.hash_leaf(leaf);
+        for (level, sibling) in self.siblings.iter().enumerate() {
+            node = if index >> level & 1 == 0 {
+                self.hasher.compress(&node, sibling)
+            } else {
+                self.hasher.compress(sibling, &node)
+            };
+        }
+        node == *root
+    }
+}
+
+impl<H: MerkleHasher> MerkleMultiProof<H> {
+    /// Whether each `leaves[i]` sits at `indices[i]` of the tree with this
+    /// `root`. Indices may come in any order; an index may repeat only with
+    /// the same leaf. An empty index set proves nothing and is rejected, as
+    /// is a height of `usize::BITS` or more.
+    pub fn verify(&self, root: &Digest, indices: &[usize], leaves: &[Vec<GoldilocksField>]) -> bool {
+        if indices.is_empty() || indices.len() != leaves.len() || self.height >= usize::BITS as usize {
+            return false;
+        }
+        let mut known = BTreeMap::new();
+        for (index, leaf) in indices.iter().zip(leaves) {
+            if index >> self.height != 0 {
+                return false;
+            }
+            let digest = self.hasher.hash_leaf(leaf);
+            if *known.entry(*index).or_insert(digest) != digest {
+                return false;
+            }
+        }
+
+        let mut proof_nodes = self.nodes.iter();
+        for _ in 0..self.height {
+            let mut parents = BTreeMap::new();
+            let mut entries = known.into_iter().peekable();
+            while let Some((index, digest)) = entries.next() {
+                let sibling = match entries.peek() {
+                    Some((next, _)) if *next == index ^ 1 => entries.next().expect("peeked").1,
+                    _ => match proof_nodes.next() {
+                        Some(node) => *node,
+                        None => return false,
+                    },
+                };
+                let parent = if index & 1 == 0 {
+                    self.hasher.compress(&digest, &sibling)
+                } else {
+                    self.hasher.compress(&sibling, &digest)
+                };
+                parents.insert(index >> 1, parent);
+            }
+            known = parents;
+        }
+        proof_nodes.next().is_none() && known.get(&0) == Some(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_elements, SplitMix64};
+
+    fn random_leaves(seed: u64, count: usize) -> Vec<Vec<GoldilocksField>> {
+        let mut rng = SplitMix64(seed);
+        (0..count).map(|_| random_elements(&mut rng, 3)).collect()
+    }
+
+    #[test]
+    fn every_proof_of_a_1000_leaf_tree_verifies() {
+        let leaves = random_leaves(65, 1000);
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        assert_eq!(tree.height(), 10);
+        let root = tree.root();
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert!(tree.open(i).verify(&root, i, leaf), "leaf {i}");
+        }
+    }
+
+    #[test]
+    fn tampering_is_detected() {
+        let leaves = random_leaves(66, 1000);
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let root = tree.root();
+        let proof = tree.open(417);
+
+        let mut leaf = leaves[417].clone();
+        leaf[1] += GoldilocksField::ONE;
+        assert!(!proof.verify(&root, 417, &leaf));
+
+        let mut bad_root = root;
+        bad_root[3] += GoldilocksField::ONE;
+        assert!(!proof.verify(&bad_root, 417, &leaves[417]));
+
+        assert!(!proof.verify(&root, 416, &leaves[417]));
+        assert!(!proof.verify(&root, 417 + 1024, &leaves[417]));
+
+        let mut bad_proof = proof.clone();
+        bad_proof.siblings[5][0] += GoldilocksField::ONE;
+        assert!(!bad_proof.verify(&root, 417, &leaves[417]));
+    }
+
+    #[test]
+    fn root_is_deterministic() {
+        let leaves = random_leaves(67, 100);
+        let a = MerkleTree::build(&leaves, PoseidonHasher);
+        let b = MerkleTree::build(&leaves, PoseidonHasher);
+        assert_eq!(a.root(), b.root());
+
+        let mut changed = leaves.clone();
+        changed[99].push(GoldilocksField::ZERO);
+        assert_ne!(MerkleTree::build(&changed, PoseidonHasher).root(), a.root());
+    }
+
+    #[test]
+    fn padding_and_small_trees() {
+        let leaves = random_leaves(68, 3);
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let h = PoseidonHasher;
+        let digests: Vec<Digest> = leaves.iter().map(|leaf| h.hash_leaf(leaf)).collect();
+        let expected = h.compress(&h.compress(&digests[0], &digests[1]), &h.compress(&digests[2], &EMPTY_LEAF));
+        assert_eq!(tree.root(), expected);
+
+        let single = MerkleTree::build(&leaves[..1], PoseidonHasher);
+        assert_eq!(single.root(), digests[0]);
+        let proof = single.open(0);
+        assert!(proof.siblings.is_empty());
+        assert!(proof.verify(&single.root(), 0, &leaves[0]));
+    }
+
+    #[test]
+    fn batch_openings_verify_and_share_nodes() {
+        let mut rng = SplitMix64(70);
+        let leaves: Vec<Vec<GoldilocksField>> = (0..1 << 16).map(|_| vec![rng.next_field()]).collect();
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let root = tree.root();
+
+        let indices: Vec<usize> = (0..32).map(|_| (rng.next_u64() % (1 << 16)) as usize).collect();
+        let opened: Vec<_> = indices.iter().map(|i| leaves[*i].clone()).collect();
+        let proof = tree.open_batch(&indices);
+        assert!(proof.verify(&root, &indices, &opened));
+
+        let individual: usize = indices.iter().map(|i| tree.open(*i).siblings.len()).sum();
+        assert_eq!(individual, 32 * 16);
+        // Near the root there are fewer nodes than paths, so paths must share.
+        assert!(proof.nodes.len() < individual, "{} >= {individual}", proof.nodes.len());
+    }
+
+    #[test]
+    fn batch_order_and_duplicates() {
+        let leaves = random_leaves(71, 100);
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let root = tree.root();
+        let sorted = [3, 17, 40, 41, 99];
+        let shuffled = [41, 99, 3, 17, 40, 3, 41];
+        let proof = tree.open_batch(&sorted);
+        assert_eq!(tree.open_batch(&shuffled), proof);
+
+        let leaves_for = |indices: &[usize]| indices.iter().map(|i| leaves[*i].clone()).collect::<Vec<_>>();
+        assert!(proof.verify(&root, &sorted, &leaves_for(&sorted)));
+        assert!(proof.verify(&root, &shuffled, &leaves_for(&shuffled)));
+
+        // A repeated index with two different leaves is rejected.
+        let mut conflicting = leaves_for(&shuffled);
+        conflicting[5] = leaves[4].clone();
+        assert!(!proof.verify(&root, &shuffled, &conflicting));
+    }
+
+    #[test]
+    fn batch_verification_rejects_tampering() {
+        let leaves = random_leaves(72, 1000);
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let root = tree.root();
+        let indices = [5, 6, 300, 301, 998];
+        let opened: Vec<_> = indices.iter().map(|i| leaves[*i].clone()).collect();
+        let proof = tree.open_batch(&indices);
+        assert!(proof.verify(&root, &indices, &opened));
+
+        for wrong in 0..indices.len() {
+            let mut bad = opened.clone();
+            bad[wrong][0] += GoldilocksField::ONE;
+            assert!(!proof.verify(&root, &indices, &bad), "leaf {wrong}");
+        }
+        let mut swapped = opened.clone();
+        swapped.swap(0, 2);
+        assert!(!proof.verify(&root, &indices, &swapped));
+        assert!(!proof.verify(&root, &[5, 6, 300, 301, 997], &opened));
+        assert!(!proof.verify(&root, &indices, &opened[..4]));
+        assert!(!proof.verify(&root, &[], &[]));
+
+        let mut short = proof.clone();
+        short.nodes.pop();
+        assert!(!short.verify(&root, &indices, &opened));
+        let mut long = proof.clone();
+        long.nodes.push(EMPTY_LEAF);
+        assert!(!long.verify(&root, &indices, &opened));
+        let mut altered = proof;
+        altered.nodes[2][1] += GoldilocksField::ONE;
+        assert!(!altered.verify(&root, &indices, &opened));
+    }
+
+    #[test]
+    fn oversized_proofs_are_rejected() {
+        let leaves = random_leaves(70, 4);
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let root = tree.root();
+        let mut proof = tree.open(1);
+        proof.siblings.resize(usize::BITS as usize, EMPTY_LEAF);
+        assert!(!proof.verify(&root, 1, &leaves[1]));
+        proof.siblings.resize(usize::BITS as usize + 8, EMPTY_LEAF);
+        assert!(!proof.verify(&root, 1, &leaves[1]));
+    }
+
+    #[test]
+    fn oversized_batch_proofs_are_rejected() {
+        let leaves = random_leaves(71, 4);
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let root = tree.root();
+        let mut multi = tree.open_batch(&[0, 3]);
+        multi.height = usize::BITS as usize;
+        assert!(!multi.verify(&root, &[0, 3], &[leaves[0].clone(), leaves[3].clone()]));
+        multi.height = usize::MAX;
+        assert!(!multi.verify(&root, &[0, 3], &[leaves[0].clone(), leaves[3].clone()]));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn opening_past_the_leaves_panics() {
+        MerkleTree::build(&random_leaves(69, 3), PoseidonHasher).open(3);
+    }
+}
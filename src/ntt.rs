@@ -0,0 +1,1332 @@
+//! Number-theoretic transforms over power-of-two subgroups.
+//!
+//! The forward transform maps coefficients c_0..c_{n-1} to the evaluations
+//! at 1, w, ..., w^(n - 1) for the primitive n-th root of unity
+//! w = `GoldilocksField::primitive_root_of_unity(log n)`; both sides are in
+//! natural order.
+
+use core::ops::{Add, Mul, Sub};
+use std::sync::OnceLock;
+
+use crate::{batch_inverse, GoldilocksField, TWO_ADICITY};
+
+/// The usual coset offset, the multiplicative generator 7, which lies outside
+/// every two-power subgroup.
+pub const DEFAULT_COSET_OFFSET: GoldilocksField = GoldilocksField::MULTIPLICATIVE_GENERATOR;
+
+/// Error returned by the NTT functions for unsupported input sizes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NttError {
+    /// The length is not a power of two.
+    NotPowerOfTwo(usize),
+    /// The length exceeds 2^32, the largest two-power subgroup.
+    TooLarge(usize),
+    /// A coset offset of zero does not define a coset.
+    ZeroOffset,
+    /// The blowup factor is not a power of two.
+    InvalidBlowup(usize),
+    /// Two inputs that must have the same length do not.
+    LengthMismatch(usize, usize),
+    /// A buffer of the given length does not split into rows of the given
+    /// length.
+    UnevenRows(usize, usize),
+}
+
+impl core::fmt::Display for NttError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NttError::NotPowerOfTwo(len) => write!(f, "NTT length {len} is not a power of two"),
+            NttError::TooLarge(len) => write!(f, "NTT length {len} exceeds 2^{TWO_ADICITY}"),
+            NttError::ZeroOffset => write!(f, "coset offset must be nonzero"),
+            NttError::InvalidBlowup(blowup) => write!(f, "blowup factor {blowup} is not a power of two"),
+            NttError::LengthMismatch(a, b) => write!(f, "input lengths {a} and {b} differ"),
+            NttError::UnevenRows(len, row_len) => write!(f, "length {len} is not a multiple of the row length {row_len}"),
+        }
+    }
+}
+
+impl std::error::Error for NttError {}
+
+/// Returns log2 of a supported transform length.
+fn log_len(len: usize) -> Result<u32, NttError> {
+    if !len.is_power_of_two() {
+        return Err(NttError::NotPowerOfTwo(len));
+    }
+    let log_n = len.trailing_zeros();
+    if log_n > TWO_ADICITY {
+        return Err(NttError::TooLarge(len));
+    }
+    Ok(log_n)
+}
+
+/// Reverses the low `log_n` bits of `i`.
+pub fn bit_reverse_index(i: usize, log_n: u32) -> usize {
+    if log_n == 0 {
+        return 0;
+    }
+    i.reverse_bits() >> (usize::BITS - log_n)
+}
+
+/// Moves each entry to its bit-reversed index. Applying it twice is the
+/// identity.
+///
+/// Panics if the length is not a power of two; an empty slice is left alone.
+pub fn bit_reverse_permute<T>(values: &mut [T]) {
+    let n = values.len();
+    if n == 0 {
+        return;
+    }
+    assert!(n.is_power_of_two(), "bit_reverse_permute: length {n} is not a power of two");
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = bit_reverse_index(i, log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+/// The first n / 2 powers of the primitive n-th root of unity (or of its
+/// inverse), in bit-reversed order. At every butterfly stage, block i then
+/// uses entry i.
+fn twiddles(log_n: u32, inverse: bool) -> Vec<GoldilocksField> {
+    if log_n == 0 {
+        return Vec::new();
+    }
+    let mut root = GoldilocksField::primitive_root_of_unity(log_n).expect("log_n is at most TWO_ADICITY");
+    if inverse {
+        root = GoldilocksField::ONE / root;
+    }
+    let half = 1usize << (log_n - 1);
+    let mut table = Vec::with_capacity(half);
+    let mut power = GoldilocksField::ONE;
+    for _ in 0..half {
+        table.push(power);
+        power *= root;
+    }
+    bit_reverse_permute(&mut table);
+    table
+}
+
+/// Process-wide twiddle tables, built on first use for each size and shared
+/// by every transform afterwards.
+///
+/// Entry log_n holds the tables for length-2^log_n transforms: the first
+/// 2^(log_n - 1) powers of w (or w^-1) in bit-reversed order.
+pub struct TwiddleCache;
+
+static FORWARD_TWIDDLES: [OnceLock<Vec<GoldilocksField>>; TWO_ADICITY as usize + 1] =
+    [const { OnceLock::new() }; TWO_ADICITY as usize + 1];
+static INVERSE_TWIDDLES: [OnceLock<Vec<GoldilocksField>>; TWO_ADICITY as usize + 1] =
+    [const { OnceLock::new() }; TWO_ADICITY as usize + 1];
+
+/// Number of tables built per (log_n, inverse), to check that they are reused.
+#[cfg(test)]
+static TABLE_BUILDS: [[std::sync::atomic::AtomicUsize; 2]; TWO_ADICITY as usize + 1] =
+    [const { [const { std::sync::atomic::AtomicUsize::new(0) }; 2] }; TWO_ADICITY as usize + 1];
+
+impl TwiddleCache {
+    /// Forward twiddles for length-2^log_n transforms.
+    ///
+    /// Panics if log_n exceeds `TWO_ADICITY`.
+    pub fn forward(log_n: u32) -> &'static [GoldilocksField] {
+        Self::table(&FORWARD_TWIDDLES, log_n, false)
+    }
+
+    /// Inverse twiddles for length-2^log_n transforms.
+    ///
+    /// Panics if log_n exceeds `TWO_ADICITY`.
+    pub fn inverse(log_n: u32) -> &'static [GoldilocksField] {
+        Self::table(&INVERSE_TWIDDLES, log_n, true)
+    }
+
+    /// Builds the forward and inverse tables for every size up to
+    /// 2^max_log_n, so later transforms don't pay for them.
+    ///
+    /// Panics if max_log_n exceeds `TWO_ADICITY`.
+    pub fn preload(max_log_n: u32) {
+        for log_n in 0..=max_log_n {
+            Self::forward(log_n);
+            Self::inverse(log_n);
+        }
+    }
+
+    fn table(
+        tables: &'static [OnceLock<Vec<GoldilocksField>>],
+        log_n: u32,
+        inverse: bool,
+    ) -> &'static [GoldilocksField] {
+        assert!(log_n <= TWO_ADICITY, "TwiddleCache: log_n {log_n} exceeds {TWO_ADICITY}");
+        tables[log_n as usize].get_or_init(|| {
+            #[cfg(test)]
+            TABLE_BUILDS[log_n as usize][inverse as usize].fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            twiddles(log_n, inverse)
+        })
+    }
+}
+
+/// With the `parallel` feature, inputs of at least this many elements are
+/// split across threads; smaller ones stay sequential.
+#[cfg(feature = "parallel")]
+pub const PARALLEL_THRESHOLD: usize = 1 << 14;
+
+/// Values the butterflies can transform: vectors over Goldilocks such as the
+/// extension field elements. The twiddles stay in the base field, so any such
+/// type transforms coefficient-wise with the same roots of unity.
+pub trait NttElement:
+    Copy + Send + Sync + Add<Output = Self> + Sub<Output = Self> + Mul<GoldilocksField, Output = Self>
+{
+}
+
+impl<T> NttElement for T where
+    T: Copy + Send + Sync + Add<Output = T> + Sub<Output = T> + Mul<GoldilocksField, Output = T>
+{
+}
+
+/// Calls `f(i, chunk)` for every length-`chunk_len` chunk, on several threads
+/// for large inputs when the `parallel` feature is enabled.
+fn for_each_chunk<T: NttElement, F>(values: &mut [T], chunk_len: usize, f: F)
+where
+    F: Fn(usize, &mut [T]) + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if values.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        values.par_chunks_exact_mut(chunk_len).enumerate().for_each(|(i, chunk)| f(i, chunk));
+        return;
+    }
+    values.chunks_exact_mut(chunk_len).enumerate().for_each(|(i, chunk)| f(i, chunk));
+}
+
+/// (u, v) -> (u + w v, u - w v) across the two halves of a block.
+fn forward_block<T: NttElement>(block: &mut [T], w: GoldilocksField) {
+    let (lo, hi) = block.split_at_mut(block.len() / 2);
+    for (u, v) in lo.iter_mut().zip(hi) {
+        let t = *v * w;
+        (*u, *v) = (*u + t, *u - t);
+    }
+}
+
+/// (u, v) -> (u + v, (u - v) w) across the two halves of a block.
+fn inverse_block<T: NttElement>(block: &mut [T], w: GoldilocksField) {
+    let (lo, hi) = block.split_at_mut(block.len() / 2);
+    for (u, v) in lo.iter_mut().zip(hi) {
+        (*u, *v) = (*u + *v, (*u - *v) * w);
+    }
+}
+
+/// Cooley-Tukey butterflies taking natural-order input to bit-reversed output.
+fn forward_butterflies<T: NttElement>(values: &mut [T], twiddles: &[GoldilocksField]) {
+    let mut half_block = values.len();
+    while half_block > 1 {
+        half_block /= 2;
+        for_each_chunk(values, 2 * half_block, |block, chunk| forward_block(chunk, twiddles[block]));
+    }
+}
+
+/// Gentleman-Sande butterflies taking bit-reversed input to natural output.
+fn inverse_butterflies<T: NttElement>(values: &mut [T], twiddles: &[GoldilocksField]) {
+    let mut half_block = 1;
+    while half_block < values.len() {
+        for_each_chunk(values, 2 * half_block, |block, chunk| inverse_block(chunk, twiddles[block]));
+        half_block *= 2;
+    }
+}
+
+/// The primitive 4th root of unity used by the transforms, 2^48.
+const W4: GoldilocksField = GoldilocksField::from_canonical(0x0001_0000_0000_0000);
+/// The primitive 8th root of unity used by the transforms, 2^120 = -2^24.
+const W8: GoldilocksField = GoldilocksField::from_canonical(0xffff_fffe_ff00_0001);
+/// W8^3 = 2^168 = -2^72.
+const W8_CUBED: GoldilocksField = GoldilocksField::from_canonical(0xffff_feff_0000_0101);
+
+/// Length-2 forward NTT, (a, b) -> (a + b, a - b).
+#[inline]
+pub fn ntt2(values: &mut [GoldilocksField; 2]) {
+    let [a, b] = *values;
+    *values = [a + b, a - b];
+}
+
+/// Length-4 forward NTT with the butterflies unrolled; natural order in and
+/// out, like `ntt_in_place`.
+#[inline]
+pub fn ntt4(values: &mut [GoldilocksField; 4]) {
+    let [x0, x1, x2, x3] = *values;
+    let (u0, u1) = (x0 + x2, x1 + x3);
+    let (v0, v1) = (x0 - x2, (x1 - x3) * W4);
+    *values = [u0 + u1, v0 + v1, u0 - u1, v0 - v1];
+}
+
+/// Length-8 forward NTT with the butterflies unrolled; natural order in and
+/// out, like `ntt_in_place`.
+#[inline]
+pub fn ntt8(values: &mut [GoldilocksField; 8]) {
+    let [x0, x1, x2, x3, x4, x5, x6, x7] = *values;
+    // Split into the even outputs, the NTT of x_j + x_(j+4), and the odd
+    // outputs, the NTT of (x_j - x_(j+4)) W8^j.
+    let mut even = [x0 + x4, x1 + x5, x2 + x6, x3 + x7];
+    let mut odd = [x0 - x4, (x1 - x5) * W8, (x2 - x6) * W4, (x3 - x7) * W8_CUBED];
+    ntt4(&mut even);
+    ntt4(&mut odd);
+    *values = [even[0], odd[0], even[1], odd[1], even[2], odd[2], even[3], odd[3]];
+}
+
+/// Transforms slices of length at most 8 with the unrolled kernels and
+/// returns false, leaving `values` untouched, for longer ones.
+fn ntt_small(values: &mut [GoldilocksField]) -> bool {
+    match values.len() {
+        0 | 1 => {}
+        2 => ntt2(values.try_into().expect("length is 2")),
+        4 => ntt4(values.try_into().expect("length is 4")),
+        8 => ntt8(values.try_into().expect("length is 8")),
+        _ => return false,
+    }
+    true
+}
+
+/// Replaces `values` by its forward NTT. The length must be a power of two
+/// no larger than 2^32.
+pub fn ntt_in_place(values: &mut [GoldilocksField]) -> Result<(), NttError> {
+    let log_n = log_len(values.len())?;
+    if !ntt_small(values) {
+        forward_butterflies(values, TwiddleCache::forward(log_n));
+        bit_reverse_permute(values);
+    }
+    Ok(())
+}
+
+/// Lengths above this use the four-step algorithm in `ntt_large`.
+pub const FOUR_STEP_THRESHOLD: usize = 1 << 20;
+
+/// Forward NTT like `ntt_in_place`, switching to `four_step_ntt` for lengths
+/// above `FOUR_STEP_THRESHOLD`. The output is identical either way.
+pub fn ntt_large(values: &mut [GoldilocksField]) -> Result<(), NttError> {
+    if values.len() > FOUR_STEP_THRESHOLD {
+        four_step_ntt(values)
+    } else {
+        ntt_in_place(values)
+    }
+}
+
+/// Forward NTT by the four-step (here six-step, with explicit transposes)
+/// algorithm, which works on rows that fit in cache.
+///
+/// For n = n1 * n2, input index j = j1 + n1 * j2 and output index
+/// k = k2 + n2 * k1 split w^(jk) into w_n2^(j2 k2) * w^(j1 k2) * w_n1^(j1 k1).
+/// The input is transposed so each j1 is a row, rows get length-n2 NTTs, entry
+/// (j1, k2) is multiplied by w^(j1 k2), and after a second transpose rows get
+/// length-n1 NTTs; a last transpose restores natural order.
+pub fn four_step_ntt(values: &mut [GoldilocksField]) -> Result<(), NttError> {
+    let log_n = log_len(values.len())?;
+    if log_n < 2 {
+        return ntt_in_place(values);
+    }
+    let (log_n1, log_n2) = (log_n.div_ceil(2), log_n / 2);
+    let (n1, n2) = (1usize << log_n1, 1usize << log_n2);
+
+    // values is n2 rows of length n1; make it n1 rows of length n2.
+    transpose_in_place(values, n2, n1);
+    ntt_rows(values, n2, TwiddleCache::forward(log_n2));
+
+    let w = GoldilocksField::primitive_root_of_unity(log_n).expect("log_n is at most TWO_ADICITY");
+    for_each_chunk(values, n2, |j1, row| {
+        let row_root = w.pow_u64(j1 as u64);
+        let mut twiddle = GoldilocksField::ONE;
+        for x in row.iter_mut() {
+            *x *= twiddle;
+            twiddle *= row_root;
+        }
+    });
+
+    transpose_in_place(values, n1, n2);
+    ntt_rows(values, n1, TwiddleCache::forward(log_n1));
+    transpose_in_place(values, n2, n1);
+    Ok(())
+}
+
+/// Forward NTT of every length-`row_len` row, sharing one twiddle table.
+fn ntt_rows(values: &mut [GoldilocksField], row_len: usize, twiddles: &[GoldilocksField]) {
+    for_each_chunk(values, row_len, |_, row| {
+        if !ntt_small(row) {
+            forward_butterflies(row, twiddles);
+            bit_reverse_permute(row);
+        }
+    });
+}
+
+/// Forward NTT of every row of a row-major matrix with rows of length
+/// `row_len`, sharing one twiddle table; equivalent to calling `ntt_in_place`
+/// on each row. Column-major data can go through `transpose` first.
+pub fn ntt_batch(data: &mut [GoldilocksField], row_len: usize) -> Result<(), NttError> {
+    let log_n = log_len(row_len)?;
+    if data.len() % row_len != 0 {
+        return Err(NttError::UnevenRows(data.len(), row_len));
+    }
+    ntt_rows(data, row_len, TwiddleCache::forward(log_n));
+    Ok(())
+}
+
+/// Transposes a row-major `rows` x `cols` matrix in place, so it becomes a
+/// row-major `cols` x `rows` matrix.
+pub fn transpose(values: &mut [GoldilocksField], rows: usize, cols: usize) -> Result<(), NttError> {
+    if rows.checked_mul(cols) != Some(values.len()) {
+        return Err(NttError::UnevenRows(values.len(), cols));
+    }
+    transpose_in_place(values, rows, cols);
+    Ok(())
+}
+
+/// Transposes a row-major rows x cols matrix without copying it. Square
+/// matrices swap entries across the diagonal, block by block so that both
+/// sides stay within a few cache lines; other shapes follow the cycles of the
+/// permutation, marking visited entries in a bit set of one bit per element.
+fn transpose_in_place(values: &mut [GoldilocksField], rows: usize, cols: usize) {
+    if rows == cols {
+        transpose_square(values, rows);
+    } else {
+        transpose_cycles(values, rows, cols);
+    }
+}
+
+fn transpose_square(values: &mut [GoldilocksField], n: usize) {
+    const BLOCK: usize = 16;
+    for row_block in (0..n).step_by(BLOCK) {
+        for col_block in (row_block..n).step_by(BLOCK) {
+            for row in row_block..(row_block + BLOCK).min(n) {
+                for col in col_block.max(row + 1)..(col_block + BLOCK).min(n) {
+                    values.swap(row * n + col, col * n + row);
+                }
+            }
+        }
+    }
+}
+
+/// The entry at `i = row * cols + col` moves to `col * rows + row`; the
+/// first and last entries stay put.
+fn transpose_cycles(values: &mut [GoldilocksField], rows: usize, cols: usize) {
+    let n = values.len();
+    let mut visited = vec![0u64; n.div_ceil(64)];
+    for start in 1..n.saturating_sub(1) {
+        if visited[start / 64] >> (start % 64) & 1 != 0 {
+            continue;
+        }
+        let mut carried = values[start];
+        let mut i = start;
+        loop {
+            let target = (i % cols) * rows + i / cols;
+            core::mem::swap(&mut values[target], &mut carried);
+            visited[target / 64] |= 1 << (target % 64);
+            if target == start {
+                break;
+            }
+            i = target;
+        }
+    }
+}
+
+/// Forward NTT of a slice of elements over Goldilocks, such as
+/// `GoldilocksQuadraticExtension` values: each coordinate is transformed as by
+/// `ntt_in_place`. The length must be a power of two no larger than 2^32.
+pub fn ntt_in_place_generic<T: NttElement>(values: &mut [T]) -> Result<(), NttError> {
+    let log_n = log_len(values.len())?;
+    forward_butterflies(values, TwiddleCache::forward(log_n));
+    bit_reverse_permute(values);
+    Ok(())
+}
+
+/// Inverse of `ntt_in_place_generic`, including the scaling by n^-1.
+pub fn intt_in_place_generic<T: NttElement>(values: &mut [T]) -> Result<(), NttError> {
+    let log_n = log_len(values.len())?;
+    bit_reverse_permute(values);
+    inverse_butterflies(values, TwiddleCache::inverse(log_n));
+    let n_inv = GoldilocksField::ONE / GoldilocksField::from(values.len() as u64);
+    for x in values.iter_mut() {
+        *x = *x * n_inv;
+    }
+    Ok(())
+}
+
+/// Inverse of `ntt_in_place`, including the scaling by n^-1.
+pub fn intt_in_place(values: &mut [GoldilocksField]) -> Result<(), NttError> {
+    let log_n = log_len(values.len())?;
+    bit_reverse_permute(values);
+    inverse_butterflies(values, TwiddleCache::inverse(log_n));
+    let n_inv = GoldilocksField::ONE / GoldilocksField::from(values.len() as u64);
+    for x in values.iter_mut() {
+        *x *= n_inv;
+    }
+    Ok(())
+}
+
+/// Multiplies values[i] by factor^i.
+fn scale_by_powers(values: &mut [GoldilocksField], factor: GoldilocksField) {
+    let mut power = GoldilocksField::ONE;
+    for x in values.iter_mut() {
+        *x *= power;
+        power *= factor;
+    }
+}
+
+/// Evaluates the coefficients in `values` on the coset offset * H, where H is
+/// the subgroup of size n: entry i becomes p(offset * w^i).
+pub fn coset_ntt(values: &mut [GoldilocksField], offset: GoldilocksField) -> Result<(), NttError> {
+    if offset == GoldilocksField::ZERO {
+        return Err(NttError::ZeroOffset);
+    }
+    log_len(values.len())?;
+    scale_by_powers(values, offset);
+    ntt_in_place(values)
+}
+
+/// Inverse of `coset_ntt`: recovers coefficients from evaluations on offset * H.
+pub fn coset_intt(values: &mut [GoldilocksField], offset: GoldilocksField) -> Result<(), NttError> {
+    if offset == GoldilocksField::ZERO {
+        return Err(NttError::ZeroOffset);
+    }
+    intt_in_place(values)?;
+    scale_by_powers(values, GoldilocksField::ONE / offset);
+    Ok(())
+}
+
+/// Evaluates the polynomial with the given n coefficients on the coset
+/// offset * H of size blowup * n, by zero-padding and running `coset_ntt`.
+/// Both n and blowup must be powers of two.
+pub fn low_degree_extension(
+    coeffs: &[GoldilocksField],
+    blowup: usize,
+    offset: GoldilocksField,
+) -> Result<Vec<GoldilocksField>, NttError> {
+    log_len(coeffs.len())?;
+    if !blowup.is_power_of_two() {
+        return Err(NttError::InvalidBlowup(blowup));
+    }
+    let extended_len = coeffs.len().checked_mul(blowup).ok_or(NttError::TooLarge(usize::MAX))?;
+    let mut values = coeffs.to_vec();
+    values.resize(extended_len, GoldilocksField::ZERO);
+    coset_ntt(&mut values, offset)?;
+    Ok(values)
+}
+
+/// Evaluates a polynomial given by any number of coefficients on the coset
+/// offset * H, where |H| is blowup times the coefficient count rounded up to
+/// a power of two. This is `low_degree_extension` with the padding done for
+/// the caller.
+pub fn evaluate_offset_fft(
+    coeffs: &[GoldilocksField],
+    blowup: usize,
+    offset: GoldilocksField,
+) -> Result<Vec<GoldilocksField>, NttError> {
+    let mut padded = coeffs.to_vec();
+    padded.resize(coeffs.len().next_power_of_two(), GoldilocksField::ZERO);
+    low_degree_extension(&padded, blowup, offset)
+}
+
+/// Recovers the n coefficients of the polynomial of degree below n whose
+/// evaluations on the subgroup H of size n = len(evals) are `evals`. High
+/// zero coefficients are kept; `interpolate_offset_fft` is the coset variant.
+pub fn interpolate_fft(evals: &[GoldilocksField]) -> Result<Vec<GoldilocksField>, NttError> {
+    let mut coeffs = evals.to_vec();
+    intt_in_place(&mut coeffs)?;
+    Ok(coeffs)
+}
+
+/// Recovers the coefficients of the polynomial of degree below n whose
+/// evaluations on offset * H are `evals`, for n = len(evals) a power of two.
+pub fn interpolate_offset_fft(evals: &[GoldilocksField], offset: GoldilocksField) -> Result<Vec<GoldilocksField>, NttError> {
+    let mut coeffs = evals.to_vec();
+    coset_intt(&mut coeffs, offset)?;
+    Ok(coeffs)
+}
+
+/// Evaluates at z the polynomial of degree below n whose evaluations on the
+/// subgroup H of size n = len(evals) are `evals`, in O(n) and without
+/// interpolating. See `barycentric_evaluate_coset`.
+pub fn barycentric_evaluate(evals: &[GoldilocksField], z: GoldilocksField) -> Result<GoldilocksField, NttError> {
+    barycentric_evaluate_coset(evals, GoldilocksField::ONE, z)
+}
+
+/// Like `barycentric_evaluate`, for evaluations on offset * H.
+///
+/// With x_i = offset * w^i, the Lagrange basis is
+/// L_i(z) = (z^n - offset^n) * x_i / (n * offset^n * (z - x_i)), so
+/// p(z) = (z^n - offset^n) / (n * offset^n) * sum(evals[i] * x_i / (z - x_i)).
+/// If z is one of the x_i the matching evaluation is returned directly.
+pub fn barycentric_evaluate_coset(
+    evals: &[GoldilocksField],
+    offset: GoldilocksField,
+    z: GoldilocksField,
+) -> Result<GoldilocksField, NttError> {
+    if offset == GoldilocksField::ZERO {
+        return Err(NttError::ZeroOffset);
+    }
+    let log_n = log_len(evals.len())?;
+    let w = GoldilocksField::primitive_root_of_unity(log_n).expect("log_n is at most TWO_ADICITY");
+
+    let mut points = Vec::with_capacity(evals.len());
+    let mut x = offset;
+    for eval in evals {
+        if x == z {
+            return Ok(*eval);
+        }
+        points.push(x);
+        x *= w;
+    }
+    let differences: Vec<_> = points.iter().map(|x| z - *x).collect();
+    let inverses = batch_inverse(&differences).expect("z is not a domain point");
+
+    let sum = evals
+        .iter()
+        .zip(&points)
+        .zip(&inverses)
+        .fold(GoldilocksField::ZERO, |acc, ((eval, x), inverse)| acc + *eval * *x * *inverse);
+    let offset_n = offset.pow_u64(evals.len() as u64);
+    let vanishing = z.pow_u64(evals.len() as u64) - offset_n;
+    Ok(vanishing * sum / (GoldilocksField::from(evals.len() as u64) * offset_n))
+}
+
+/// a[i] *= b[i], on several threads for large inputs when the `parallel`
+/// feature is enabled.
+fn pointwise_mul(a: &mut [GoldilocksField], b: &[GoldilocksField]) {
+    #[cfg(feature = "parallel")]
+    if a.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        a.par_iter_mut().zip(b.par_iter()).for_each(|(x, y)| *x *= *y);
+        return;
+    }
+    for (x, y) in a.iter_mut().zip(b) {
+        *x *= *y;
+    }
+}
+
+/// Multiplies two polynomials in coefficient form through NTTs of the next
+/// power of two at least len(a) + len(b) - 1. Trailing zero coefficients
+/// are trimmed, so the zero polynomial is the empty vector.
+///
+/// Panics if the product needs more than 2^32 coefficients.
+pub fn poly_mul(a: &[GoldilocksField], b: &[GoldilocksField]) -> Vec<GoldilocksField> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let product_len = a.len() + b.len() - 1;
+    let n = product_len.next_power_of_two();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(n, GoldilocksField::ZERO);
+    fb.resize(n, GoldilocksField::ZERO);
+    ntt_in_place(&mut fa).expect("poly_mul: product too large");
+    ntt_in_place(&mut fb).expect("poly_mul: product too large");
+    pointwise_mul(&mut fa, &fb);
+    intt_in_place(&mut fa).expect("poly_mul: product too large");
+    fa.truncate(product_len);
+    while fa.last() == Some(&GoldilocksField::ZERO) {
+        fa.pop();
+    }
+    fa
+}
+
+/// Returns log2 of a supported negacyclic length, which leaves room for the
+/// 2n-th root of unity: n <= 2^31.
+fn negacyclic_log_len(len: usize) -> Result<u32, NttError> {
+    let log_n = log_len(len)?;
+    if log_n == TWO_ADICITY {
+        return Err(NttError::TooLarge(len));
+    }
+    Ok(log_n)
+}
+
+/// Forward negacyclic NTT, the transform that diagonalizes multiplication in
+/// Fp[x]/(x^n + 1): entry i becomes p(psi * w^i) = p(psi^(2i + 1)) for the
+/// primitive 2n-th root psi with psi^2 = w. The length must be a power of two
+/// no larger than 2^31.
+pub fn negacyclic_ntt(values: &mut [GoldilocksField]) -> Result<(), NttError> {
+    let log_n = negacyclic_log_len(values.len())?;
+    let psi = GoldilocksField::primitive_root_of_unity(log_n + 1).expect("log_n + 1 is at most TWO_ADICITY");
+    scale_by_powers(values, psi);
+    ntt_in_place(values)
+}
+
+/// Inverse of `negacyclic_ntt`.
+pub fn negacyclic_intt(values: &mut [GoldilocksField]) -> Result<(), NttError> {
+    let log_n = negacyclic_log_len(values.len())?;
+    let psi = GoldilocksField::primitive_root_of_unity(log_n + 1).expect("log_n + 1 is at most TWO_ADICITY");
+    intt_in_place(values)?;
+    scale_by_powers(values, GoldilocksField::ONE / psi);
+    Ok(())
+}
+
+/// The product of `a` and `b` in Fp[x]/(x^n + 1), where both have the same
+/// power-of-two length n <= 2^31: x^n wraps around with a sign flip.
+pub fn negacyclic_convolve(a: &[GoldilocksField], b: &[GoldilocksField]) -> Result<Vec<GoldilocksField>, NttError> {
+    if a.len() != b.len() {
+        return Err(NttError::LengthMismatch(a.len(), b.len()));
+    }
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    negacyclic_ntt(&mut fa)?;
+    negacyclic_ntt(&mut fb)?;
+    pointwise_mul(&mut fa, &fb);
+    negacyclic_intt(&mut fa)?;
+    Ok(fa)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EPSILON, MODULUS};
+    use crate::test_utils::{random_elements, SplitMix64};
+    use crate::GoldilocksQuadraticExtension;
+
+    fn horner(coeffs: &[GoldilocksField], x: GoldilocksField) -> GoldilocksField {
+        coeffs.iter().rev().fold(GoldilocksField::ZERO, |acc, c| acc * x + *c)
+    }
+
+    /// Direct O(n^2) evaluation at the powers of the n-th root of unity.
+    fn naive_dft(coeffs: &[GoldilocksField]) -> Vec<GoldilocksField> {
+        let n = coeffs.len();
+        let w = GoldilocksField::primitive_root_of_unity(n.trailing_zeros()).unwrap();
+        (0..n as u64).map(|i| horner(coeffs, w.pow_u64(i))).collect()
+    }
+
+    #[test]
+    fn ntt_matches_naive_dft() {
+        let mut rng = SplitMix64(40);
+        for log_n in 0..=6 {
+            let coeffs = random_elements(&mut rng, 1 << log_n);
+            let mut values = coeffs.clone();
+            ntt_in_place(&mut values).unwrap();
+            assert_eq!(values, naive_dft(&coeffs), "n = {}", 1 << log_n);
+        }
+    }
+
+    #[test]
+    fn ntt_round_trips() {
+        let mut rng = SplitMix64(41);
+        for n in [1, 2, 1024, 1 << 16] {
+            let original = random_elements(&mut rng, n);
+            let mut values = original.clone();
+            ntt_in_place(&mut values).unwrap();
+            intt_in_place(&mut values).unwrap();
+            assert_eq!(values, original, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn ntt_of_delta_is_all_ones() {
+        let mut values = vec![GoldilocksField::ZERO; 256];
+        values[0] = GoldilocksField::ONE;
+        ntt_in_place(&mut values).unwrap();
+        assert!(values.iter().all(|x| *x == GoldilocksField::ONE));
+    }
+
+    #[test]
+    fn pointwise_product_is_cyclic_convolution() {
+        let mut rng = SplitMix64(42);
+        for n in [1, 2, 4, 8, 32] {
+            let a = random_elements(&mut rng, n);
+            let b = random_elements(&mut rng, n);
+            let mut expected = vec![GoldilocksField::ZERO; n];
+            for i in 0..n {
+                for j in 0..n {
+                    expected[(i + j) % n] += a[i] * b[j];
+                }
+            }
+
+            let (mut fa, mut fb) = (a.clone(), b.clone());
+            ntt_in_place(&mut fa).unwrap();
+            ntt_in_place(&mut fb).unwrap();
+            let mut product: Vec<_> = fa.iter().zip(&fb).map(|(x, y)| *x * *y).collect();
+            intt_in_place(&mut product).unwrap();
+            assert_eq!(product, expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn coset_ntt_evaluates_on_the_coset() {
+        let mut rng = SplitMix64(43);
+        for (log_n, offset) in [(0, 5), (3, 7), (6, 7), (8, 0xdead_beef)] {
+            let offset = GoldilocksField::new(offset);
+            let coeffs = random_elements(&mut rng, 1 << log_n);
+            let mut values = coeffs.clone();
+            coset_ntt(&mut values, offset).unwrap();
+            let w = GoldilocksField::primitive_root_of_unity(log_n).unwrap();
+            for (i, value) in values.iter().enumerate() {
+                assert_eq!(*value, horner(&coeffs, offset * w.pow_u64(i as u64)), "n = {}, i = {i}", 1 << log_n);
+            }
+        }
+    }
+
+    #[test]
+    fn coset_ntt_round_trips() {
+        let mut rng = SplitMix64(44);
+        for offset in [GoldilocksField::ONE, DEFAULT_COSET_OFFSET, rng.next_field()] {
+            for n in [1, 16, 1024] {
+                let original = random_elements(&mut rng, n);
+                let mut values = original.clone();
+                coset_ntt(&mut values, offset).unwrap();
+                coset_intt(&mut values, offset).unwrap();
+                assert_eq!(values, original, "n = {n}, offset = {offset}");
+            }
+        }
+    }
+
+    #[test]
+    fn coset_ntt_with_unit_offset_is_the_plain_ntt() {
+        let mut rng = SplitMix64(45);
+        let mut coset = random_elements(&mut rng, 64);
+        let mut plain = coset.clone();
+        coset_ntt(&mut coset, GoldilocksField::ONE).unwrap();
+        ntt_in_place(&mut plain).unwrap();
+        assert_eq!(coset, plain);
+    }
+
+    #[test]
+    fn coset_ntt_rejects_a_zero_offset() {
+        let mut values = vec![GoldilocksField::ONE; 4];
+        assert_eq!(coset_ntt(&mut values, GoldilocksField::ZERO), Err(NttError::ZeroOffset));
+        assert_eq!(coset_intt(&mut values, GoldilocksField::ZERO), Err(NttError::ZeroOffset));
+    }
+
+    #[test]
+    fn bit_reverse_permute_matches_naive_reversal() {
+        for log_n in 0..=12 {
+            let n = 1usize << log_n;
+            let mut values: Vec<usize> = (0..n).collect();
+            bit_reverse_permute(&mut values);
+            for (i, value) in values.iter().enumerate() {
+                let naive = (0..log_n).fold(0, |acc, bit| acc << 1 | (i >> bit) & 1);
+                assert_eq!(*value, naive, "n = {n}, i = {i}");
+                assert_eq!(bit_reverse_index(i, log_n), naive);
+            }
+            bit_reverse_permute(&mut values);
+            assert!(values.iter().copied().eq(0..n), "not an involution for n = {n}");
+        }
+    }
+
+    #[test]
+    fn bit_reverse_permute_of_trivial_slices() {
+        let mut single = [GoldilocksField::TWO];
+        bit_reverse_permute(&mut single);
+        assert_eq!(single, [GoldilocksField::TWO]);
+        bit_reverse_permute::<u8>(&mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a power of two")]
+    fn bit_reverse_permute_rejects_other_lengths() {
+        bit_reverse_permute(&mut [1, 2, 3]);
+    }
+
+    #[test]
+    fn low_degree_extension_matches_horner() {
+        let mut rng = SplitMix64(46);
+        let coeffs = random_elements(&mut rng, 16);
+        let extended = low_degree_extension(&coeffs, 4, DEFAULT_COSET_OFFSET).unwrap();
+        assert_eq!(extended.len(), 64);
+        let w = GoldilocksField::primitive_root_of_unity(6).unwrap();
+        for (i, value) in extended.iter().enumerate() {
+            assert_eq!(*value, horner(&coeffs, DEFAULT_COSET_OFFSET * w.pow_u64(i as u64)), "i = {i}");
+        }
+    }
+
+    #[test]
+    fn low_degree_extension_without_blowup_is_the_coset_ntt() {
+        let mut rng = SplitMix64(47);
+        let coeffs = random_elements(&mut rng, 32);
+        let mut expected = coeffs.clone();
+        coset_ntt(&mut expected, DEFAULT_COSET_OFFSET).unwrap();
+        assert_eq!(low_degree_extension(&coeffs, 1, DEFAULT_COSET_OFFSET), Ok(expected));
+    }
+
+    #[test]
+    fn low_degree_extension_rejects_bad_sizes() {
+        let coeffs = vec![GoldilocksField::ONE; 8];
+        for blowup in [0, 3, 6] {
+            assert_eq!(low_degree_extension(&coeffs, blowup, DEFAULT_COSET_OFFSET), Err(NttError::InvalidBlowup(blowup)));
+        }
+        assert_eq!(
+            low_degree_extension(&coeffs[..5], 2, DEFAULT_COSET_OFFSET),
+            Err(NttError::NotPowerOfTwo(5))
+        );
+    }
+
+    #[test]
+    fn offset_fft_round_trips() {
+        let mut rng = SplitMix64(49);
+        for (len, blowup) in [(1, 1), (5, 2), (16, 1), (16, 8), (100, 4)] {
+            let offset = rng.next_field();
+            let coeffs = random_elements(&mut rng, len);
+            let evals = evaluate_offset_fft(&coeffs, blowup, offset).unwrap();
+            assert_eq!(evals.len(), len.next_power_of_two() * blowup);
+            let mut recovered = interpolate_offset_fft(&evals, offset).unwrap();
+            assert!(recovered[len..].iter().all(|c| *c == GoldilocksField::ZERO));
+            recovered.truncate(len);
+            assert_eq!(recovered, coeffs, "len = {len}, blowup = {blowup}");
+        }
+    }
+
+    #[test]
+    fn offset_fft_special_cases() {
+        let mut rng = SplitMix64(50);
+        let coeffs = random_elements(&mut rng, 32);
+
+        let mut plain = coeffs.clone();
+        ntt_in_place(&mut plain).unwrap();
+        assert_eq!(evaluate_offset_fft(&coeffs, 1, GoldilocksField::ONE), Ok(plain));
+
+        let lde = low_degree_extension(&coeffs, 4, DEFAULT_COSET_OFFSET).unwrap();
+        assert_eq!(evaluate_offset_fft(&coeffs, 4, DEFAULT_COSET_OFFSET), Ok(lde));
+
+        let constant = [GoldilocksField::new(42)];
+        let evals = evaluate_offset_fft(&constant, 8, DEFAULT_COSET_OFFSET).unwrap();
+        assert_eq!(evals, vec![GoldilocksField::new(42); 8]);
+    }
+
+    #[test]
+    fn interpolate_fft_recovers_a_known_polynomial() {
+        // p(x) = 3 + 2x + x^3 on the subgroup of size 8.
+        let coeffs = [3u64, 2, 0, 1, 0, 0, 0, 0].map(GoldilocksField::new);
+        let w = GoldilocksField::primitive_root_of_unity(3).unwrap();
+        let evals: Vec<_> = (0..8).map(|i| horner(&coeffs, w.pow_u64(i))).collect();
+        assert_eq!(interpolate_fft(&evals), Ok(coeffs.to_vec()));
+    }
+
+    #[test]
+    fn interpolate_fft_then_evaluate_reproduces_the_data() {
+        let mut rng = SplitMix64(51);
+        for log_n in 0..=8 {
+            let data = random_elements(&mut rng, 1 << log_n);
+            let coeffs = interpolate_fft(&data).unwrap();
+            assert_eq!(coeffs.len(), data.len());
+            let w = GoldilocksField::primitive_root_of_unity(log_n).unwrap();
+            for (i, value) in data.iter().enumerate() {
+                assert_eq!(horner(&coeffs, w.pow_u64(i as u64)), *value);
+            }
+        }
+        let constant = [GoldilocksField::new(9)];
+        assert_eq!(interpolate_fft(&constant), Ok(constant.to_vec()));
+        assert_eq!(interpolate_fft(&[GoldilocksField::ONE; 3]), Err(NttError::NotPowerOfTwo(3)));
+    }
+
+    #[test]
+    fn barycentric_evaluate_matches_horner() {
+        let mut rng = SplitMix64(52);
+        for log_n in 0..=10 {
+            let n = 1 << log_n;
+            let coeffs = random_elements(&mut rng, n);
+            let mut evals = coeffs.clone();
+            ntt_in_place(&mut evals).unwrap();
+            let w = GoldilocksField::primitive_root_of_unity(log_n).unwrap();
+            let inside = w.pow_u64(rng.next_u64() % n as u64);
+            for z in [rng.next_field(), GoldilocksField::ZERO, inside] {
+                assert_eq!(barycentric_evaluate(&evals, z), Ok(horner(&coeffs, z)), "n = {n}, z = {z}");
+            }
+        }
+    }
+
+    #[test]
+    fn barycentric_evaluate_on_a_coset() {
+        let mut rng = SplitMix64(53);
+        for log_n in [0, 1, 5, 9] {
+            let coeffs = random_elements(&mut rng, 1 << log_n);
+            let mut evals = coeffs.clone();
+            coset_ntt(&mut evals, DEFAULT_COSET_OFFSET).unwrap();
+            let inside = DEFAULT_COSET_OFFSET * GoldilocksField::primitive_root_of_unity(log_n).unwrap();
+            for z in [rng.next_field(), GoldilocksField::ONE, DEFAULT_COSET_OFFSET, inside] {
+                let value = barycentric_evaluate_coset(&evals, DEFAULT_COSET_OFFSET, z);
+                assert_eq!(value, Ok(horner(&coeffs, z)), "log_n = {log_n}, z = {z}");
+            }
+        }
+    }
+
+    #[test]
+    fn four_step_ntt_is_bit_identical() {
+        let mut rng = SplitMix64(54);
+        for log_n in (0..=16).chain([19]) {
+            let original = random_elements(&mut rng, 1 << log_n);
+            let mut plain = original.clone();
+            let mut blocked = original.clone();
+            ntt_in_place(&mut plain).unwrap();
+            four_step_ntt(&mut blocked).unwrap();
+            assert_eq!(blocked, plain, "log_n = {log_n}");
+        }
+    }
+
+    #[test]
+    fn ntt_large_above_the_threshold() {
+        let mut rng = SplitMix64(55);
+        for n in [FOUR_STEP_THRESHOLD, 2 * FOUR_STEP_THRESHOLD, 4 * FOUR_STEP_THRESHOLD] {
+            let original = random_elements(&mut rng, n);
+            let mut plain = original.clone();
+            let mut large = original;
+            ntt_in_place(&mut plain).unwrap();
+            ntt_large(&mut large).unwrap();
+            assert!(large == plain, "n = {n}");
+        }
+    }
+
+    /// The looped radix-2 transform, bypassing the small kernels.
+    fn generic_ntt(values: &mut [GoldilocksField]) {
+        forward_butterflies(values, &twiddles(values.len().trailing_zeros(), false));
+        bit_reverse_permute(values);
+    }
+
+    #[test]
+    fn twiddle_cache_matches_the_uncached_tables() {
+        for log_n in 0..=10 {
+            assert_eq!(TwiddleCache::forward(log_n), twiddles(log_n, false), "log_n = {log_n}");
+            assert_eq!(TwiddleCache::inverse(log_n), twiddles(log_n, true), "log_n = {log_n}");
+        }
+    }
+
+    #[test]
+    fn twiddle_cache_reuses_its_tables() {
+        let log_n = 13;
+        let mut rng = SplitMix64(64);
+        let original = random_elements(&mut rng, 1 << log_n);
+        let table = TwiddleCache::forward(log_n).as_ptr();
+
+        let mut first = original.clone();
+        let mut second = original.clone();
+        ntt_in_place(&mut first).unwrap();
+        ntt_in_place(&mut second).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(TwiddleCache::forward(log_n).as_ptr(), table);
+        assert_eq!(TABLE_BUILDS[log_n as usize][0].load(std::sync::atomic::Ordering::Relaxed), 1);
+
+        let mut uncached = original;
+        generic_ntt(&mut uncached);
+        assert_eq!(first, uncached);
+    }
+
+    #[test]
+    fn twiddle_cache_preload_builds_every_size() {
+        TwiddleCache::preload(4);
+        for log_n in 0..=4 {
+            assert!(FORWARD_TWIDDLES[log_n].get().is_some());
+            assert!(INVERSE_TWIDDLES[log_n].get().is_some());
+        }
+    }
+
+    #[test]
+    fn small_kernel_roots_match_the_subgroup_roots() {
+        assert_eq!(W4, GoldilocksField::primitive_root_of_unity(2).unwrap());
+        assert_eq!(W8, GoldilocksField::primitive_root_of_unity(3).unwrap());
+        assert_eq!(W8_CUBED, W8 * W4);
+    }
+
+    #[test]
+    fn ntt2_matches_generic_on_edge_values() {
+        let edges = [0, 1, 2, EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1].map(GoldilocksField::from_canonical);
+        for a in edges {
+            for b in edges {
+                let mut kernel = [a, b];
+                let mut generic = [a, b];
+                ntt2(&mut kernel);
+                generic_ntt(&mut generic);
+                assert_eq!(kernel, generic, "({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn ntt4_and_ntt8_match_generic() {
+        let mut rng = SplitMix64(58);
+        for _ in 0..1000 {
+            let mut kernel: [GoldilocksField; 4] = random_elements(&mut rng, 4).try_into().unwrap();
+            let mut generic = kernel;
+            ntt4(&mut kernel);
+            generic_ntt(&mut generic);
+            assert_eq!(kernel, generic);
+
+            let mut kernel: [GoldilocksField; 8] = random_elements(&mut rng, 8).try_into().unwrap();
+            let mut generic = kernel;
+            ntt8(&mut kernel);
+            generic_ntt(&mut generic);
+            assert_eq!(kernel, generic);
+        }
+    }
+
+    #[test]
+    fn ntt_dispatch_matches_generic() {
+        let mut rng = SplitMix64(59);
+        for log_n in 0..=12 {
+            let original = random_elements(&mut rng, 1 << log_n);
+            let mut dispatched = original.clone();
+            let mut generic = original.clone();
+            ntt_in_place(&mut dispatched).unwrap();
+            generic_ntt(&mut generic);
+            assert_eq!(dispatched, generic, "log_n = {log_n}");
+        }
+    }
+
+    #[test]
+    fn transpose_rectangular_matrices() {
+        let mut values: Vec<GoldilocksField> = (0..6u64).map(GoldilocksField::new).collect();
+        transpose_in_place(&mut values, 2, 3);
+        assert_eq!(values, [0u64, 3, 1, 4, 2, 5].map(GoldilocksField::new));
+    }
+
+    #[test]
+    fn transpose_matches_the_index_definition() {
+        let mut rng = SplitMix64(53);
+        for (rows, cols) in [(1, 1), (1, 7), (7, 1), (5, 5), (33, 33), (16, 32), (64, 32), (3, 10), (17, 40)] {
+            let original = random_elements(&mut rng, rows * cols);
+            let mut values = original.clone();
+            transpose_in_place(&mut values, rows, cols);
+            for row in 0..rows {
+                for col in 0..cols {
+                    assert_eq!(values[col * rows + row], original[row * cols + col], "{rows} x {cols} at ({row}, {col})");
+                }
+            }
+            transpose_in_place(&mut values, cols, rows);
+            assert!(values == original, "{rows} x {cols} round trip");
+        }
+    }
+
+    #[test]
+    fn ntt_batch_matches_per_row_ntts() {
+        let mut rng = SplitMix64(62);
+        let (rows, row_len) = (64, 1024);
+        let original = random_elements(&mut rng, rows * row_len);
+        let mut batched = original.clone();
+        ntt_batch(&mut batched, row_len).unwrap();
+        let mut per_row = original;
+        for row in per_row.chunks_exact_mut(row_len) {
+            ntt_in_place(row).unwrap();
+        }
+        assert!(batched == per_row);
+    }
+
+    #[test]
+    fn ntt_batch_of_columns_through_transpose() {
+        let mut rng = SplitMix64(63);
+        let (rows, cols) = (16, 8);
+        let original = random_elements(&mut rng, rows * cols);
+        let mut values = original.clone();
+        transpose(&mut values, rows, cols).unwrap();
+        ntt_batch(&mut values, rows).unwrap();
+        transpose(&mut values, cols, rows).unwrap();
+        for col in 0..cols {
+            let mut column: Vec<_> = (0..rows).map(|row| original[row * cols + col]).collect();
+            ntt_in_place(&mut column).unwrap();
+            for row in 0..rows {
+                assert_eq!(values[row * cols + col], column[row], "({row}, {col})");
+            }
+        }
+    }
+
+    #[test]
+    fn ntt_batch_rejects_bad_shapes() {
+        let mut values = vec![GoldilocksField::ONE; 24];
+        assert_eq!(ntt_batch(&mut values, 16), Err(NttError::UnevenRows(24, 16)));
+        assert_eq!(ntt_batch(&mut values, 6), Err(NttError::NotPowerOfTwo(6)));
+        assert_eq!(ntt_batch(&mut values, 0), Err(NttError::NotPowerOfTwo(0)));
+        assert_eq!(transpose(&mut values, 5, 5), Err(NttError::UnevenRows(24, 5)));
+        assert_eq!(ntt_batch(&mut [], 8), Ok(()));
+    }
+
+    /// Radix-2 NTT on a single thread, to pin the parallel paths against.
+    #[cfg(feature = "parallel")]
+    fn serial_ntt(values: &mut [GoldilocksField]) {
+        let table = twiddles(values.len().trailing_zeros(), false);
+        let mut half_block = values.len();
+        while half_block > 1 {
+            half_block /= 2;
+            for (block, chunk) in values.chunks_exact_mut(2 * half_block).enumerate() {
+                forward_block(chunk, table[block]);
+            }
+        }
+        bit_reverse_permute(values);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn parallel_ntt_matches_serial() {
+        let mut rng = SplitMix64(56);
+        for log_n in 10..=20 {
+            let original = random_elements(&mut rng, 1 << log_n);
+            let mut serial = original.clone();
+            serial_ntt(&mut serial);
+
+            let mut parallel = original.clone();
+            ntt_in_place(&mut parallel).unwrap();
+            assert!(parallel == serial, "ntt_in_place, log_n = {log_n}");
+
+            let mut blocked = original.clone();
+            four_step_ntt(&mut blocked).unwrap();
+            assert!(blocked == serial, "four_step_ntt, log_n = {log_n}");
+
+            intt_in_place(&mut parallel).unwrap();
+            assert!(parallel == original, "intt_in_place, log_n = {log_n}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn parallel_poly_mul_matches_schoolbook() {
+        let mut rng = SplitMix64(57);
+        let a = random_elements(&mut rng, PARALLEL_THRESHOLD / 2 + 1);
+        let b = random_elements(&mut rng, 300);
+        assert_eq!(poly_mul(&a, &b), schoolbook_mul(&a, &b));
+    }
+
+    fn schoolbook_mul(a: &[GoldilocksField], b: &[GoldilocksField]) -> Vec<GoldilocksField> {
+        let mut product = vec![GoldilocksField::ZERO; (a.len() + b.len()).saturating_sub(1)];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                product[i + j] += *x * *y;
+            }
+        }
+        while product.last() == Some(&GoldilocksField::ZERO) {
+            product.pop();
+        }
+        product
+    }
+
+    #[test]
+    fn poly_mul_matches_schoolbook() {
+        let mut rng = SplitMix64(48);
+        for len_a in (1..=201).step_by(20).chain([2, 3, 64, 65]) {
+            let len_b = 1 + rng.next_u64() as usize % 201;
+            let a = random_elements(&mut rng, len_a);
+            let b = random_elements(&mut rng, len_b);
+            assert_eq!(poly_mul(&a, &b), schoolbook_mul(&a, &b), "{len_a} x {len_b}");
+        }
+    }
+
+    #[test]
+    fn poly_mul_edge_cases() {
+        let x = [GoldilocksField::ZERO, GoldilocksField::ONE];
+        let three = [GoldilocksField::new(3)];
+        assert!(poly_mul(&[], &x).is_empty());
+        assert!(poly_mul(&x, &[]).is_empty());
+        assert!(poly_mul(&[GoldilocksField::ZERO], &x).is_empty());
+        assert_eq!(poly_mul(&three, &three), vec![GoldilocksField::new(9)]);
+        assert_eq!(poly_mul(&x, &x), vec![GoldilocksField::ZERO, GoldilocksField::ZERO, GoldilocksField::ONE]);
+        // Trailing zeros in the inputs do not survive into the product.
+        let padded = [GoldilocksField::ONE, GoldilocksField::ZERO, GoldilocksField::ZERO];
+        assert_eq!(poly_mul(&padded, &three), vec![GoldilocksField::new(3)]);
+    }
+
+    /// Schoolbook product with x^n = -1.
+    fn schoolbook_negacyclic(a: &[GoldilocksField], b: &[GoldilocksField]) -> Vec<GoldilocksField> {
+        let n = a.len();
+        let mut out = vec![GoldilocksField::ZERO; n];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                if i + j < n {
+                    out[i + j] += *x * *y;
+                } else {
+                    out[i + j - n] -= *x * *y;
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn negacyclic_convolve_matches_schoolbook() {
+        let mut rng = SplitMix64(60);
+        for n in [1, 4, 8, 64] {
+            let a = random_elements(&mut rng, n);
+            let b = random_elements(&mut rng, n);
+            assert_eq!(negacyclic_convolve(&a, &b).unwrap(), schoolbook_negacyclic(&a, &b), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn negacyclic_wraps_with_a_sign_flip() {
+        // x^(n - 1) * x = x^n = -1.
+        let mut a = vec![GoldilocksField::ZERO; 8];
+        let mut b = vec![GoldilocksField::ZERO; 8];
+        a[7] = GoldilocksField::ONE;
+        b[1] = GoldilocksField::ONE;
+        let mut expected = vec![GoldilocksField::ZERO; 8];
+        expected[0] = GoldilocksField::NEG_ONE;
+        assert_eq!(negacyclic_convolve(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn negacyclic_ntt_round_trips_and_evaluates_at_odd_powers() {
+        let mut rng = SplitMix64(61);
+        for n in [1, 2, 16, 1024] {
+            let original = random_elements(&mut rng, n);
+            let mut values = original.clone();
+            negacyclic_ntt(&mut values).unwrap();
+            let psi = GoldilocksField::primitive_root_of_unity(n.trailing_zeros() + 1).unwrap();
+            for i in [0, n / 2, n - 1] {
+                assert_eq!(values[i], horner(&original, psi.pow_u64(2 * i as u64 + 1)), "n = {n}, i = {i}");
+            }
+            negacyclic_intt(&mut values).unwrap();
+            assert_eq!(values, original, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn negacyclic_rejects_bad_lengths() {
+        let mut values = vec![GoldilocksField::ONE; 6];
+        assert_eq!(negacyclic_ntt(&mut values), Err(NttError::NotPowerOfTwo(6)));
+        assert_eq!(negacyclic_intt(&mut values), Err(NttError::NotPowerOfTwo(6)));
+        let a = vec![GoldilocksField::ONE; 4];
+        let b = vec![GoldilocksField::ONE; 8];
+        assert_eq!(negacyclic_convolve(&a, &b), Err(NttError::LengthMismatch(4, 8)));
+    }
+
+    #[test]
+    fn non_power_of_two_lengths_error() {
+        for n in [0, 3, 6, 1000] {
+            let mut values = vec![GoldilocksField::ONE; n];
+            assert_eq!(ntt_in_place(&mut values), Err(NttError::NotPowerOfTwo(n)));
+            assert_eq!(intt_in_place(&mut values), Err(NttError::NotPowerOfTwo(n)));
+        }
+    }
+
+    fn random_quadratic(rng: &mut SplitMix64, n: usize) -> Vec<GoldilocksQuadraticExtension> {
+        (0..n)
+            .map(|_| GoldilocksQuadraticExtension::new([rng.next_field(), rng.next_field()]))
+            .collect()
+    }
+
+    #[test]
+    fn quadratic_ntt_round_trips_and_acts_coordinate_wise() {
+        let mut rng = SplitMix64(0x775);
+        let original = random_quadratic(&mut rng, 1 << 10);
+        let mut values = original.clone();
+        ntt_in_place_generic(&mut values).unwrap();
+
+        for coordinate in 0..2 {
+            let mut base: Vec<_> = original.iter().map(|x| x.coefficients()[coordinate]).collect();
+            ntt_in_place(&mut base).unwrap();
+            assert!(values.iter().zip(&base).all(|(x, b)| x.coefficients()[coordinate] == *b));
+        }
+
+        intt_in_place_generic(&mut values).unwrap();
+        assert_eq!(values, original);
+
+        let mut odd = vec![GoldilocksQuadraticExtension::ONE; 6];
+        assert_eq!(ntt_in_place_generic(&mut odd), Err(NttError::NotPowerOfTwo(6)));
+        assert_eq!(intt_in_place_generic(&mut odd), Err(NttError::NotPowerOfTwo(6)));
+    }
+
+    #[test]
+    fn quadratic_pointwise_products_are_cyclic_convolutions() {
+        let n = 64;
+        let mut rng = SplitMix64(0x776);
+        let a = random_quadratic(&mut rng, n);
+        let b = random_quadratic(&mut rng, n);
+
+        let mut expected = vec![GoldilocksQuadraticExtension::ZERO; n];
+        for i in 0..n {
+            for j in 0..n {
+                expected[(i + j) % n] = expected[(i + j) % n] + a[i] * b[j];
+            }
+        }
+
+        let (mut fa, mut fb) = (a, b);
+        ntt_in_place_generic(&mut fa).unwrap();
+        ntt_in_place_generic(&mut fb).unwrap();
+        let mut product: Vec<_> = fa.iter().zip(&fb).map(|(x, y)| *x * *y).collect();
+        intt_in_place_generic(&mut product).unwrap();
+        assert_eq!(product, expected);
+    }
+}
@@ -0,0 +1,153 @@
+//! `num-traits` impls, behind the `num-traits` feature.
+//!
+//! `Num` also asks for `Rem`. Division in a field is exact, so `a % b` is zero
+//! for every nonzero `b`; a zero divisor panics, as it does for `/`.
+
+use lambdaworks_math::field::traits::IsPrimeField;
+use num_traits::{Inv, Num, One, Pow, Zero};
+
+use crate::{GoldilocksField, ParseFieldElementError};
+
+impl Zero for GoldilocksField {
+    fn zero() -> Self {
+        GoldilocksField::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == GoldilocksField::ZERO
+    }
+}
+
+impl One for GoldilocksField {
+    fn one() -> Self {
+        GoldilocksField::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        *self == GoldilocksField::ONE
+    }
+}
+
+impl core::ops::Rem for GoldilocksField {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        assert!(rhs != GoldilocksField::ZERO, "GoldilocksField::rem: divisor is zero");
+        GoldilocksField::ZERO
+    }
+}
+
+impl Num for GoldilocksField {
+    type FromStrRadixErr = ParseFieldElementError;
+
+    /// Parses unsigned digits of radix 10 or 16 of any length, reducing modulo
+    /// p. Unlike `FromStr`, no sign, whitespace or `0x` prefix is accepted,
+    /// matching `u64::from_str_radix`.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFieldElementError> {
+        let is_digit: fn(&u8) -> bool = match radix {
+            10 => u8::is_ascii_digit,
+            16 => u8::is_ascii_hexdigit,
+            _ => return Err(ParseFieldElementError::UnsupportedRadix(radix)),
+        };
+        if s.is_empty() {
+            return Err(ParseFieldElementError::Empty);
+        }
+        if !s.as_bytes().iter().all(is_digit) {
+            return Err(ParseFieldElementError::InvalidDigit);
+        }
+        if radix == 10 {
+            s.parse()
+        } else {
+            <GoldilocksField as IsPrimeField>::from_hex(s)
+                .map(|value| GoldilocksField { value })
+                .map_err(|_| ParseFieldElementError::InvalidDigit)
+        }
+    }
+}
+
+/// The multiplicative inverse. Panics on zero, as `ONE / self` does.
+impl Inv for GoldilocksField {
+    type Output = Self;
+
+    fn inv(self) -> Self {
+        GoldilocksField::ONE / self
+    }
+}
+
+impl Pow<u64> for GoldilocksField {
+    type Output = Self;
+
+    fn pow(self, exponent: u64) -> Self {
+        self.pow_u64(exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+
+    fn parse(s: &str, radix: u32) -> Result<GoldilocksField, ParseFieldElementError> {
+        GoldilocksField::from_str_radix(s, radix)
+    }
+
+    #[test]
+    fn identities_and_inverse_agree_with_the_field_operations() {
+        assert_eq!(<GoldilocksField as Zero>::zero(), GoldilocksField::ZERO);
+        assert_eq!(<GoldilocksField as One>::one(), GoldilocksField::ONE);
+        assert!(Zero::is_zero(&GoldilocksField::new(MODULUS)));
+        assert!(GoldilocksField::new(MODULUS + 1).is_one());
+
+        let mut rng = SplitMix64(0x9a98);
+        for _ in 0..500 {
+            let (x, e) = (rng.next_field(), rng.next_u64());
+            assert_eq!(Pow::pow(x, e), x.pow_u64(e));
+            if x != GoldilocksField::ZERO {
+                assert_eq!(x.inv() * x, GoldilocksField::ONE);
+                assert_eq!(rng.next_field() % x, GoldilocksField::ZERO);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor is zero")]
+    fn rem_by_zero_panics() {
+        let _ = GoldilocksField::ONE % GoldilocksField::ZERO;
+    }
+
+    #[test]
+    fn from_str_radix_reduces_decimal_and_hex() {
+        assert_eq!(parse("0", 10), Ok(GoldilocksField::ZERO));
+        assert_eq!(parse("18446744069414584321", 10), Ok(GoldilocksField::ZERO));
+        assert_eq!(parse("18446744069414584322", 10), Ok(GoldilocksField::ONE));
+        assert_eq!(parse("ffffffff00000002", 16), Ok(GoldilocksField::ONE));
+        assert_eq!(parse("DEADBEEF", 16), Ok(GoldilocksField::new(0xdead_beef)));
+        // 2^64 mod p = 2^32 - 1.
+        assert_eq!(parse("18446744073709551616", 10), Ok(GoldilocksField::new(0xffff_ffff)));
+        assert_eq!(parse("10000000000000000", 16), Ok(GoldilocksField::new(0xffff_ffff)));
+
+        let mut rng = SplitMix64(0x5714);
+        for _ in 0..200 {
+            let x = rng.next_field();
+            let value = x.to_canonical_u64();
+            assert_eq!(parse(&value.to_string(), 10), Ok(x));
+            assert_eq!(parse(&format!("{value:x}"), 16), Ok(x));
+        }
+    }
+
+    #[test]
+    fn from_str_radix_rejects_malformed_input() {
+        assert_eq!(parse("", 10), Err(ParseFieldElementError::Empty));
+        assert_eq!(parse("", 16), Err(ParseFieldElementError::Empty));
+        for s in ["12a", " 12", "+12", "-1", "0x12"] {
+            assert_eq!(parse(s, 10), Err(ParseFieldElementError::InvalidDigit), "{s}");
+        }
+        for s in ["0x12", "12g", "1 2"] {
+            assert_eq!(parse(s, 16), Err(ParseFieldElementError::InvalidDigit), "{s}");
+        }
+        for radix in [2, 8, 36] {
+            assert_eq!(parse("10", radix), Err(ParseFieldElementError::UnsupportedRadix(radix)));
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Conversions to and from plonky2's `GoldilocksField`, behind the
+//! `plonky2-interop` feature.
+//!
+//! Both types represent the same field, so the conversions are exact. plonky2
+//! keeps elements as possibly non-canonical u64s; values cross over as
+//! canonical u64s, so neither side sees the other's representation.
+
+use plonky2_field::goldilocks_field::GoldilocksField as Plonky2Field;
+use plonky2_field::types::{Field, PrimeField64};
+
+use crate::GoldilocksField;
+
+impl From<Plonky2Field> for GoldilocksField {
+    fn from(x: Plonky2Field) -> Self {
+        GoldilocksField::from_canonical(x.to_canonical_u64())
+    }
+}
+
+impl From<GoldilocksField> for Plonky2Field {
+    fn from(x: GoldilocksField) -> Self {
+        Plonky2Field::from_canonical_u64(x.to_canonical_u64())
+    }
+}
+
+/// Converts a slice of plonky2 elements in one pass into a preallocated vector.
+pub fn from_plonky2_slice(xs: &[Plonky2Field]) -> Vec<GoldilocksField> {
+    xs.iter().map(|&x| x.into()).collect()
+}
+
+/// Converts a slice of elements in one pass into a preallocated vector of
+/// plonky2 elements.
+pub fn to_plonky2_vec(xs: &[GoldilocksField]) -> Vec<Plonky2Field> {
+    xs.iter().map(|&x| x.into()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+    use plonky2_field::ops::Square;
+
+    fn samples(rng: &mut SplitMix64, n: usize) -> Vec<GoldilocksField> {
+        let mut xs = vec![GoldilocksField::ZERO, GoldilocksField::ONE, GoldilocksField::new(MODULUS - 1)];
+        xs.extend((0..n).map(|_| rng.next_field()));
+        xs
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let mut rng = SplitMix64(95);
+        let xs = samples(&mut rng, 1000);
+        for &x in &xs {
+            let y = Plonky2Field::from(x);
+            assert_eq!(y.to_canonical_u64(), x.to_canonical_u64());
+            assert_eq!(GoldilocksField::from(y), x);
+        }
+        let ys = to_plonky2_vec(&xs);
+        assert_eq!(ys.len(), xs.len());
+        assert_eq!(from_plonky2_slice(&ys), xs);
+        assert_eq!(from_plonky2_slice(&[]), []);
+    }
+
+    #[test]
+    fn non_canonical_plonky2_values_convert_to_their_residue() {
+        for (raw, expected) in [(MODULUS, 0), (MODULUS + 5, 5), (u64::MAX, u64::MAX - MODULUS)] {
+            let x = GoldilocksField::from(Plonky2Field::from_noncanonical_u64(raw));
+            assert_eq!(x.to_canonical_u64(), expected);
+        }
+    }
+
+    #[test]
+    fn arithmetic_agrees_with_plonky2() {
+        let mut rng = SplitMix64(0x9e2);
+        let xs = samples(&mut rng, 2000);
+        let ys = samples(&mut rng, 2000);
+        for (&a, &b) in xs.iter().zip(ys.iter().rev()) {
+            let (pa, pb) = (Plonky2Field::from(a), Plonky2Field::from(b));
+            assert_eq!(GoldilocksField::from(pa + pb), a + b);
+            assert_eq!(GoldilocksField::from(pa - pb), a - b);
+            assert_eq!(GoldilocksField::from(pa * pb), a * b);
+            assert_eq!(GoldilocksField::from(-pa), -a);
+            assert_eq!(GoldilocksField::from(pa.square()), a.square());
+            assert_eq!(GoldilocksField::from(pa.exp_u64(b.to_canonical_u64())), a.pow_u64(b.to_canonical_u64()));
+            match pa.try_inverse() {
+                Some(inverse) => assert_eq!(GoldilocksField::from(inverse), GoldilocksField::ONE / a),
+                None => assert_eq!(a, GoldilocksField::ZERO),
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! proptest strategies for field and extension elements, behind the
+//! `proptest-support` feature.
+//!
+//! `arb_field_element` mixes uniform elements with the values arithmetic bugs
+//! tend to hide behind: zero, one, small values and values just below p.
+//! Alternatives are listed simplest first and ranges shrink toward their
+//! start, so failing cases shrink toward zero and one.
+
+use proptest::prelude::*;
+
+use crate::{GoldilocksCubicExtension, GoldilocksField, GoldilocksQuadraticExtension, GoldilocksQuinticExtension, MODULUS};
+
+/// Any element, with zero, one, small values and values near p weighted in.
+pub fn arb_field_element() -> impl Strategy<Value = GoldilocksField> + Clone {
+    prop_oneof![
+        1 => Just(GoldilocksField::ZERO),
+        1 => Just(GoldilocksField::ONE),
+        2 => (0..1u64 << 16).prop_map(GoldilocksField::from_canonical),
+        2 => (1..=1u64 << 16).prop_map(|k| GoldilocksField::from_canonical(MODULUS - k)),
+        10 => (0..MODULUS).prop_map(GoldilocksField::from_canonical),
+    ]
+}
+
+/// Like `arb_field_element`, without zero.
+pub fn arb_nonzero() -> impl Strategy<Value = GoldilocksField> + Clone {
+    arb_field_element().prop_filter("nonzero", |x| *x != GoldilocksField::ZERO)
+}
+
+/// Vectors of `arb_field_element` values with a length in `len`.
+pub fn arb_vec(len: impl Into<prop::collection::SizeRange>) -> impl Strategy<Value = Vec<GoldilocksField>> {
+    prop::collection::vec(arb_field_element(), len)
+}
+
+/// Elements of Fp^2 with `arb_field_element` coefficients.
+pub fn arb_quadratic() -> impl Strategy<Value = GoldilocksQuadraticExtension> + Clone {
+    prop::array::uniform2(arb_field_element()).prop_map(GoldilocksQuadraticExtension::new)
+}
+
+/// Elements of Fp^3 with `arb_field_element` coefficients.
+pub fn arb_cubic() -> impl Strategy<Value = GoldilocksCubicExtension> + Clone {
+    prop::array::uniform3(arb_field_element()).prop_map(GoldilocksCubicExtension::new)
+}
+
+/// Elements of Fp^5 with `arb_field_element` coefficients.
+pub fn arb_quintic() -> impl Strategy<Value = GoldilocksQuinticExtension> + Clone {
+    prop::array::uniform5(arb_field_element()).prop_map(GoldilocksQuinticExtension::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inner_product;
+
+    proptest! {
+        #[test]
+        fn addition_and_multiplication_are_associative(
+            a in arb_field_element(),
+            b in arb_field_element(),
+            c in arb_field_element(),
+        ) {
+            prop_assert_eq!((a + b) + c, a + (b + c));
+            prop_assert_eq!((a * b) * c, a * (b * c));
+        }
+
+        #[test]
+        fn multiplication_distributes_over_addition(
+            a in arb_field_element(),
+            b in arb_field_element(),
+            c in arb_field_element(),
+        ) {
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+            prop_assert_eq!((a - b) * c, a * c - b * c);
+        }
+
+        #[test]
+        fn identities_and_inverses(a in arb_field_element(), b in arb_nonzero()) {
+            prop_assert_eq!(a + GoldilocksField::ZERO, a);
+            prop_assert_eq!(a * GoldilocksField::ONE, a);
+            prop_assert_eq!(a - a, GoldilocksField::ZERO);
+            prop_assert_eq!(a + -a, GoldilocksField::ZERO);
+            prop_assert_eq!(b * (GoldilocksField::ONE / b), GoldilocksField::ONE);
+            prop_assert_eq!(a / b * b, a);
+            prop_assert!(a.to_canonical_u64() < MODULUS);
+        }
+
+        #[test]
+        fn inner_product_matches_the_fold(pair in arb_vec(0..64).prop_flat_map(|a| {
+            let len = a.len();
+            (Just(a), arb_vec(len))
+        })) {
+            let (a, b) = pair;
+            let fold = a.iter().zip(&b).fold(GoldilocksField::ZERO, |acc, (x, y)| acc + *x * *y);
+            prop_assert_eq!(inner_product(&a, &b), fold);
+        }
+
+        #[test]
+        fn quadratic_field_laws(a in arb_quadratic(), b in arb_quadratic(), c in arb_quadratic()) {
+            prop_assert_eq!((a * b) * c, a * (b * c));
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+            if a != GoldilocksQuadraticExtension::ZERO {
+                prop_assert_eq!(a * a.inv().unwrap(), GoldilocksQuadraticExtension::ONE);
+            }
+        }
+
+        #[test]
+        fn cubic_field_laws(a in arb_cubic(), b in arb_cubic(), c in arb_cubic()) {
+            prop_assert_eq!((a * b) * c, a * (b * c));
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+            if a != GoldilocksCubicExtension::ZERO {
+                prop_assert_eq!(a * a.inv().unwrap(), GoldilocksCubicExtension::ONE);
+            }
+        }
+
+        #[test]
+        fn quintic_field_laws(a in arb_quintic(), b in arb_quintic(), c in arb_quintic()) {
+            prop_assert_eq!((a * b) * c, a * (b * c));
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+            if a != GoldilocksQuinticExtension::ZERO {
+                prop_assert_eq!(a * a.inv().unwrap(), GoldilocksQuinticExtension::ONE);
+            }
+        }
+
+        #[test]
+        fn arb_vec_respects_the_length(v in arb_vec(3..=7), w in arb_vec(5)) {
+            prop_assert!((3..=7).contains(&v.len()));
+            prop_assert_eq!(w.len(), 5);
+        }
+    }
+}
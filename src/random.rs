@@ -0,0 +1,298 @@
+//! Sampling field elements with `rand`, behind the `rand` feature.
+//!
+//! `GoldilocksField::sample` and `random_vec` only need an `RngCore`;
+//! `rng.gen::<GoldilocksField>()` draws from the same distribution and
+//! `rng.gen_range(a..b)` uniformly from a range of canonical values.
+
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard, Uniform};
+use rand::{Rng, RngCore};
+
+use crate::{GoldilocksCubicExtension, GoldilocksField, GoldilocksQuadraticExtension, GoldilocksQuinticExtension, MODULUS};
+
+impl GoldilocksField {
+    /// Draws a uniform element by rejection sampling: a u64 draw of p or more
+    /// is discarded and drawn again, so there is no modulo bias. A draw is
+    /// rejected with probability (2^32 - 1) / 2^64, about 2^-32.
+    pub fn sample<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let x = rng.next_u64();
+            if x < MODULUS {
+                return GoldilocksField::from_canonical(x);
+            }
+        }
+    }
+
+    /// Draws a uniform nonzero element, drawing again on zero.
+    pub fn sample_nonzero<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let x = Self::sample(rng);
+            if x != Self::ZERO {
+                return x;
+            }
+        }
+    }
+
+    /// Draws a uniform invertible element. In a field this is
+    /// `sample_nonzero`; the name is kept for callers that blind with units.
+    pub fn sample_invertible<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self::sample_nonzero(rng)
+    }
+
+    /// Draws n uniform elements, filling a u64 buffer with one call to the
+    /// generator and keeping the draws below p in place; the rare rejected
+    /// draws are made up by refilling the tail.
+    ///
+    /// For generators whose byte stream is their `next_u64` output in
+    /// little-endian order, as with `StdRng`, this returns the same elements
+    /// as n calls to `sample`.
+    pub fn random_vec<R: RngCore + ?Sized>(n: usize, rng: &mut R) -> Vec<Self> {
+        let mut words = vec![0u64; n];
+        let mut filled = 0;
+        while filled < n {
+            rng.fill(&mut words[filled..]);
+            let mut kept = filled;
+            for i in filled..n {
+                if words[i] < MODULUS {
+                    words[kept] = words[i];
+                    kept += 1;
+                }
+            }
+            filled = kept;
+        }
+        words.into_iter().map(GoldilocksField::from_canonical).collect()
+    }
+}
+
+macro_rules! impl_extension_sampling {
+    ($ty:ident) => {
+        impl $ty {
+            /// Draws a uniform element, sampling each coefficient with
+            /// `GoldilocksField::sample` from the lowest degree up.
+            pub fn sample<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+                Self::new(core::array::from_fn(|_| GoldilocksField::sample(rng)))
+            }
+
+            /// Draws a uniform nonzero element, drawing again on zero.
+            pub fn sample_nonzero<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+                loop {
+                    let x = Self::sample(rng);
+                    if x != Self::ZERO {
+                        return x;
+                    }
+                }
+            }
+
+            /// Draws a uniform invertible element, which in a field is
+            /// `sample_nonzero`.
+            pub fn sample_invertible<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_nonzero(rng)
+            }
+        }
+    };
+}
+
+impl_extension_sampling!(GoldilocksQuadraticExtension);
+impl_extension_sampling!(GoldilocksCubicExtension);
+impl_extension_sampling!(GoldilocksQuinticExtension);
+
+/// The distribution of `GoldilocksField::sample`.
+impl Distribution<GoldilocksField> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GoldilocksField {
+        GoldilocksField::sample(rng)
+    }
+}
+
+/// The `UniformSampler` behind `gen_range` and `Uniform<GoldilocksField>`,
+/// uniform over an interval of canonical values.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformGoldilocks(Uniform<u64>);
+
+impl UniformSampler for UniformGoldilocks {
+    type X = GoldilocksField;
+
+    /// Panics if low >= high.
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<GoldilocksField> + Sized,
+        B2: SampleBorrow<GoldilocksField> + Sized,
+    {
+        UniformGoldilocks(Uniform::new(low.borrow().to_canonical_u64(), high.borrow().to_canonical_u64()))
+    }
+
+    /// Panics if low > high.
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<GoldilocksField> + Sized,
+        B2: SampleBorrow<GoldilocksField> + Sized,
+    {
+        UniformGoldilocks(Uniform::new_inclusive(low.borrow().to_canonical_u64(), high.borrow().to_canonical_u64()))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GoldilocksField {
+        GoldilocksField::from_canonical(self.0.sample(rng))
+    }
+}
+
+impl SampleUniform for GoldilocksField {
+    type Sampler = UniformGoldilocks;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    #[test]
+    fn seeded_sampling_is_deterministic_and_canonical() {
+        let draw = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..1000).map(|_| rng.gen::<GoldilocksField>()).collect::<Vec<_>>()
+        };
+        let samples = draw(79);
+        assert_eq!(samples, draw(79));
+        assert_ne!(samples, draw(80));
+        assert!(samples.iter().all(|x| x.to_canonical_u64() < MODULUS));
+    }
+
+    /// Yields each value of a fixed list in turn.
+    struct Scripted(std::vec::IntoIter<u64>);
+
+    impl RngCore for Scripted {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0.next().expect("script exhausted")
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn draws_of_p_or_more_are_redrawn() {
+        // Reducing these modulo p would return 0, 1 and 2^32 - 2.
+        let mut rng = Scripted(vec![MODULUS, MODULUS + 1, u64::MAX, 5, MODULUS - 1].into_iter());
+        assert_eq!(rng.gen::<GoldilocksField>(), GoldilocksField::new(5));
+        assert_eq!(rng.gen::<GoldilocksField>(), GoldilocksField::NEG_ONE);
+    }
+
+    #[test]
+    fn random_vec_is_canonical_and_matches_single_samples() {
+        let n = 1 << 20;
+        let bulk = GoldilocksField::random_vec(n, &mut StdRng::seed_from_u64(0x80));
+        assert_eq!(bulk.len(), n);
+        assert!(bulk.iter().all(|x| x.to_canonical_u64() < MODULUS));
+        assert_eq!(bulk, GoldilocksField::random_vec(n, &mut StdRng::seed_from_u64(0x80)));
+
+        let mut rng = StdRng::seed_from_u64(0x80);
+        let singles: Vec<_> = (0..1000).map(|_| GoldilocksField::sample(&mut rng)).collect();
+        assert_eq!(bulk[..1000], singles[..]);
+        assert!(GoldilocksField::random_vec(0, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn random_vec_refills_rejected_draws_in_order() {
+        let script = vec![1, u64::MAX, 2, MODULUS, 3, MODULUS + 7, 4, 5];
+        let bulk = GoldilocksField::random_vec(5, &mut Scripted(script.clone().into_iter()));
+        let mut rng = Scripted(script.into_iter());
+        let singles: Vec<_> = (0..5).map(|_| GoldilocksField::sample(&mut rng)).collect();
+        assert_eq!(bulk, singles);
+        assert_eq!(bulk, [1, 2, 3, 4, 5].map(GoldilocksField::new));
+    }
+
+    #[test]
+    fn nonzero_sampling_redraws_zero() {
+        let mut rng = Scripted(vec![0, MODULUS, 0, 9].into_iter());
+        assert_eq!(GoldilocksField::sample_nonzero(&mut rng), GoldilocksField::new(9));
+        let mut rng = Scripted(vec![0, 0, 0, 4].into_iter());
+        assert_eq!(GoldilocksField::sample_invertible(&mut rng), GoldilocksField::new(4));
+        let mut rng = Scripted(vec![0, 0, 0, 1].into_iter());
+        assert_eq!(
+            GoldilocksQuadraticExtension::sample_nonzero(&mut rng),
+            GoldilocksQuadraticExtension::new([GoldilocksField::ZERO, GoldilocksField::ONE])
+        );
+    }
+
+    #[test]
+    fn nonzero_sampling_is_deterministic_and_never_zero() {
+        let draw = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let base: Vec<_> = (0..10_000)
+                .flat_map(|_| [GoldilocksField::sample_nonzero(&mut rng), GoldilocksField::sample_invertible(&mut rng)])
+                .collect();
+            let quadratic: Vec<_> = (0..10_000).map(|_| GoldilocksQuadraticExtension::sample_invertible(&mut rng)).collect();
+            let cubic: Vec<_> = (0..10_000).map(|_| GoldilocksCubicExtension::sample_nonzero(&mut rng)).collect();
+            let quintic: Vec<_> = (0..10_000).map(|_| GoldilocksQuinticExtension::sample_invertible(&mut rng)).collect();
+            (base, quadratic, cubic, quintic)
+        };
+        let (base, quadratic, cubic, quintic) = draw(0x81);
+        assert!(!base.contains(&GoldilocksField::ZERO));
+        assert!(!quadratic.contains(&GoldilocksQuadraticExtension::ZERO));
+        assert!(!cubic.contains(&GoldilocksCubicExtension::ZERO));
+        assert!(!quintic.contains(&GoldilocksQuinticExtension::ZERO));
+        assert!(quintic.iter().all(|x| x.inv().is_ok()));
+        assert!(draw(0x81) == (base, quadratic, cubic, quintic));
+    }
+
+    #[test]
+    fn top_bits_are_evenly_spread() {
+        // Chi-square over the top 4 bits: with 15 degrees of freedom the
+        // statistic exceeds 37.7 with probability 0.001. Reducing raw draws
+        // modulo p would instead pile 2^-32 of the mass onto the low values,
+        // which the redraw test above checks directly.
+        let mut rng = StdRng::seed_from_u64(0x79);
+        let samples = 1 << 16;
+        let mut buckets = [0u64; 16];
+        for _ in 0..samples {
+            buckets[(rng.gen::<GoldilocksField>().to_canonical_u64() >> 60) as usize] += 1;
+        }
+        let expected = samples as f64 / 16.0;
+        let chi_square: f64 = buckets.iter().map(|&n| (n as f64 - expected).powi(2) / expected).sum();
+        assert!(chi_square < 37.7, "chi-square {chi_square}, buckets {buckets:?}");
+    }
+
+    #[test]
+    fn gen_range_samples_canonical_intervals() {
+        let mut rng = StdRng::seed_from_u64(0x7a);
+        let (low, high) = (GoldilocksField::new(10), GoldilocksField::new(20));
+        let mut seen = [false; 11];
+        for _ in 0..1000 {
+            let x = rng.gen_range(low..high);
+            assert!(low <= x && x < high);
+            seen[(x.to_canonical_u64() - 10) as usize] = true;
+            let y = rng.gen_range(low..=high);
+            assert!(low <= y && y <= high);
+            seen[(y.to_canonical_u64() - 10) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let top = GoldilocksField::NEG_ONE;
+        assert_eq!(rng.gen_range(top..=top), top);
+        let x = rng.gen_range(GoldilocksField::ZERO..=top);
+        assert!(x.to_canonical_u64() < MODULUS);
+
+        let distribution = Uniform::new(low, high);
+        assert!((0..100).map(|_| distribution.sample(&mut rng)).all(|x| low <= x && x < high));
+    }
+
+    #[test]
+    #[should_panic]
+    fn gen_range_rejects_empty_ranges() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let x = GoldilocksField::new(3);
+        rng.gen_range(x..x);
+    }
+}
@@ -0,0 +1,125 @@
+//! Strategies for reducing 128-bit products modulo p.
+//!
+//! The field uses [`EpsilonReducer`] by default. Enabling the `barrett` feature
+//! switches base-field multiplication and `inner_product` to
+//! [`BarrettReducer`]; both reducers are always available, so they can also be
+//! benchmarked side by side.
+
+use crate::{EPSILON, MODULUS};
+
+/// Reduction of a 128-bit value to its canonical residue modulo p.
+pub trait Reducer {
+    fn reduce_u128(x: u128) -> u64;
+
+    /// a * b mod p for canonical a and b.
+    fn mul(a: u64, b: u64) -> u64 {
+        Self::reduce_u128(a as u128 * b as u128)
+    }
+}
+
+/// Reduction using 2^64 = 2^32 - 1 and 2^96 = -1 (mod p); see [`crate::reduce_u128`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EpsilonReducer;
+
+impl Reducer for EpsilonReducer {
+    fn reduce_u128(x: u128) -> u64 {
+        crate::reduce_u128(x)
+    }
+}
+
+/// Barrett reduction with the precomputed mu = floor(2^128 / p).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BarrettReducer;
+
+impl BarrettReducer {
+    /// floor(2^128 / p) = 2^64 + MU_LO.
+    pub const MU_LO: u64 = EPSILON;
+}
+
+impl Reducer for BarrettReducer {
+    fn reduce_u128(x: u128) -> u64 {
+        let x0 = x as u64;
+        let x1 = (x >> 64) as u64;
+
+        // q = floor(x * mu / 2^128) with mu = 2^64 + MU_LO. The low 64 bits of
+        // x0 * MU_LO cannot carry into bit 128, so they are dropped.
+        let t_hi = (x0 as u128 * Self::MU_LO as u128) >> 64;
+        let s = x0 as u128 + x1 as u128 * Self::MU_LO as u128 + t_hi;
+        let q = x1 as u128 + (s >> 64);
+
+        // q underestimates floor(x / p) by at most 2, so r < 3p.
+        let mut r = x - q * MODULUS as u128;
+        if r >= MODULUS as u128 {
+            r -= MODULUS as u128;
+        }
+        if r >= MODULUS as u128 {
+            r -= MODULUS as u128;
+        }
+        r as u64
+    }
+}
+
+/// The reducer used by the field arithmetic.
+#[cfg(not(feature = "barrett"))]
+pub(crate) type ActiveReducer = EpsilonReducer;
+#[cfg(feature = "barrett")]
+pub(crate) type ActiveReducer = BarrettReducer;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(x: u128) -> u64 {
+        (x % MODULUS as u128) as u64
+    }
+
+    #[test]
+    fn barrett_mu_is_floor_of_2_128_over_p() {
+        // mu * p fits in a u128 and 2^128 - mu * p is 2^128 mod p = p - 2^32.
+        let mu_p = ((MODULUS as u128) << 64) + BarrettReducer::MU_LO as u128 * MODULUS as u128;
+        let remainder = 0u128.wrapping_sub(mu_p);
+        assert_eq!(remainder, (MODULUS - (1 << 32)) as u128);
+    }
+
+    #[test]
+    fn reducers_agree_on_edge_values() {
+        let p = MODULUS as u128;
+        let edges = [
+            0,
+            1,
+            p - 1,
+            p,
+            p + 1,
+            (p - 1) * (p - 1),
+            p * p - 1,
+            1 << 64,
+            (1 << 96) - 1,
+            1 << 96,
+            u64::MAX as u128 * u64::MAX as u128,
+            u128::MAX - p,
+            u128::MAX,
+        ];
+        for x in edges {
+            assert_eq!(BarrettReducer::reduce_u128(x), reference(x), "{x}");
+            assert_eq!(EpsilonReducer::reduce_u128(x), reference(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn reducers_agree_on_random_inputs() {
+        let mut state: u64 = 0x5eed;
+        let mut next = || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        for _ in 0..1 << 21 {
+            let x = (next() as u128) << 64 | next() as u128;
+            assert_eq!(BarrettReducer::reduce_u128(x), EpsilonReducer::reduce_u128(x), "{x}");
+            let (a, b) = (next() % MODULUS, next() % MODULUS);
+            assert_eq!(BarrettReducer::mul(a, b), EpsilonReducer::mul(a, b), "{a} * {b}");
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! `rkyv` support, behind the `rkyv` feature.
+//!
+//! An element archives as its canonical value in a little-endian u64, so an
+//! archived `Vec<GoldilocksField>` is a plain u64 array that can be read in
+//! place, e.g. from a memory-mapped trace file. Validation through
+//! `rkyv::access` rejects values ≥ p, so a validated archive only ever holds
+//! canonical elements.
+
+use core::fmt;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::rend::u64_le;
+use rkyv::traits::NoUndef;
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::{GoldilocksField, MODULUS};
+
+/// The archived form of a `GoldilocksField`: its canonical value as a
+/// little-endian u64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArchivedGoldilocksField(u64_le);
+
+// SAFETY: a transparent wrapper around `u64_le`, which is portable and has no
+// padding or invalid bit patterns.
+unsafe impl Portable for ArchivedGoldilocksField {}
+unsafe impl NoUndef for ArchivedGoldilocksField {}
+
+impl ArchivedGoldilocksField {
+    /// Returns the element. Values in an archive obtained without validation
+    /// may be ≥ p and are reduced.
+    pub fn to_native(&self) -> GoldilocksField {
+        GoldilocksField::new(self.0.to_native())
+    }
+}
+
+impl Archive for GoldilocksField {
+    type Archived = ArchivedGoldilocksField;
+    type Resolver = ();
+
+    fn resolve(&self, _resolver: (), out: Place<Self::Archived>) {
+        out.write(ArchivedGoldilocksField(u64_le::from_native(self.to_canonical_u64())));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for GoldilocksField {
+    fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<GoldilocksField, D> for ArchivedGoldilocksField {
+    fn deserialize(&self, _deserializer: &mut D) -> Result<GoldilocksField, D::Error> {
+        Ok(self.to_native())
+    }
+}
+
+#[derive(Debug)]
+struct NonCanonicalError(u64);
+
+impl fmt::Display for NonCanonicalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "non-canonical Goldilocks element {:#x}: must be below {MODULUS:#x}", self.0)
+    }
+}
+
+impl std::error::Error for NonCanonicalError {}
+
+// SAFETY: `check_bytes` returns Ok only for canonical values, which are exactly
+// the values `resolve` writes.
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ArchivedGoldilocksField
+where
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _context: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees `value` is aligned and points to
+        // readable bytes, and every bit pattern is a valid u64_le.
+        let value = unsafe { (*value).0.to_native() };
+        if value >= MODULUS {
+            return Err(C::Error::new(NonCanonicalError(value)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use rkyv::rancor::Error;
+    use rkyv::Archived;
+
+    fn column() -> Vec<GoldilocksField> {
+        let mut rng = SplitMix64(0xa4c1);
+        let mut elements: Vec<_> = [0, 1, MODULUS - 1].map(GoldilocksField::new).to_vec();
+        elements.extend((0..1000).map(|_| rng.next_field()));
+        elements
+    }
+
+    /// Byte offset of the archived element array within `bytes`.
+    fn elements_offset(bytes: &[u8]) -> usize {
+        let archived = rkyv::access::<Archived<Vec<GoldilocksField>>, Error>(bytes).unwrap();
+        archived.as_ptr() as usize - bytes.as_ptr() as usize
+    }
+
+    #[test]
+    fn archived_column_is_read_in_place() {
+        let elements = column();
+        let bytes = rkyv::to_bytes::<Error>(&elements).unwrap();
+        let archived = rkyv::access::<Archived<Vec<GoldilocksField>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), elements.len());
+        for (a, x) in archived.iter().zip(&elements) {
+            assert_eq!(a.to_native(), *x);
+        }
+
+        // The archived elements are the canonical values as little-endian
+        // u64s, inside the buffer itself.
+        let offset = elements_offset(&bytes);
+        let raw: Vec<u8> = elements.iter().flat_map(|x| x.to_canonical_u64().to_le_bytes()).collect();
+        assert_eq!(bytes[offset..offset + raw.len()], raw[..]);
+    }
+
+    #[test]
+    fn round_trips_to_owned_values() {
+        let elements = column();
+        let bytes = rkyv::to_bytes::<Error>(&elements).unwrap();
+        let archived = rkyv::access::<Archived<Vec<GoldilocksField>>, Error>(&bytes).unwrap();
+        let owned: Vec<GoldilocksField> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(owned, elements);
+    }
+
+    #[test]
+    fn validation_rejects_non_canonical_values() {
+        let elements = column();
+        let mut bytes = rkyv::to_bytes::<Error>(&elements).unwrap();
+        let offset = elements_offset(&bytes);
+        for value in [MODULUS, u64::MAX] {
+            let slot = offset + 8 * 517;
+            bytes[slot..slot + 8].copy_from_slice(&value.to_le_bytes());
+            let err = rkyv::access::<Archived<Vec<GoldilocksField>>, Error>(&bytes).unwrap_err();
+            assert!(err.to_string().contains("non-canonical"), "{err}");
+        }
+        let slot = offset + 8 * 517;
+        bytes[slot..slot + 8].copy_from_slice(&(MODULUS - 1).to_le_bytes());
+        assert!(rkyv::access::<Archived<Vec<GoldilocksField>>, Error>(&bytes).is_ok());
+    }
+}
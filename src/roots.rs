@@ -0,0 +1,111 @@
+//! Subgroups of roots of unity, for NTT twiddles and evaluation domains.
+
+use lambdaworks_math::field::errors::FieldError;
+
+use crate::ntt::bit_reverse_permute;
+use crate::GoldilocksField;
+
+/// Yields 1, w, w^2, ..., w^(n - 1) for a primitive n-th root of unity w,
+/// n = 2^log_order, without allocating.
+#[derive(Debug, Clone)]
+pub struct RootsIterator {
+    current: GoldilocksField,
+    step: GoldilocksField,
+    remaining: u64,
+}
+
+impl RootsIterator {
+    /// Fails with `RootOfUnityError` for log_order > 32.
+    pub fn new(log_order: u32) -> Result<Self, FieldError> {
+        Ok(RootsIterator {
+            current: GoldilocksField::ONE,
+            step: GoldilocksField::primitive_root_of_unity(log_order)?,
+            remaining: 1 << log_order,
+        })
+    }
+}
+
+impl Iterator for RootsIterator {
+    type Item = GoldilocksField;
+
+    fn next(&mut self) -> Option<GoldilocksField> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let root = self.current;
+        self.current *= self.step;
+        self.remaining -= 1;
+        Some(root)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// Returns [1, w, w^2, ..., w^(n - 1)] for the order-2^log_order subgroup.
+pub fn roots_of_unity(log_order: u32) -> Result<Vec<GoldilocksField>, FieldError> {
+    Ok(RootsIterator::new(log_order)?.collect())
+}
+
+/// Like `roots_of_unity`, but entry i holds w^bitreverse(i), the order a
+/// decimation-in-time NTT consumes twiddles in.
+pub fn roots_of_unity_bit_reversed(log_order: u32) -> Result<Vec<GoldilocksField>, FieldError> {
+    let mut roots = roots_of_unity(log_order)?;
+    bit_reverse_permute(&mut roots);
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn roots_have_the_subgroup_order() {
+        for log_order in 1..=16 {
+            let roots = roots_of_unity(log_order).unwrap();
+            let n = roots.len();
+            assert_eq!(n, 1 << log_order);
+            let w = roots[1];
+            assert_eq!(w * roots[n - 1], GoldilocksField::ONE);
+            assert_eq!(roots[n / 2], GoldilocksField::NEG_ONE);
+            let distinct: HashSet<_> = roots.iter().collect();
+            assert_eq!(distinct.len(), n);
+        }
+        assert_eq!(roots_of_unity(0).unwrap(), vec![GoldilocksField::ONE]);
+    }
+
+    #[test]
+    fn bit_reversed_roots_are_a_permutation() {
+        let natural = roots_of_unity(10).unwrap();
+        let reversed = roots_of_unity_bit_reversed(10).unwrap();
+        assert_eq!(reversed[1], natural[512]);
+        assert_eq!(reversed[2], natural[256]);
+        let mut a: Vec<u64> = natural.iter().map(|x| x.to_canonical_u64()).collect();
+        let mut b: Vec<u64> = reversed.iter().map(|x| x.to_canonical_u64()).collect();
+        a.sort_unstable();
+        b.sort_unstable();
+        assert_eq!(a, b);
+        assert_eq!(roots_of_unity_bit_reversed(0).unwrap(), vec![GoldilocksField::ONE]);
+    }
+
+    #[test]
+    fn iterator_is_lazy_and_exact() {
+        let iter = RootsIterator::new(32).unwrap();
+        assert_eq!(iter.size_hint().1, usize::try_from(1u64 << 32).ok());
+        let first: Vec<_> = iter.take(3).collect();
+        let w = GoldilocksField::primitive_root_of_unity(32).unwrap();
+        assert_eq!(first, vec![GoldilocksField::ONE, w, w * w]);
+        assert_eq!(RootsIterator::new(4).unwrap().count(), 16);
+    }
+
+    #[test]
+    fn log_order_above_32_errors() {
+        assert!(matches!(roots_of_unity(33), Err(FieldError::RootOfUnityError(33))));
+        assert!(RootsIterator::new(40).is_err());
+    }
+}
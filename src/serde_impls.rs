@@ -0,0 +1,178 @@
+//! `serde` support, behind the `serde` feature.
+//!
+//! Binary formats get the canonical value as a u64, which bincode writes as 8
+//! little-endian bytes, and reject values ≥ p on input. Human-readable formats
+//! get the `0x`-prefixed lowercase hex string produced by `to_hex`: elements
+//! exceed 2^53, so a JSON number would be silently rounded by JavaScript.
+//!
+//! On the human-readable side, strings may be `0x`-prefixed hex or decimal and
+//! must be canonical. Bare integers are accepted for compatibility with older
+//! dumps and are reduced mod p.
+
+use core::fmt;
+
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{GoldilocksField, MODULUS};
+
+impl Serialize for GoldilocksField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            serializer.serialize_u64(self.to_canonical_u64())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GoldilocksField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(TextVisitor)
+        } else {
+            deserializer.deserialize_u64(CanonicalVisitor)
+        }
+    }
+}
+
+struct CanonicalVisitor;
+
+impl Visitor<'_> for CanonicalVisitor {
+    type Value = GoldilocksField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a canonical Goldilocks element (an integer below {MODULUS:#x})")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        if value >= MODULUS {
+            return Err(E::invalid_value(Unexpected::Unsigned(value), &self));
+        }
+        Ok(GoldilocksField::from_canonical(value))
+    }
+}
+
+struct TextVisitor;
+
+impl Visitor<'_> for TextVisitor {
+    type Value = GoldilocksField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a decimal or 0x-prefixed hex string below {MODULUS:#x}, or an unsigned integer")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(GoldilocksField::new(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let (digits, radix) = match value.strip_prefix("0x") {
+            Some(hex) => (hex, 16),
+            None => (value, 10),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(E::invalid_value(Unexpected::Str(value), &self));
+        }
+        // Any number of leading zeros is fine, but the value itself must fit.
+        let canonical = u64::from_str_radix(digits, radix)
+            .ok()
+            .filter(|&v| v < MODULUS)
+            .ok_or_else(|| E::custom(format_args!("non-canonical Goldilocks element {value}: must be below {MODULUS:#x}")))?;
+        Ok(GoldilocksField::from_canonical(canonical))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+
+    fn samples() -> Vec<GoldilocksField> {
+        let mut rng = SplitMix64(0x5e7de);
+        let mut elements: Vec<_> = [0, 1, 2, u32::MAX as u64, MODULUS - 1].map(GoldilocksField::new).to_vec();
+        elements.extend((0..100).map(|_| rng.next_field()));
+        elements
+    }
+
+    #[test]
+    fn bincode_round_trip_is_eight_le_bytes() {
+        for x in samples() {
+            let bytes = bincode::serialize(&x).unwrap();
+            assert_eq!(bytes, x.to_canonical_u64().to_le_bytes());
+            assert_eq!(bincode::deserialize::<GoldilocksField>(&bytes).unwrap(), x);
+        }
+        let elements = samples();
+        let bytes = bincode::serialize(&elements).unwrap();
+        assert_eq!(bincode::deserialize::<Vec<GoldilocksField>>(&bytes).unwrap(), elements);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for x in samples() {
+            let json = serde_json::to_string(&x).unwrap();
+            assert_eq!(json, format!("\"{}\"", x.to_hex()));
+            assert_eq!(serde_json::from_str::<GoldilocksField>(&json).unwrap(), x);
+        }
+        let elements = samples();
+        let json = serde_json::to_string(&elements).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<GoldilocksField>>(&json).unwrap(), elements);
+    }
+
+    #[test]
+    fn json_form_of_known_elements_is_stable() {
+        assert_eq!(serde_json::to_string(&GoldilocksField::new(0)).unwrap(), r#""0x0""#);
+        assert_eq!(serde_json::to_string(&GoldilocksField::new(0xdead_beef)).unwrap(), r#""0xdeadbeef""#);
+        assert_eq!(serde_json::to_string(&GoldilocksField::new(MODULUS - 1)).unwrap(), r#""0xffffffff00000000""#);
+        assert_eq!(serde_json::from_str::<GoldilocksField>(r#""0x000ff""#).unwrap(), GoldilocksField::new(255));
+    }
+
+    #[test]
+    fn bincode_rejects_non_canonical_values() {
+        for v in [MODULUS, MODULUS + 1, u64::MAX] {
+            let err = bincode::deserialize::<GoldilocksField>(&v.to_le_bytes()).unwrap_err();
+            assert!(err.to_string().contains("canonical Goldilocks element"), "{err}");
+        }
+        assert!(bincode::deserialize::<GoldilocksField>(&[0; 7]).is_err());
+    }
+
+    #[test]
+    fn json_rejects_malformed_and_non_canonical_strings() {
+        for json in [r#""""#, r#""0x""#, r#""ff""#, r#""0xfg""#, r#""0x+1""#, r#""+1""#, r#""0x 1""#, r#""0XFF""#, "-1", "null"] {
+            let err = serde_json::from_str::<GoldilocksField>(json).unwrap_err();
+            assert!(err.to_string().contains("0x-prefixed hex string"), "{json}: {err}");
+        }
+        for json in [
+            r#""0xffffffff00000001""#,
+            r#""0xffffffffffffffff""#,
+            r#""0x10000000000000000""#,
+            r#""18446744069414584321""#,
+            r#""18446744073709551616""#,
+        ] {
+            let err = serde_json::from_str::<GoldilocksField>(json).unwrap_err();
+            assert!(err.to_string().contains("non-canonical"), "{json}: {err}");
+        }
+    }
+
+    #[test]
+    fn json_accepts_decimal_hex_and_numbers() {
+        let expected = GoldilocksField::new(MODULUS - 1);
+        for json in [r#""18446744069414584320""#, "18446744069414584320", r#""0xffffffff00000000""#] {
+            assert_eq!(serde_json::from_str::<GoldilocksField>(json).unwrap(), expected, "{json}");
+        }
+        assert_eq!(serde_json::from_str::<GoldilocksField>(r#""007""#).unwrap(), GoldilocksField::new(7));
+        // Numbers from older dumps are reduced rather than rejected.
+        assert_eq!(serde_json::from_str::<GoldilocksField>("18446744069414584321").unwrap(), GoldilocksField::ZERO);
+        assert_eq!(serde_json::from_str::<GoldilocksField>("18446744073709551615").unwrap(), GoldilocksField::new(u64::MAX));
+    }
+
+    #[test]
+    fn json_never_emits_bare_numbers() {
+        for x in samples() {
+            let json = serde_json::to_string(&x).unwrap();
+            assert!(json.starts_with('"') && json.ends_with('"'), "{json}");
+        }
+        let json = serde_json::to_string(&vec![GoldilocksField::new(MODULUS - 1); 3]).unwrap();
+        assert_eq!(json, r#"["0xffffffff00000000","0xffffffff00000000","0xffffffff00000000"]"#);
+    }
+}
@@ -0,0 +1,28 @@
+//! Helpers shared by the unit tests of several modules.
+
+use crate::{GoldilocksField, MODULUS};
+
+/// Deterministic splitmix64 stream so randomized tests are reproducible.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub(crate) fn next_element(&mut self) -> u64 {
+        self.next_u64() % MODULUS
+    }
+
+    pub(crate) fn next_field(&mut self) -> GoldilocksField {
+        GoldilocksField::new(self.next_element())
+    }
+}
+
+pub(crate) fn random_elements(rng: &mut SplitMix64, len: usize) -> Vec<GoldilocksField> {
+    (0..len).map(|_| rng.next_field()).collect()
+}
@@ -0,0 +1,9 @@
+//! Fiat–Shamir transcripts.
+
+mod challenger;
+#[cfg(feature = "transcript")]
+mod keccak;
+
+pub use challenger::PoseidonChallenger;
+#[cfg(feature = "transcript")]
+pub use keccak::Transcript;
@@ -0,0 +1,210 @@
+//! Field-native Fiat–Shamir challenger: a duplex sponge over Poseidon.
+
+use crate::hash::poseidon::poseidon_permutation;
+use crate::GoldilocksField;
+
+const WIDTH: usize = 12;
+const RATE: usize = 8;
+
+/// The plonky2 challenger: an overwrite-mode duplex over the width-12 Poseidon
+/// permutation, cheap to recompute inside a circuit.
+///
+/// Observed elements are buffered until RATE of them are pending, then
+/// overwrite the rate part of the state before a permutation. Sampling
+/// flushes any pending inputs, and otherwise hands out the rate part of the
+/// last permuted state, last element first, permuting again once it is used
+/// up. Observing discards the unused outputs, so every challenge depends on
+/// everything observed before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonChallenger {
+    state: [GoldilocksField; WIDTH],
+    input_buffer: Vec<GoldilocksField>,
+    output_buffer: Vec<GoldilocksField>,
+}
+
+impl Default for PoseidonChallenger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonChallenger {
+    pub fn new() -> Self {
+        PoseidonChallenger {
+            state: [GoldilocksField::ZERO; WIDTH],
+            input_buffer: Vec::with_capacity(RATE),
+            output_buffer: Vec::with_capacity(RATE),
+        }
+    }
+
+    pub fn observe_element(&mut self, element: GoldilocksField) {
+        self.output_buffer.clear();
+        self.input_buffer.push(element);
+        if self.input_buffer.len() == RATE {
+            self.duplex();
+        }
+    }
+
+    pub fn observe_elements(&mut self, elements: &[GoldilocksField]) {
+        for &element in elements {
+            self.observe_element(element);
+        }
+    }
+
+    pub fn sample_element(&mut self) -> GoldilocksField {
+        if !self.input_buffer.is_empty() || self.output_buffer.is_empty() {
+            self.duplex();
+        }
+        self.output_buffer.pop().expect("duplex fills the output buffer")
+    }
+
+    /// Returns the low `bits` bits of a sampled element, e.g. a query index
+    /// into a domain of size 2^bits. Since p is within 2^32 of 2^64, the
+    /// result is within 2^-32 of uniform.
+    ///
+    /// Panics unless bits < usize::BITS.
+    pub fn sample_bits(&mut self, bits: usize) -> usize {
+        assert!(bits < usize::BITS as usize, "PoseidonChallenger: bits must be below usize::BITS");
+        let value = self.sample_element().to_canonical_u64() as usize;
+        value & ((1 << bits) - 1)
+    }
+
+    fn duplex(&mut self) {
+        for (slot, input) in self.state.iter_mut().zip(self.input_buffer.drain(..)) {
+            *slot = input;
+        }
+        poseidon_permutation(&mut self.state);
+        self.output_buffer.clear();
+        self.output_buffer.extend_from_slice(&self.state[..RATE]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(values: impl IntoIterator<Item = u64>) -> Vec<GoldilocksField> {
+        values.into_iter().map(GoldilocksField::new).collect()
+    }
+
+    #[test]
+    fn interleavings_are_deterministic() {
+        let run = || {
+            let mut challenger = PoseidonChallenger::new();
+            let mut samples = Vec::new();
+            for round in 0..5u64 {
+                challenger.observe_elements(&elements(0..round * 3));
+                for _ in 0..round {
+                    samples.push(challenger.sample_element());
+                }
+                samples.push(GoldilocksField::new(challenger.sample_bits(20) as u64));
+            }
+            samples
+        };
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn observing_one_by_one_or_in_bulk_agrees() {
+        let inputs = elements(1..=11);
+        let mut bulk = PoseidonChallenger::new();
+        bulk.observe_elements(&inputs);
+        let mut single = PoseidonChallenger::new();
+        for &x in &inputs {
+            single.observe_element(x);
+        }
+        assert_eq!(bulk.sample_element(), single.sample_element());
+        assert_eq!(bulk, single);
+    }
+
+    #[test]
+    fn challenges_depend_on_observations() {
+        let mut a = PoseidonChallenger::new();
+        let mut b = PoseidonChallenger::new();
+        a.observe_elements(&elements([1, 2, 3]));
+        b.observe_elements(&elements([1, 2, 4]));
+        assert_ne!(a.sample_element(), b.sample_element());
+
+        // Observing after sampling discards the remaining outputs.
+        let mut c = PoseidonChallenger::new();
+        let first = c.sample_element();
+        c.observe_element(first);
+        let mut d = PoseidonChallenger::new();
+        d.sample_element();
+        assert_ne!(c.sample_element(), d.sample_element());
+    }
+
+    #[test]
+    fn sampling_past_the_rate_permutes_again() {
+        let mut challenger = PoseidonChallenger::new();
+        let samples: Vec<_> = (0..RATE + 2).map(|_| challenger.sample_element()).collect();
+
+        let mut state = [GoldilocksField::ZERO; WIDTH];
+        poseidon_permutation(&mut state);
+        let first_block: Vec<_> = state[..RATE].iter().rev().copied().collect();
+        assert_eq!(samples[..RATE], first_block[..]);
+        poseidon_permutation(&mut state);
+        assert_eq!(samples[RATE..], [state[RATE - 1], state[RATE - 2]]);
+    }
+
+    #[test]
+    fn sample_bits_masks_a_sampled_element() {
+        let mut challenger = PoseidonChallenger::new();
+        challenger.observe_elements(&elements([7, 8, 9]));
+        let mut copy = challenger.clone();
+        for bits in [0, 1, 5, 20, 32, 63] {
+            let expected = copy.sample_element().to_canonical_u64() & ((1u64 << bits) - 1);
+            assert_eq!(challenger.sample_bits(bits) as u64, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "bits")]
+    fn sample_bits_rejects_full_width() {
+        PoseidonChallenger::new().sample_bits(usize::BITS as usize);
+    }
+
+    /// The permutation of `state` with `inputs` overwriting its first
+    /// elements.
+    fn duplex(state: &mut [GoldilocksField; WIDTH], inputs: &[u64]) -> [GoldilocksField; WIDTH] {
+        for (x, input) in state.iter_mut().zip(inputs) {
+            *x = GoldilocksField::new(*input);
+        }
+        poseidon_permutation(state);
+        *state
+    }
+
+    #[test]
+    fn transcripts_follow_the_overwrite_duplex() {
+        // The first challenges of a fresh challenger are elements 7 and 6 of
+        // plonky2's known answer for the permutation of the zero state.
+        let mut challenger = PoseidonChallenger::new();
+        let samples = [challenger.sample_element(), challenger.sample_element()];
+        assert_eq!(samples, elements([0x1c687363b207df62, 0xd032648251ee0b3c])[..]);
+
+        // Pending inputs are flushed by the next sample, and observing drops
+        // the unused outputs.
+        let mut challenger = PoseidonChallenger::new();
+        challenger.observe_elements(&elements(0..5));
+        let mut samples: Vec<_> = (0..3).map(|_| challenger.sample_element()).collect();
+        challenger.observe_element(GoldilocksField::new(100));
+        samples.push(challenger.sample_element());
+        let mut state = [GoldilocksField::ZERO; WIDTH];
+        let first = duplex(&mut state, &[0, 1, 2, 3, 4]);
+        let second = duplex(&mut state, &[100]);
+        assert_eq!(samples, [first[7], first[6], first[5], second[7]]);
+
+        // A full rate of inputs permutes at once; outputs run out after RATE
+        // samples and a permutation without inputs refills them.
+        let mut challenger = PoseidonChallenger::new();
+        challenger.observe_elements(&elements(0..12));
+        let samples: Vec<_> = (0..10).map(|_| challenger.sample_element()).collect();
+        let mut state = [GoldilocksField::ZERO; WIDTH];
+        duplex(&mut state, &[0, 1, 2, 3, 4, 5, 6, 7]);
+        let outputs = duplex(&mut state, &[8, 9, 10, 11]);
+        let refill = duplex(&mut state, &[]);
+        let mut expected: Vec<_> = outputs[..RATE].iter().rev().copied().collect();
+        expected.extend([refill[7], refill[6]]);
+        assert_eq!(samples, expected);
+    }
+}
@@ -0,0 +1,178 @@
+//! Byte-oriented Fiat–Shamir transcript over Keccak256.
+
+use lambdaworks_math::traits::ByteConversion;
+use sha3::{Digest, Keccak256};
+
+use crate::{reduce_u128, GoldilocksField};
+
+const TAG_PROTOCOL: u8 = 0;
+const TAG_MESSAGE: u8 = 1;
+const TAG_CHALLENGE: u8 = 2;
+const TAG_OUTPUT: u8 = 3;
+
+/// A Keccak256 hash chain over everything the prover has sent so far.
+///
+/// Every operation hashes the previous 32-byte state together with a tag, the
+/// length-prefixed label and the length-prefixed payload, so no two different
+/// sequences of operations can produce the same state by re-splitting bytes
+/// between labels and payloads. Challenges are derived from the state after
+/// their label has been absorbed, so asking for a challenge also binds it into
+/// the transcript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    state: [u8; 32],
+}
+
+impl Transcript {
+    /// Starts a transcript for the protocol named by `domain_label`.
+    pub fn new(domain_label: &[u8]) -> Self {
+        let mut transcript = Transcript { state: [0; 32] };
+        transcript.absorb(TAG_PROTOCOL, domain_label, &[]);
+        transcript
+    }
+
+    pub fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.absorb(TAG_MESSAGE, label, bytes);
+    }
+
+    /// Appends the canonical little-endian encoding of `element`.
+    pub fn append_field_element(&mut self, label: &[u8], element: &GoldilocksField) {
+        self.absorb(TAG_MESSAGE, label, &element.to_bytes_le());
+    }
+
+    /// Returns 128 bits of hash output reduced mod p, so the distance from
+    /// uniform is below 2^-64.
+    pub fn challenge_field_element(&mut self, label: &[u8]) -> GoldilocksField {
+        GoldilocksField::new(reduce_u128(self.challenge_u128(label)))
+    }
+
+    /// Returns a challenge in 0..bound, from 128 bits of hash output so the
+    /// modulo bias is below bound / 2^128.
+    ///
+    /// Panics if bound is 0.
+    pub fn challenge_usize(&mut self, label: &[u8], bound: usize) -> usize {
+        assert!(bound > 0, "Transcript: challenge bound must be positive");
+        (self.challenge_u128(label) % bound as u128) as usize
+    }
+
+    fn challenge_u128(&mut self, label: &[u8]) -> u128 {
+        self.absorb(TAG_CHALLENGE, label, &[]);
+        let mut hasher = Keccak256::new();
+        hasher.update(self.state);
+        hasher.update([TAG_OUTPUT]);
+        let output = hasher.finalize();
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&output[..16]);
+        u128::from_le_bytes(bytes)
+    }
+
+    fn absorb(&mut self, tag: u8, label: &[u8], data: &[u8]) {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.state);
+        hasher.update([tag]);
+        hasher.update((label.len() as u64).to_le_bytes());
+        hasher.update(label);
+        hasher.update((data.len() as u64).to_le_bytes());
+        hasher.update(data);
+        self.state.copy_from_slice(&hasher.finalize()[..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MODULUS;
+
+    fn sample_transcript() -> Transcript {
+        let mut transcript = Transcript::new(b"test protocol");
+        transcript.append_bytes(b"commitment", &[1, 2, 3, 4]);
+        transcript.append_field_element(b"evaluation", &GoldilocksField::new(MODULUS - 5));
+        transcript
+    }
+
+    #[test]
+    fn identical_inputs_give_identical_challenges() {
+        let mut a = sample_transcript();
+        let mut b = sample_transcript();
+        for _ in 0..10 {
+            assert_eq!(a.challenge_field_element(b"alpha"), b.challenge_field_element(b"alpha"));
+            assert_eq!(a.challenge_usize(b"query", 1000), b.challenge_usize(b"query", 1000));
+        }
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn labels_and_payloads_are_bound() {
+        let alpha = sample_transcript().challenge_field_element(b"alpha");
+        assert_ne!(sample_transcript().challenge_field_element(b"beta"), alpha);
+
+        let mut other_label = Transcript::new(b"test protocol");
+        other_label.append_bytes(b"commitmenu", &[1, 2, 3, 4]);
+        other_label.append_field_element(b"evaluation", &GoldilocksField::new(MODULUS - 5));
+        assert_ne!(other_label.challenge_field_element(b"alpha"), alpha);
+
+        // Moving bytes from the label into the payload changes the transcript.
+        let mut resplit = Transcript::new(b"test protocol");
+        resplit.append_bytes(b"commit", b"ment\x01\x02\x03\x04");
+        resplit.append_field_element(b"evaluation", &GoldilocksField::new(MODULUS - 5));
+        assert_ne!(resplit.challenge_field_element(b"alpha"), alpha);
+
+        let mut other_domain = Transcript::new(b"other protocol");
+        other_domain.append_bytes(b"commitment", &[1, 2, 3, 4]);
+        other_domain.append_field_element(b"evaluation", &GoldilocksField::new(MODULUS - 5));
+        assert_ne!(other_domain.challenge_field_element(b"alpha"), alpha);
+
+        // Elements are appended by value, whatever representative they were built from.
+        let mut canonical = Transcript::new(b"test protocol");
+        canonical.append_field_element(b"x", &GoldilocksField::new(3));
+        let mut wrapped = Transcript::new(b"test protocol");
+        wrapped.append_field_element(b"x", &GoldilocksField::new(MODULUS + 3));
+        assert_eq!(canonical, wrapped);
+    }
+
+    #[test]
+    fn successive_challenges_differ() {
+        let mut transcript = sample_transcript();
+        let first = transcript.challenge_field_element(b"alpha");
+        let second = transcript.challenge_field_element(b"alpha");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn challenges_cover_the_high_bits() {
+        let mut transcript = Transcript::new(b"statistics");
+        let samples = 2048;
+        let mut bit_counts = [0u32; 64];
+        for _ in 0..samples {
+            let value = transcript.challenge_field_element(b"x").to_canonical_u64();
+            assert!(value < MODULUS);
+            for (bit, count) in bit_counts.iter_mut().enumerate() {
+                *count += ((value >> bit) & 1) as u32;
+            }
+        }
+        // Every bit, up to bit 63, is set about half of the time; 6 standard
+        // deviations is about 136.
+        for (bit, &count) in bit_counts.iter().enumerate() {
+            assert!(count.abs_diff(samples / 2) < 136, "bit {bit} set {count} times");
+        }
+    }
+
+    #[test]
+    fn challenge_usize_stays_in_range() {
+        let mut transcript = Transcript::new(b"queries");
+        let mut seen = [false; 7];
+        for _ in 0..200 {
+            seen[transcript.challenge_usize(b"index", 7)] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(transcript.challenge_usize(b"index", 1), 0);
+        let large = transcript.challenge_usize(b"index", usize::MAX);
+        assert!(large < usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "bound")]
+    fn challenge_usize_rejects_zero_bound() {
+        Transcript::new(b"queries").challenge_usize(b"index", 0);
+    }
+}
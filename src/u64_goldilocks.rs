@@ -1,282 +1,2686 @@
 use lambdaworks_math::{
     field::traits::IsField,
     field::traits::IsPrimeField,
+    field::traits::IsFFTField,
     field::errors::FieldError,
     errors::*,
-    traits::*
+    traits::*,
+    unsigned_integer::element::UnsignedInteger,
+    unsigned_integer::traits::IsUnsignedInteger,
 };
 
+mod backend;
+#[cfg(feature = "borsh")]
+mod borsh_impls;
+pub mod ecgfp5;
+pub mod encoding;
+pub mod extension;
+#[cfg(feature = "ff")]
+mod ff_impls;
+#[cfg(feature = "capi")]
+pub mod ffi;
+mod fixed_base;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+pub mod hash;
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+mod hash_to_field;
+pub mod merkle;
+pub mod ntt;
+#[cfg(feature = "num-traits")]
+mod num_traits_impls;
+#[cfg(feature = "plonky2-interop")]
+pub mod plonky2_interop;
+#[cfg(feature = "proptest-support")]
+pub mod proptest_support;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod reduction;
+#[cfg(feature = "rkyv")]
+mod rkyv_impls;
+mod roots;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod transcript;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "winterfell-interop")]
+pub mod winterfell_interop;
+#[cfg(test)]
+mod test_utils;
+
+pub use extension::{GoldilocksCubicExtension, GoldilocksQuadraticExtension, GoldilocksQuinticExtension};
+pub use fixed_base::FixedBasePow;
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+pub use hash_to_field::{expand_message_xmd, hash_to_field};
+#[cfg(feature = "rkyv")]
+pub use rkyv_impls::ArchivedGoldilocksField;
+pub use roots::{roots_of_unity, roots_of_unity_bit_reversed, RootsIterator};
+
+use reduction::{ActiveReducer, Reducer};
+
 pub const MODULUS: u64 = 0xffff_ffff_0000_0001;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct GoldilocksField {
-    value: u64,
-}
+/// 2^64 mod p, i.e. 2^32 - 1.
+pub const EPSILON: u64 = 0xffff_ffff;
+
+/// p - 1 = 2^32 * (2^32 - 1), so the multiplicative group has a subgroup of
+/// order 2^k for every k up to 32.
+pub const TWO_ADICITY: u32 = 32;
+
+/// (p - 1) / 2^32 = 2^32 - 1, the odd part of the multiplicative group order.
+/// Raising to this power maps any element into the order-2^32 subgroup.
+pub const MULTIPLICATIVE_ODD_ORDER: u64 = (MODULUS - 1) >> TWO_ADICITY;
+
+/// 7^((p - 1) / 2^32), a generator of the order-2^32 subgroup.
+pub const TWO_ADIC_ROOT_OF_UNITY: GoldilocksField =
+    GoldilocksField::from_canonical(<GoldilocksField as IsFFTField>::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY);
+
+/// Maps any u64 to its canonical residue in [0, p). Every u64 is below 2p, so
+/// this subtracts p under a mask derived from the comparison, without branching.
+pub const fn canonicalize(a: u64) -> u64 {
+    let mask = ((a >= MODULUS) as u64).wrapping_neg();
+    a.wrapping_sub(MODULUS & mask)
+}
+
+/// Reduces a 128-bit value modulo p using 2^64 = 2^32 - 1 and 2^96 = -1 (mod p),
+/// returning the canonical residue.
+///
+/// Writing x = x_hi_hi * 2^96 + x_hi_lo * 2^64 + x_lo, the result is
+/// x_lo - x_hi_hi + x_hi_lo * EPSILON. The subtraction can borrow, adding
+/// 2^64 = EPSILON too much, and the addition can carry, dropping 2^64; both are
+/// corrected by EPSILON, and a final conditional subtraction makes it canonical.
+pub fn reduce_u128(x: u128) -> u64 {
+    let x_lo = x as u64;
+    let x_hi = (x >> 64) as u64;
+    let x_hi_hi = x_hi >> 32;
+    let x_hi_lo = x_hi & EPSILON;
+
+    // x_lo - x_hi_hi; on borrow we wrapped by 2^64, which is EPSILON too many.
+    let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+    if borrow {
+        t0 = t0.wrapping_sub(EPSILON);
+    }
+    // x_hi_lo * 2^64 = x_hi_lo * EPSILON, which always fits in 64 bits.
+    let t1 = x_hi_lo * EPSILON;
+    let (t2, carry) = t0.overflowing_add(t1);
+    let t2 = t2.wrapping_add(EPSILON * carry as u64);
+
+    canonicalize(t2)
+}
+
+/// Computes a * b + c on base-type values with a single reduction. For
+/// canonical inputs the intermediate is at most p(p - 1) < 2^128.
+pub fn multiply_accumulate(a: &u64, b: &u64, c: &u64) -> u64 {
+    backend::mul_add(*a, *b, *c)
+}
+
+/// Computes sum(a[i] * b[i]) with a single reduction at the end: products are
+/// accumulated in a u128, counting the wrap-arounds past 2^128 separately.
+///
+/// Panics if the slices have different lengths.
+pub fn inner_product(a: &[GoldilocksField], b: &[GoldilocksField]) -> GoldilocksField {
+    assert_eq!(a.len(), b.len(), "inner_product: slices have different lengths");
+
+    // 2^128 mod p = -2^32 mod p.
+    const TWO_128_MOD_P: u64 = MODULUS - (1 << 32);
+
+    let mut acc: u128 = 0;
+    let mut wraps: u64 = 0;
+    for (x, y) in a.iter().zip(b) {
+        let (sum, wrapped) = acc.overflowing_add(x.value as u128 * y.value as u128);
+        acc = sum;
+        wraps += wrapped as u64;
+    }
+    let acc = ActiveReducer::reduce_u128(acc);
+    let sum = ActiveReducer::reduce_u128(wraps as u128 * TWO_128_MOD_P as u128 + acc as u128);
+    GoldilocksField { value: backend::from_product_sum(sum) }
+}
+
+/// A signed sum of products of internal values, reduced once at the end. The
+/// extension multiplications accumulate each output limb here, so Karatsuba's
+/// cross terms cost no extra reductions.
+///
+/// Carries and borrows past 2^128 are counted in `wraps` and folded back with
+/// 2^128 = -2^32 (mod p), as in `inner_product`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WideSum {
+    acc: u128,
+    wraps: i64,
+}
+
+impl WideSum {
+    pub(crate) fn product(a: GoldilocksField, b: GoldilocksField) -> Self {
+        WideSum { acc: a.value as u128 * b.value as u128, wraps: 0 }
+    }
+
+    pub(crate) fn reduce(self) -> GoldilocksField {
+        const P: i128 = MODULUS as i128;
+        let r = ActiveReducer::reduce_u128(self.acc) as i128 - ((self.wraps as i128) << 32);
+        let r = if r < 0 {
+            r + P
+        } else if r >= P {
+            r - P
+        } else {
+            r
+        };
+        GoldilocksField { value: backend::from_product_sum(r as u64) }
+    }
+}
+
+impl core::ops::Add for WideSum {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (acc, carry) = self.acc.overflowing_add(rhs.acc);
+        WideSum { acc, wraps: self.wraps + rhs.wraps + carry as i64 }
+    }
+}
+
+impl core::ops::Sub for WideSum {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (acc, borrow) = self.acc.overflowing_sub(rhs.acc);
+        WideSum { acc, wraps: self.wraps - rhs.wraps - borrow as i64 }
+    }
+}
+
+/// Inverts every element with a single field inversion (Montgomery's trick):
+/// prefix products are inverted once and unwound back to front.
+///
+/// Returns `InvZeroError` if any element is zero; see
+/// `batch_inverse_allowing_zeros` to map zeros to zero instead.
+pub fn batch_inverse(values: &[GoldilocksField]) -> Result<Vec<GoldilocksField>, FieldError> {
+    if values.contains(&GoldilocksField::ZERO) {
+        return Err(FieldError::InvZeroError);
+    }
+    Ok(batch_inverse_allowing_zeros(values))
+}
+
+/// Like `batch_inverse`, but zeros are skipped in the products and map to zero.
+pub fn batch_inverse_allowing_zeros(values: &[GoldilocksField]) -> Vec<GoldilocksField> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = GoldilocksField::ONE;
+    for x in values {
+        prefix.push(acc);
+        if *x != GoldilocksField::ZERO {
+            acc *= *x;
+        }
+    }
+
+    // acc is a product of nonzero elements, so it is invertible.
+    let mut inv_acc = GoldilocksField { value: GoldilocksField::inv(&acc.value).unwrap() };
+    let mut result = vec![GoldilocksField::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        if values[i] != GoldilocksField::ZERO {
+            result[i] = inv_acc * prefix[i];
+            inv_acc *= values[i];
+        }
+    }
+    result
+}
+
+/// Error returned when parsing a `GoldilocksField` from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseFieldElementError {
+    /// The string was empty (after trimming whitespace and the sign).
+    Empty,
+    /// The string contained a character that is not a digit of its radix.
+    InvalidDigit,
+    /// A radix other than 10 or 16 was requested.
+    UnsupportedRadix(u32),
+}
+
+impl core::fmt::Display for ParseFieldElementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseFieldElementError::Empty => write!(f, "cannot parse a field element from an empty string"),
+            ParseFieldElementError::InvalidDigit => write!(f, "invalid digit found in field element string"),
+            ParseFieldElementError::UnsupportedRadix(radix) => {
+                write!(f, "unsupported radix {radix} for a field element string: expected 10 or 16")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseFieldElementError {}
+
+/// Lowercase `0x`-prefixed hex of the canonical residue of a base-type value.
+pub fn to_hex(a: &u64) -> String {
+    format!("0x{:x}", backend::from_repr(canonicalize(*a)))
+}
+
+/// Evaluates x^(p - 2) with a fixed addition chain of 64 squarings and 9
+/// multiplications. p - 2 = 0xfffffffe_ffffffff is 31 ones, a zero and 32
+/// ones, so it is built from the runs x^(2^k - 1), with
+/// x^(2^(j + k) - 1) = (x^(2^j - 1))^(2^k) * x^(2^k - 1).
+fn p_minus_2_chain<T: Copy>(x: T, square: impl Fn(T) -> T, mul: impl Fn(T, T) -> T) -> T {
+    let exp_acc = |base: T, squarings: u32, tail: T| {
+        let mut acc = base;
+        for _ in 0..squarings {
+            acc = square(acc);
+        }
+        mul(acc, tail)
+    };
+    let t1 = x;
+    let t2 = exp_acc(t1, 1, t1);
+    let t3 = exp_acc(t2, 1, t1);
+    let t6 = exp_acc(t3, 3, t3);
+    let t12 = exp_acc(t6, 6, t6);
+    let t24 = exp_acc(t12, 12, t12);
+    let t30 = exp_acc(t24, 6, t6);
+    let t31 = exp_acc(t30, 1, t1);
+    let t32 = exp_acc(t31, 1, t1);
+    exp_acc(t31, 33, t32)
+}
+
+
+/// Prime factorization of p - 1 = 2^32 * 3 * 5 * 17 * 257 * 65537.
+const GROUP_ORDER_FACTORS: [(u64, u32); 6] = [(2, 32), (3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)];
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// a^-1 mod m for gcd(a, m) = 1, with 0 for m = 1.
+fn inverse_mod(a: u64, m: u64) -> u64 {
+    let (mut r0, mut r1) = (m as i128, (a % m) as i128);
+    let (mut s0, mut s1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    debug_assert!(r0 == 1 || m == 1);
+    s0.rem_euclid(m as i128) as u64
+}
+
+/// The x mod m1 * m2 with x = a1 mod m1 and x = a2 mod m2, for coprime m1, m2
+/// whose product is at most p - 1.
+fn crt(a1: u64, m1: u64, a2: u64, m2: u64) -> u64 {
+    // x = a1 + m1 * t with t = (a2 - a1) * m1^-1 mod m2.
+    let diff = (a2 as i128 - a1 as i128).rem_euclid(m2 as i128) as u128;
+    let t = diff * inverse_mod(m1 % m2, m2) as u128 % m2 as u128;
+    (a1 as u128 + m1 as u128 * t) as u64
+}
+
+/// Halves a canonical value modulo p.
+const fn halve_u64(value: u64) -> u64 {
+    if value & 1 == 0 {
+        value >> 1
+    } else {
+        (value >> 1) + (MODULUS >> 1) + 1
+    }
+}
+
+/// Reduces hi * 2^64 + lo modulo p, where hi * 2^64 = hi * EPSILON, returning
+/// the canonical residue.
+pub fn reduce_u96(hi: u32, lo: u64) -> u64 {
+    let t = hi as u64 * EPSILON;
+    let (sum, carry) = lo.overflowing_add(t);
+    // On carry sum < t, so adding EPSILON cannot overflow again.
+    let sum = sum.wrapping_add(EPSILON * carry as u64);
+
+    canonicalize(sum)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GoldilocksField {
+    value: u64,
+}
+
+impl GoldilocksField {
+    pub const ZERO: GoldilocksField = GoldilocksField::from_canonical(0);
+    pub const ONE: GoldilocksField = GoldilocksField::from_canonical(1);
+    pub const TWO: GoldilocksField = GoldilocksField::from_canonical(2);
+    pub const NEG_ONE: GoldilocksField = GoldilocksField::from_canonical(MODULUS - 1);
+    pub const GENERATOR: GoldilocksField = GoldilocksField::from_canonical(7);
+    /// A generator of the multiplicative group, the same element as `GENERATOR`.
+    pub const MULTIPLICATIVE_GENERATOR: GoldilocksField = GoldilocksField::GENERATOR;
+
+    /// Creates an element from a value already below p, skipping the reduction.
+    pub const fn from_canonical(value: u64) -> Self {
+        debug_assert!(value < MODULUS);
+        GoldilocksField { value: backend::to_repr(value) }
+    }
+
+    /// Creates a field element, reducing `a` into the canonical range [0, p).
+    pub const fn new(a: u64) -> Self {
+        GoldilocksField { value: backend::to_repr(a) }
+    }
+
+    /// Returns the canonical representative in [0, p).
+    pub const fn to_canonical_u64(&self) -> u64 {
+        backend::from_repr(canonicalize(self.value))
+    }
+
+    /// Like `from_bytes_be`, but rejects encodings of values >= p with
+    /// `ByteConversionError::InvalidValue` so every element has exactly one
+    /// accepted encoding.
+    pub fn from_bytes_be_canonical(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 8] = first_8_bytes(bytes).ok_or(ByteConversionError::FromBEBytesError)?;
+        Self::check_canonical(u64::from_be_bytes(bytes))
+    }
+
+    /// Little-endian counterpart of `from_bytes_be_canonical`.
+    pub fn from_bytes_le_canonical(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 8] = first_8_bytes(bytes).ok_or(ByteConversionError::FromLEBytesError)?;
+        Self::check_canonical(u64::from_le_bytes(bytes))
+    }
+
+    /// Length in bytes of the `AsBytes` encoding, for preallocating
+    /// buffers of serialized elements.
+    pub const fn serialized_size() -> usize {
+        8
+    }
+
+    /// The big-endian encoding `AsBytes` produced before 0.2.0.
+    pub fn serialize_be(&self) -> Vec<u8> {
+        self.to_bytes_be()
+    }
+
+    /// Big-endian counterpart of `Deserializable::deserialize`, for data
+    /// written before 0.2.0. Applies the same length and canonicity checks.
+    pub fn deserialize_be(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != Self::serialized_size() {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        Self::from_bytes_be_canonical(bytes).map_err(|x| x.into())
+    }
+
+    fn check_canonical(value: u64) -> Result<Self, ByteConversionError> {
+        if value >= MODULUS {
+            return Err(ByteConversionError::InvalidValue);
+        }
+        Ok(Self::from_canonical(value))
+    }
+
+    /// Every u32 is below p, so this skips reduction entirely.
+    pub const fn from_canonical_u32(x: u32) -> Self {
+        Self::from_canonical(x as u64)
+    }
+
+    /// Embeds a signed integer, mapping negative values to p - |x|.
+    pub const fn from_i64(x: i64) -> Self {
+        let magnitude = Self::new(x.unsigned_abs());
+        if x < 0 {
+            GoldilocksField { value: MODULUS - magnitude.value }
+        } else {
+            magnitude
+        }
+    }
+
+    /// Reduces a 128-bit integer into the field.
+    pub fn from_u128(x: u128) -> Self {
+        GoldilocksField { value: backend::to_repr(reduce_u128(x)) }
+    }
+
+    /// Maps 16 bytes of uniform entropy, such as a hash output, to an element
+    /// within statistical distance 2^-64 of uniform: the bytes are read as a
+    /// little-endian u128 and reduced with the 2^64 = 2^32 - 1 folding.
+    pub fn from_random_bytes(bytes: &[u8; 16]) -> Self {
+        Self::from_u128(u128::from_le_bytes(*bytes))
+    }
+
+    /// Slice form of `from_random_bytes`. Fails with
+    /// `ByteConversionError::FromLEBytesError` unless given exactly 16 bytes.
+    pub fn from_random_bytes_slice(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: &[u8; 16] = bytes.try_into().map_err(|_| ByteConversionError::FromLEBytesError)?;
+        Ok(Self::from_random_bytes(bytes))
+    }
+
+    /// Reduces 8 little-endian bytes modulo p. This is biased: the values
+    /// below 2^32 - 1 are twice as likely as the rest, a statistical distance
+    /// of about 2^-32 from uniform. Prefer `from_random_bytes` unless that is
+    /// acceptable.
+    pub fn from_random_bytes_64(bytes: &[u8; 8]) -> Self {
+        Self::new(u64::from_le_bytes(*bytes))
+    }
+
+    /// Returns the unique `i64` that `from_i64` maps to this element, or `None`
+    /// when two `i64` values map to it. That happens for canonical values in
+    /// [2^63 - 2^32 + 1, 2^63 - 1], e.g. the images of `i64::MIN` and `i64::MAX`.
+    pub fn to_i64_checked(&self) -> Option<i64> {
+        let value = self.to_canonical_u64();
+        let as_positive = value <= i64::MAX as u64;
+        let as_negative = MODULUS - value <= 1 << 63;
+        match (as_positive, as_negative) {
+            (true, false) => Some(value as i64),
+            (false, true) => Some((value as i128 - MODULUS as i128) as i64),
+            _ => None,
+        }
+    }
+
+    /// Lowercase `0x`-prefixed hex of the canonical value, without leading zeros.
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.value)
+    }
+
+    /// Like `to_hex`, but always emits 16 nibbles.
+    pub fn to_hex_padded(&self) -> String {
+        format!("0x{:016x}", self.to_canonical_u64())
+    }
+
+    /// Squares the element.
+    pub fn square(&self) -> Self {
+        GoldilocksField { value: <Self as IsField>::square(&self.value) }
+    }
+
+    /// Returns 2 * self.
+    pub fn double(&self) -> Self {
+        GoldilocksField { value: <Self as IsField>::add(&self.value, &self.value) }
+    }
+
+    /// Returns self / 2. Odd values are made even by adding p; since both are
+    /// odd, (v + p) / 2 = (v >> 1) + (p + 1) / 2, which cannot overflow.
+    pub fn halve(&self) -> Self {
+        GoldilocksField { value: halve_u64(self.value) }
+    }
+
+    /// Multiplies by a 32-bit constant. The product fits in 96 bits, so it
+    /// needs only the cheaper `reduce_u96`.
+    pub fn mul_u32(&self, c: u32) -> Self {
+        let x = self.value as u128 * c as u128;
+        GoldilocksField { value: reduce_u96((x >> 64) as u32, x as u64) }
+    }
+
+    /// Multiplies by a constant, taking the `mul_u32` path when `c` fits in
+    /// 32 bits and the generic multiplication otherwise.
+    pub fn mul_u64_small(&self, c: u64) -> Self {
+        match u32::try_from(c) {
+            Ok(c) => self.mul_u32(c),
+            Err(_) => *self * Self::new(c),
+        }
+    }
+
+    /// Multiplies by the generator 7 as (x << 3) - x.
+    pub fn mul_by_7(&self) -> Self {
+        let x = ((self.value as u128) << 3) - self.value as u128;
+        GoldilocksField { value: reduce_u96((x >> 64) as u32, x as u64) }
+    }
+
+    /// Multiplies by 3 as x + x + x.
+    pub fn mul_by_3(&self) -> Self {
+        *self + *self + *self
+    }
+
+    /// Computes a * b + c with a single reduction.
+    pub fn multiply_accumulate(a: &Self, b: &Self, c: &Self) -> Self {
+        GoldilocksField { value: multiply_accumulate(&a.value, &b.value, &c.value) }
+    }
+
+    /// Raises the element to `exponent`, which may be any unsigned integer
+    /// type, at its full width.
+    pub fn pow<T: IsUnsignedInteger>(&self, exponent: T) -> Self {
+        GoldilocksField { value: <Self as IsField>::pow(&self.value, exponent) }
+    }
+
+    /// Raises the element to a 64-bit exponent.
+    pub fn pow_u64(&self, exponent: u64) -> Self {
+        self.pow(exponent)
+    }
+
+    /// Raises the element to a multi-limb exponent given as little-endian u64
+    /// limbs, by left-to-right square-and-multiply. An empty slice is the
+    /// exponent zero.
+    pub fn pow_limbs(&self, exponent: &[u64]) -> Self {
+        let mut acc = Self::ONE;
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> bit) & 1 == 1 {
+                    acc *= *self;
+                }
+            }
+        }
+        acc
+    }
+
+    /// Raises the element to a signed exponent; a negative exponent inverts
+    /// the element once and raises the inverse to |exponent|. Zero to a
+    /// negative power is `InvZeroError`, while 0^0 is one, as with `pow_u64`.
+    pub fn pow_i64(&self, exponent: i64) -> Result<Self, FieldError> {
+        if exponent >= 0 {
+            return Ok(self.pow_u64(exponent as u64));
+        }
+        let inverse = GoldilocksField { value: <Self as IsField>::inv(&self.value)? };
+        Ok(inverse.pow_u64(exponent.unsigned_abs()))
+    }
+
+    /// Returns self^(2^k) by squaring k times, for any k.
+    pub fn exp_power_of_2(&self, k: u32) -> Self {
+        let mut acc = *self;
+        for _ in 0..k {
+            acc = acc.square();
+        }
+        acc
+    }
+
+    /// Raises the element to a lambdaworks `UnsignedInteger`, whose limbs are
+    /// stored most significant first.
+    pub fn pow_unsigned_integer<const NUM_LIMBS: usize>(&self, exponent: &UnsignedInteger<NUM_LIMBS>) -> Self {
+        let mut limbs = exponent.limbs;
+        limbs.reverse();
+        self.pow_limbs(&limbs)
+    }
+
+    /// Inverts a base-type value with the binary extended Euclidean algorithm.
+    ///
+    /// Keeps u * a = x1 and v * a = x2 (mod p) starting from (u, v) = (a, p),
+    /// halving and subtracting until one side reaches 1. Uses only shifts,
+    /// subtractions and comparisons, so it is much cheaper than `inv_fermat`,
+    /// but its running time depends on `a`.
+    pub fn inv_gcd(a: &u64) -> Result<u64, FieldError> {
+        debug_assert!(*a < MODULUS, "non-canonical operand");
+        if *a == 0 {
+            return Err(FieldError::InvZeroError);
+        }
+        let (mut u, mut v) = (backend::from_repr(*a), MODULUS);
+        let (mut x1, mut x2) = (1u64, 0u64);
+        while u != 1 && v != 1 {
+            while u & 1 == 0 {
+                u >>= 1;
+                x1 = halve_u64(x1);
+            }
+            while v & 1 == 0 {
+                v >>= 1;
+                x2 = halve_u64(x2);
+            }
+            if u >= v {
+                u -= v;
+                x1 = <Self as IsField>::sub(&x1, &x2);
+            } else {
+                v -= u;
+                x2 = <Self as IsField>::sub(&x2, &x1);
+            }
+        }
+        Ok(backend::to_repr(if u == 1 { x1 } else { x2 }))
+    }
+
+    /// Inverts a base-type value as a^(p - 2) by Fermat's little theorem.
+    pub fn inv_fermat(a: &u64) -> Result<u64, FieldError> {
+        debug_assert!(*a < MODULUS, "non-canonical operand");
+        if *a == 0 {
+            return Err(FieldError::InvZeroError);
+        }
+        Ok(<Self as IsField>::pow(a, MODULUS - 2))
+    }
+
+    /// Inverts a base-type value as a^(p - 2) using a fixed addition chain.
+    /// The sequence of field operations does not depend on `a`, which makes
+    /// this the inversion to use where timing matters.
+    pub fn inv_addchain(a: &u64) -> Result<u64, FieldError> {
+        debug_assert!(*a < MODULUS, "non-canonical operand");
+        let inverse = p_minus_2_chain(*a, |x| <Self as IsField>::square(&x), |x, y| Self::mul(&x, &y));
+        if *a == 0 {
+            return Err(FieldError::InvZeroError);
+        }
+        Ok(inverse)
+    }
+
+    /// Computes a / b, returning `InvZeroError` instead of panicking when b is
+    /// zero.
+    pub fn checked_div(a: &Self, b: &Self) -> Result<Self, FieldError> {
+        let inverse = <Self as IsField>::inv(&b.value)?;
+        Ok(GoldilocksField { value: Self::mul(&a.value, &inverse) })
+    }
+
+    /// Returns a square root of the element, or `None` if it is not a square.
+    ///
+    /// Of the two roots r and p - r, the one with the smaller canonical value
+    /// is returned. Uses Tonelli-Shanks with p - 1 = 2^32 * (2^32 - 1) and the
+    /// non-residue 7, whose (2^32 - 1)-th power generates the 2^32-order
+    /// subgroup.
+    pub fn sqrt(&self) -> Option<Self> {
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        // With q = 2^32 - 1: x = a^((q + 1) / 2) and t = a^q, so x^2 = t * a
+        // holds throughout while t is driven to 1 inside the 2-Sylow subgroup.
+        let y = self.pow_u64((1 << 31) - 1);
+        let mut x = y * *self;
+        let mut t = y * x;
+        let mut c = TWO_ADIC_ROOT_OF_UNITY;
+        let mut m = 32;
+        while t != Self::ONE {
+            // Least i with t^(2^i) = 1; i = m means a is not a square.
+            let mut i = 0;
+            let mut t_pow = t;
+            while t_pow != Self::ONE {
+                t_pow = t_pow.square();
+                i += 1;
+                if i == m {
+                    return None;
+                }
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.square();
+            }
+            x *= b;
+            c = b.square();
+            t *= c;
+            m = i;
+        }
+        Some(x.smaller_root())
+    }
+
+    /// Of x and -x, returns the one with the smaller canonical value.
+    fn smaller_root(self) -> Self {
+        let negated = -self;
+        if negated.to_canonical_u64() < self.to_canonical_u64() {
+            negated
+        } else {
+            self
+        }
+    }
+
+    /// Computes sqrt(u / v) with a single exponentiation chain and no
+    /// separate inversion (the `sqrt_ratio` of RFC 9380, appendix F.2.1.1,
+    /// with Z = 7).
+    ///
+    /// Returns `(true, sqrt(u / v))` when u / v is a square and
+    /// `(false, sqrt(7 * u / v))` otherwise, taking the smaller root as `sqrt`
+    /// does. For u = 0 and nonzero v the result is `(true, 0)`; for v = 0 it is
+    /// `(false, 0)`.
+    pub fn sqrt_ratio(u: &Self, v: &Self) -> (bool, Self) {
+        if *v == Self::ZERO {
+            return (false, Self::ZERO);
+        }
+        if *u == Self::ZERO {
+            return (true, Self::ZERO);
+        }
+        // Z^((q + 1) / 2) with q = 2^32 - 1.
+        const Z_SQRT_FACTOR: GoldilocksField = GoldilocksField::from_canonical(0xf132_3b7a_cab0_9084);
+
+        let (u, v) = (*u, *v);
+        let mut tv1 = TWO_ADIC_ROOT_OF_UNITY;
+        let mut tv2 = v.pow_2_32_minus_1();
+        let mut tv3 = tv2.square() * v;
+        let mut tv5 = (u * tv3).pow_u64((1 << 31) - 1) * tv2;
+        tv2 = tv5 * v;
+        tv3 = tv5 * u;
+        let mut tv4 = tv3 * tv2;
+        tv5 = tv4.pow_u64(1 << 31);
+        let is_square = tv5 == Self::ONE;
+        if !is_square {
+            tv3 *= Z_SQRT_FACTOR;
+            tv4 *= tv1;
+        }
+        for k in (2..=32).rev() {
+            tv5 = tv4;
+            for _ in 0..k - 2 {
+                tv5 = tv5.square();
+            }
+            let e1 = tv5 == Self::ONE;
+            tv2 = tv3 * tv1;
+            tv1 = tv1.square();
+            if !e1 {
+                tv3 = tv2;
+                tv4 *= tv1;
+            }
+        }
+        (is_square, tv3.smaller_root())
+    }
+
+    /// Returns an element of exact order 2^k, the k-th successive square root
+    /// of one: TWO_ADIC_ROOT_OF_UNITY squared 32 - k times. Fails with
+    /// `RootOfUnityError(k)` for k > 32.
+    pub fn primitive_root_of_unity(k: u32) -> Result<Self, FieldError> {
+        if k > TWO_ADICITY {
+            return Err(FieldError::RootOfUnityError(k as u64));
+        }
+        let mut root = TWO_ADIC_ROOT_OF_UNITY;
+        for _ in k..TWO_ADICITY {
+            root = root.square();
+        }
+        Ok(root)
+    }
+
+    /// Returns the exact multiplicative order of the element, or
+    /// `InvZeroError` for zero. Starting from p - 1, each prime factor is
+    /// divided out for as long as the element still reaches one.
+    pub fn multiplicative_order(&self) -> Result<u64, FieldError> {
+        if *self == Self::ZERO {
+            return Err(FieldError::InvZeroError);
+        }
+        let mut order = MODULUS - 1;
+        for (prime, multiplicity) in GROUP_ORDER_FACTORS {
+            for _ in 0..multiplicity {
+                if self.pow_u64(order / prime) != Self::ONE {
+                    break;
+                }
+                order /= prime;
+            }
+        }
+        Ok(order)
+    }
+
+    /// Returns the least k with base^k = target, or `None` if target is not in
+    /// the subgroup generated by base (always the case for a zero base or
+    /// target).
+    ///
+    /// Pohlig-Hellman: with n the order of base, the logarithm is found
+    /// modulo each prime power q^e dividing n, one base-q digit at a time, and
+    /// combined with the CRT. The 2^32 part lifts one bit per step; digits for
+    /// odd primes come from baby-step giant-step in the order-q subgroup. The
+    /// largest table is for q = 65537, at 257 entries.
+    pub fn discrete_log(base: &Self, target: &Self) -> Option<u64> {
+        if *target == Self::ZERO {
+            return None;
+        }
+        let n = base.multiplicative_order().ok()?;
+        if target.pow_u64(n) != Self::ONE {
+            return None;
+        }
+
+        let (mut log, mut modulus) = (0u64, 1u64);
+        for (prime, multiplicity) in GROUP_ORDER_FACTORS {
+            let mut prime_power = 1;
+            for _ in 0..multiplicity {
+                if n % (prime_power * prime) != 0 {
+                    break;
+                }
+                prime_power *= prime;
+            }
+            if prime_power == 1 {
+                continue;
+            }
+            let g = base.pow_u64(n / prime_power);
+            let h = target.pow_u64(n / prime_power);
+            let digit_log = Self::prime_power_log(g, h, prime, prime_power)?;
+            log = crt(log, modulus, digit_log, prime_power);
+            modulus *= prime_power;
+        }
+        Some(log)
+    }
+
+    /// Logarithm of h to base g, where g has order q^e = prime_power.
+    fn prime_power_log(g: Self, h: Self, prime: u64, prime_power: u64) -> Option<u64> {
+        // gamma has order q; each digit is log_gamma of h * g^-x pushed into
+        // the order-q subgroup.
+        let gamma = g.pow_u64(prime_power / prime);
+        let g_inv = Self::checked_div(&Self::ONE, &g).ok()?;
+        let (mut x, mut digit_weight) = (0u64, 1u64);
+        while digit_weight < prime_power {
+            let residual = h * g_inv.pow_u64(x);
+            let projected = residual.pow_u64(prime_power / (digit_weight * prime));
+            let digit = Self::prime_order_log(gamma, projected, prime)?;
+            x += digit * digit_weight;
+            digit_weight *= prime;
+        }
+        Some(x)
+    }
+
+    /// Baby-step giant-step for gamma of prime order q, with ceil(sqrt(q))
+    /// baby steps.
+    fn prime_order_log(gamma: Self, h: Self, prime: u64) -> Option<u64> {
+        if prime == 2 {
+            return match h {
+                h if h == Self::ONE => Some(0),
+                h if h == gamma => Some(1),
+                _ => None,
+            };
+        }
+        let m = (prime as f64).sqrt().ceil() as u64;
+        let mut baby_steps = std::collections::HashMap::with_capacity(m as usize);
+        let mut power = Self::ONE;
+        for j in 0..m {
+            baby_steps.entry(power).or_insert(j);
+            power *= gamma;
+        }
+        // power = gamma^m; walk h * gamma^(-m i).
+        let giant_step = Self::checked_div(&Self::ONE, &power).ok()?;
+        let mut current = h;
+        for i in 0..m {
+            if let Some(j) = baby_steps.get(&current) {
+                return Some(i * m + j);
+            }
+            current *= giant_step;
+        }
+        None
+    }
+
+    /// Returns a cube root of the element, or `None` if it is not a cube.
+    /// Cube roots of nonzero cubes are not unique; see `nth_root`.
+    pub fn cube_root(&self) -> Option<Self> {
+        self.nth_root(3)
+    }
+
+    /// Returns some y with y^n = self, or `None` if there is none.
+    ///
+    /// With g = gcd(n, p - 1), a nonzero x has an n-th root exactly when
+    /// x^((p - 1) / g) = 1. A g-th root y is found first and then raised to
+    /// e = (n / g)^-1 mod (p - 1) / g, so that (y^e)^n = y^g = x. When g = 1
+    /// this is just x^(n^-1 mod p - 1), the unique root; e.g. `nth_root(7)`
+    /// inverts the x^7 S-box.
+    ///
+    /// The g-th root is taken in the style of Adleman-Manders-Miller: the
+    /// odd part of g is coprime to the order of x's subgroup and is undone by
+    /// an exponent, and the 2-power part is fixed up by a discrete logarithm in
+    /// the 2^32-order subgroup, as in Tonelli-Shanks. `nth_root(0)` is one for
+    /// one and `None` otherwise.
+    pub fn nth_root(&self, n: u64) -> Option<Self> {
+        const ORDER: u64 = MODULUS - 1;
+        if n == 0 {
+            return (*self == Self::ONE).then_some(Self::ONE);
+        }
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        let g = gcd(n, ORDER);
+        if self.pow_u64(ORDER / g) != Self::ONE {
+            return None;
+        }
+
+        // Odd part: x lies in the subgroup of order (p - 1) / g, which shares
+        // no odd factor with g, so y = x^(g_odd^-1) is a g_odd-th root and,
+        // being a power of x, still a 2^a-th power.
+        let two_power = g & g.wrapping_neg();
+        let g_odd = g / two_power;
+        let mut y = self.pow_u64(inverse_mod(g_odd, ORDER / g_odd));
+
+        if two_power > 1 {
+            // z0 = y^(2^-a mod q) with q = 2^32 - 1 satisfies z0^(2^a) = y * t
+            // for some t in the 2^32-order subgroup; t = w^l with 2^a | l, and
+            // w^(-l / 2^a) cancels it.
+            const Q: u64 = MULTIPLICATIVE_ODD_ORDER;
+            let z0 = y.pow_u64(inverse_mod(two_power % Q, Q));
+            let mut t = z0;
+            for _ in 0..two_power.trailing_zeros() {
+                t = t.square();
+            }
+            let t = t * Self::checked_div(&Self::ONE, &y).ok()?;
+            let l = Self::two_adic_log(t)?;
+            if l % two_power != 0 {
+                return None;
+            }
+            let correction = TWO_ADIC_ROOT_OF_UNITY.pow_u64((1 << 32) - l / two_power);
+            y = z0 * correction;
+        }
+
+        Some(y.pow_u64(inverse_mod(n / g, ORDER / g)))
+    }
+
+    /// For t in the order-2^32 subgroup, the l in [0, 2^32) with
+    /// TWO_ADIC_ROOT_OF_UNITY^l = t, recovered one bit at a time.
+    fn two_adic_log(t: Self) -> Option<u64> {
+        let w_inv = Self::checked_div(&Self::ONE, &TWO_ADIC_ROOT_OF_UNITY).ok()?;
+        let mut rest = t;
+        let mut w_inv_pow = w_inv;
+        let mut l = 0;
+        for i in 0..32 {
+            let mut probe = rest;
+            for _ in 0..31 - i {
+                probe = probe.square();
+            }
+            if probe != Self::ONE {
+                l |= 1 << i;
+                rest *= w_inv_pow;
+            }
+            w_inv_pow = w_inv_pow.square();
+        }
+        (rest == Self::ONE).then_some(l)
+    }
+
+    /// Returns 1 for nonzero squares, -1 for non-squares and 0 for zero.
+    ///
+    /// Evaluates Euler's criterion a^((p - 1) / 2) with
+    /// (p - 1) / 2 = (2^32 - 1) * 2^31: a run of 32 ones followed by 31
+    /// squarings.
+    pub fn legendre_symbol(&self) -> i8 {
+        let mut t = self.pow_2_32_minus_1();
+        for _ in 0..31 {
+            t = t.square();
+        }
+        if t == Self::ZERO {
+            0
+        } else if t == Self::ONE {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Whether the element is a square, counting zero as one.
+    pub fn is_quadratic_residue(&self) -> bool {
+        self.legendre_symbol() >= 0
+    }
+
+    /// self^(2^32 - 1) by doubling runs of ones: 31 squarings, 5 multiplications.
+    fn pow_2_32_minus_1(&self) -> Self {
+        let mut run = *self;
+        for k in [1, 2, 4, 8, 16] {
+            let mut shifted = run;
+            for _ in 0..k {
+                shifted = shifted.square();
+            }
+            run = shifted * run;
+        }
+        run
+    }
+
+
+    /// Whether the element generates the whole multiplicative group, i.e.
+    /// x^((p - 1) / q) != 1 for every prime q dividing p - 1.
+    pub fn is_primitive_root(&self) -> bool {
+        *self != Self::ZERO
+            && GROUP_ORDER_FACTORS
+                .iter()
+                .all(|(prime, _)| self.pow_u64((MODULUS - 1) / prime) != Self::ONE)
+    }
+
+    /// Returns the multiplicative generator 7.
+    pub fn generator() -> GoldilocksField {
+        Self::MULTIPLICATIVE_GENERATOR
+    }
+}
+
+impl IsField for GoldilocksField {
+    type BaseType = u64;
+
+    fn add(a: &u64, b: &u64) -> u64 {
+        debug_assert!(*a < MODULUS && *b < MODULUS, "non-canonical operand");
+        // For canonical inputs a + b < 2p. On overflow the true sum is
+        // 2^64 + sum = sum + EPSILON, which is already below p.
+        let (sum, over) = a.overflowing_add(*b);
+        if over {
+            sum + EPSILON
+        } else if sum >= MODULUS {
+            sum - MODULUS
+        } else {
+            sum
+        }
+    }
+
+    fn sub(a: &u64, b: &u64) -> u64 {
+        debug_assert!(*a < MODULUS && *b < MODULUS, "non-canonical operand");
+        // On borrow, diff = a - b + 2^64; wrapping in MODULUS yields a - b + p.
+        let (diff, borrow) = a.overflowing_sub(*b);
+        diff.wrapping_add(MODULUS * borrow as u64)
+    }
+
+    fn neg(a: &u64) -> u64 {
+        Self::sub(&0, a)
+    }
+
+    fn mul(a: &u64, b: &u64) -> u64 {
+        debug_assert!(*a < MODULUS && *b < MODULUS, "non-canonical operand");
+        backend::mul(*a, *b)
+    }
+
+    // Overridden so `pow` and `inv` square through a single widening
+    // multiply of one operand instead of the generic two-operand path.
+    fn square(a: &u64) -> u64 {
+        backend::mul(*a, *a)
+    }
+
+    fn div(a: &u64, b: &u64) -> u64 {
+        match Self::inv(b) {
+            Ok(inverse) => Self::mul(a, &inverse),
+            Err(_) => panic!("GoldilocksField::div: divisor is zero"),
+        }
+    }
+
+    fn inv(a: &u64) -> Result<u64, FieldError> {
+        Self::inv_gcd(a)
+    }
+
+    fn eq(a: &u64, b: &u64) -> bool {
+        Self::from_u64(*a) == Self::from_u64(*b)
+    }
+
+    fn zero() -> u64 {
+        0
+    }
+
+    fn one() -> u64 {
+        backend::ONE
+    }
+
+    fn from_u64(x: u64) -> u64 {
+        backend::to_repr(x)
+    }
+
+    fn from_base_type(x: u64) -> u64 {
+        Self::from_u64(x)
+    }
+
+}
+
+impl IsPrimeField for GoldilocksField {
+    type RepresentativeType = u64;
+
+    fn representative(a: &Self::BaseType) -> Self::RepresentativeType {
+        backend::from_repr(*a)
+    }
+
+    fn field_bit_size() -> usize {
+        ((MODULUS - 1).ilog2() + 1) as usize
+    }
+
+    fn to_hex(a: &u64) -> String {
+        to_hex(a)
+    }
+
+    /// Parses a hex string of any length, with an optional `0x` prefix, and
+    /// reduces the value modulo p.
+    fn from_hex(hex_string: &str) -> Result<Self::BaseType, CreationError> {
+        let hex_string = hex_string.strip_prefix("0x").unwrap_or(hex_string);
+        if hex_string.is_empty() || !hex_string.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(CreationError::InvalidHexString);
+        }
+
+        // Fold 16-nibble chunks from the most significant end: acc * 2^64 + chunk.
+        let first_chunk_len = match hex_string.len() % 16 {
+            0 => 16,
+            len => len,
+        };
+        let (head, tail) = hex_string.as_bytes().split_at(first_chunk_len);
+        let mut acc = canonicalize(parse_hex_chunk(head));
+        for chunk in tail.chunks(16) {
+            acc = reduce_u128(((acc as u128) << 64) | parse_hex_chunk(chunk) as u128);
+        }
+        Ok(backend::to_repr(acc))
+    }
+
+}
+
+impl IsFFTField for GoldilocksField {
+    const TWO_ADICITY: u64 = TWO_ADICITY as u64;
+    // Canonical value: `FieldElement::new` converts it with `from_base_type`.
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: u64 = 0x1856_29dc_da58_878c;
+
+    fn field_name() -> &'static str {
+        "goldilocks64"
+    }
+}
+
+impl ByteConversion for GoldilocksField {
+
+    //#[cfg(feature = "std")]
+    fn to_bytes_be(&self) -> Vec<u8> {
+        u64::to_be_bytes(self.to_canonical_u64()).into()
+    }
+
+    //#[cfg(feature = "std")]
+    fn to_bytes_le(&self) -> Vec<u8> {
+        u64::to_le_bytes(self.to_canonical_u64()).into()
+    }
+
+    /// Reads the first 8 bytes; inputs shorter than 8 bytes are an error and
+    /// any trailing bytes are ignored. Values >= p are reduced, see
+    /// `from_bytes_be_canonical` for the strict variant.
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 8] = first_8_bytes(bytes).ok_or(ByteConversionError::FromBEBytesError)?;
+        Ok(Self::new(u64::from_be_bytes(bytes)))
+    }
+
+    /// Reads the first 8 bytes; inputs shorter than 8 bytes are an error and
+    /// any trailing bytes are ignored. Values >= p are reduced, see
+    /// `from_bytes_le_canonical` for the strict variant.
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 8] = first_8_bytes(bytes).ok_or(ByteConversionError::FromLEBytesError)?;
+        Ok(Self::new(u64::from_le_bytes(bytes)))
+    }
+    
+
+    
+}
+
+/// Parses at most 16 already validated hex digits.
+fn parse_hex_chunk(chunk: &[u8]) -> u64 {
+    chunk
+        .iter()
+        .fold(0, |acc, &b| (acc << 4) | (b as char).to_digit(16).unwrap() as u64)
+}
+
+fn first_8_bytes(bytes: &[u8]) -> Option<[u8; 8]> {
+    bytes.get(..8)?.try_into().ok()
+}
+
+impl Deserializable for GoldilocksField {
+    /// Accepts exactly the `as_bytes` encodings: inputs that are not
+    /// `serialized_size()` bytes long fail with `InvalidAmountOfBytes`, and
+    /// values >= p with `InvalidValue`.
+    fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>
+        where
+            Self: Sized {
+                if bytes.len() != Self::serialized_size() {
+                    return Err(DeserializationError::InvalidAmountOfBytes);
+                }
+                Self::from_bytes_le_canonical(bytes).map_err(|x| x.into())
+    }
+}
+
+/// The canonical value as 8 little-endian bytes, the same bytes as
+/// `to_bytes_le` and the encoding used by plonky2, winterfell and miden.
+///
+/// Changed in 0.2.0: 0.1 wrote big-endian bytes, which `serialize_be` still
+/// produces and `deserialize_be` accepts.
+///
+/// lambdaworks-crypto's Merkle backends and transcripts bound
+/// `FieldElement<F>: AsBytes` instead, which the orphan rule leaves to
+/// lambdaworks to implement, so they do not accept this field.
+impl AsBytes for GoldilocksField {
+    fn as_bytes(&self) -> Vec<u8> {
+        self.to_bytes_le()
+    }
+}
+
+macro_rules! impl_from_small_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for GoldilocksField {
+                fn from(x: $t) -> Self {
+                    Self::from_canonical_u32(x.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_unsigned!(u8, u16, u32, bool);
+
+impl From<usize> for GoldilocksField {
+    fn from(x: usize) -> Self {
+        // Reduces correctly even where usize is wider than 64 bits.
+        Self::from_u128(x as u128)
+    }
+}
+
+/// Big-endian, matching `from_bytes_be`.
+impl From<[u8; 8]> for GoldilocksField {
+    fn from(bytes: [u8; 8]) -> Self {
+        Self::new(u64::from_be_bytes(bytes))
+    }
+}
+
+/// Big-endian; unlike `from_bytes_be`, the slice must be exactly 8 bytes long.
+impl TryFrom<&[u8]> for GoldilocksField {
+    type Error = ByteConversionError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 8] = bytes.try_into().map_err(|_| ByteConversionError::FromBEBytesError)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+/// Big-endian, matching `to_bytes_be`.
+impl From<GoldilocksField> for [u8; 8] {
+    fn from(element: GoldilocksField) -> Self {
+        element.to_canonical_u64().to_be_bytes()
+    }
+}
+
+impl From<i64> for GoldilocksField {
+    fn from(x: i64) -> Self {
+        Self::from_i64(x)
+    }
+}
+
+impl From<u128> for GoldilocksField {
+    fn from(x: u128) -> Self {
+        Self::from_u128(x)
+    }
+}
+
+/// Orders elements by canonical value, independent of the representation.
+impl PartialOrd for GoldilocksField {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GoldilocksField {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_canonical_u64().cmp(&other.to_canonical_u64())
+    }
+}
+
+impl core::fmt::Display for GoldilocksField {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.to_canonical_u64(), f)
+    }
+}
+
+impl core::fmt::LowerHex for GoldilocksField {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerHex::fmt(&self.to_canonical_u64(), f)
+    }
+}
+
+impl core::fmt::UpperHex for GoldilocksField {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::UpperHex::fmt(&self.to_canonical_u64(), f)
+    }
+}
+
+/// Parses a decimal string of any length, reducing it modulo p. Surrounding
+/// whitespace and a leading `+` are accepted, and `0x`-prefixed strings are
+/// parsed as hex.
+impl core::str::FromStr for GoldilocksField {
+    type Err = ParseFieldElementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix('+').unwrap_or(s);
+        if s.is_empty() {
+            return Err(ParseFieldElementError::Empty);
+        }
+        if s.starts_with("0x") {
+            return Self::from_hex(s)
+                .map(|value| GoldilocksField { value })
+                .map_err(|_| ParseFieldElementError::InvalidDigit);
+        }
+
+        let mut acc = 0u64;
+        for b in s.bytes() {
+            if !b.is_ascii_digit() {
+                return Err(ParseFieldElementError::InvalidDigit);
+            }
+            acc = reduce_u128(acc as u128 * 10 + (b - b'0') as u128);
+        }
+        Ok(Self::new(acc))
+    }
+}
+
+// The operator traits are implemented by path rather than imported, so that
+// `GoldilocksField::add` and friends keep resolving to the `IsField` functions.
+impl From<u64> for GoldilocksField {
+    fn from(a: u64) -> Self {
+        Self::new(a)
+    }
+}
+
+impl core::ops::Add for GoldilocksField {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        GoldilocksField { value: <Self as IsField>::add(&self.value, &rhs.value) }
+    }
+}
+
+impl core::ops::Sub for GoldilocksField {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        GoldilocksField { value: <Self as IsField>::sub(&self.value, &rhs.value) }
+    }
+}
+
+impl core::ops::Mul for GoldilocksField {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        GoldilocksField { value: <Self as IsField>::mul(&self.value, &rhs.value) }
+    }
+}
+
+impl core::ops::Div for GoldilocksField {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        GoldilocksField { value: <Self as IsField>::div(&self.value, &rhs.value) }
+    }
+}
+
+impl core::ops::Neg for GoldilocksField {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        GoldilocksField { value: <Self as IsField>::neg(&self.value) }
+    }
+}
+
+// By-reference and compound-assignment forms of the binary operators, all
+// forwarding to the by-value impls above.
+macro_rules! impl_ref_and_assign_ops {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident;)*) => {
+        $(
+            impl core::ops::$trait<&GoldilocksField> for GoldilocksField {
+                type Output = Self;
+
+                fn $method(self, rhs: &Self) -> Self {
+                    core::ops::$trait::$method(self, *rhs)
+                }
+            }
+
+            impl core::ops::$assign_trait for GoldilocksField {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = core::ops::$trait::$method(*self, rhs);
+                }
+            }
+
+            impl core::ops::$assign_trait<&GoldilocksField> for GoldilocksField {
+                fn $assign_method(&mut self, rhs: &Self) {
+                    *self = core::ops::$trait::$method(*self, *rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_ref_and_assign_ops! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+}
+
+impl core::iter::Sum for GoldilocksField {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a GoldilocksField> for GoldilocksField {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl core::iter::Product for GoldilocksField {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a> core::iter::Product<&'a GoldilocksField> for GoldilocksField {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl Default for GoldilocksField {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;  
+    use crate::test_utils::{random_elements, SplitMix64};
+    const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+    // The arithmetic helpers take and return canonical values, so the same
+    // vectors check every backend's internal representation.
+    fn element(value: u64) -> GoldilocksField {
+        GoldilocksField::from_canonical(value)
+    }
+
+    fn add_values(a: u64, b: u64) -> u64 {
+        (element(a) + element(b)).to_canonical_u64()
+    }
+
+    fn sub_values(a: u64, b: u64) -> u64 {
+        (element(a) - element(b)).to_canonical_u64()
+    }
+
+    fn neg_value(a: u64) -> u64 {
+        (-element(a)).to_canonical_u64()
+    }
+
+    fn mul_values(a: u64, b: u64) -> u64 {
+        (element(a) * element(b)).to_canonical_u64()
+    }
+
+    fn inv_value(a: u64) -> Result<u64, FieldError> {
+        GoldilocksField::checked_div(&GoldilocksField::ONE, &element(a)).map(|inverse| inverse.to_canonical_u64())
+    }
+
+    fn from_hex_value(hex: &str) -> Result<u64, CreationError> {
+        GoldilocksField::from_hex(hex).map(|value| GoldilocksField::representative(&value))
+    }
+
+    fn mul_reference(a: u64, b: u64) -> u64 {
+        ((a as u128 * b as u128) % MODULUS as u128) as u64
+    }
+
+
+    #[test]
+    fn get_generator() {
+        assert_eq!(GoldilocksField::generator(), GoldilocksField::new(7));
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(add_values(7, 10), 17);
+    }
+
+    #[test]
+    fn max_order_plus_1_is_0() {
+        assert_eq!(add_values(MODULUS - 1, 1), 0);
+    }
+
+    #[test]
+    fn add_overflowing_operands() {
+        let a = MODULUS - 1;
+        assert_eq!(add_values(a, a), MODULUS - 2);
+        assert_eq!(add_values(a, MODULUS - 2), MODULUS - 3);
+        assert_eq!(add_values(1 << 63, 1 << 63), EPSILON);
+    }
+
+    #[test]
+    fn add_matches_u128_reference() {
+        let mut rng = SplitMix64(2);
+        for _ in 0..10_000 {
+            let a = rng.next_element();
+            let b = rng.next_element();
+            let expected = ((a as u128 + b as u128) % MODULUS as u128) as u64;
+            assert_eq!(add_values(a, b), expected, "{a} + {b}");
+        }
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(sub_values(5, 3), 2);
+    }
+
+    #[test]
+    fn sub_borrowing_operands() {
+        assert_eq!(sub_values(MODULUS - 1, 1), MODULUS - 2);
+        assert_eq!(sub_values(0, MODULUS - 1), 1);
+        assert_eq!(sub_values(MODULUS - 1, MODULUS - 1), 0);
+    }
+
+    #[test]
+    fn sub_matches_i128_reference() {
+        let mut rng = SplitMix64(3);
+        for _ in 0..10_000 {
+            let a = rng.next_element();
+            let b = rng.next_element();
+            let expected = (a as i128 - b as i128).rem_euclid(MODULUS as i128) as u64;
+            assert_eq!(sub_values(a, b), expected, "{a} - {b}");
+        }
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(neg_value(5), MODULUS - 5);
+        assert_eq!(add_values(5, neg_value(5)), 0);
+    }
+
+    #[test]
+    fn neg_0_is_0() {
+        assert_eq!(neg_value(0), 0);
+        assert_eq!(neg_value(MODULUS - 1), 1);
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(mul_values(5, 3), 15);
+    }
+
+    #[test]
+    fn mul_order_minus_1() {
+        assert_eq!(mul_values(MODULUS - 1, MODULUS - 1), 1);
+    }
+
+    #[test]
+    fn mul_large_operands() {
+        let a = MODULUS - 2;
+        let b = MODULUS - 3;
+        assert_eq!(mul_values(a, b), 6);
+        assert_eq!(mul_values(a, b), mul_reference(a, b));
+    }
+
+    #[test]
+    fn mul_2_32_times_2_32() {
+        let a = 1 << 32;
+        // 2^64 = 2^32 - 1 (mod p)
+        assert_eq!(mul_values(a, a), EPSILON);
+    }
+
+    #[test]
+    fn mul_edge_operands() {
+        let edges = [0, 1, 2, EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1];
+        for a in edges {
+            for b in edges {
+                assert_eq!(mul_values(a, b), mul_reference(a, b), "{a} * {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn mul_matches_u128_reference() {
+        let mut rng = SplitMix64(1);
+        for _ in 0..10_000 {
+            let a = rng.next_element();
+            let b = rng.next_element();
+            assert_eq!(mul_values(a, b), mul_reference(a, b), "{a} * {b}");
+        }
+    }
+
+    #[test]
+    fn inv_0_error() {
+        assert!(matches!(inv_value(0), Err(FieldError::InvZeroError)));
+    }
+
+    #[test]
+    fn inv_2() {
+        let inverse = inv_value(2).unwrap();
+        assert_eq!(inverse, 0x7fff_ffff_8000_0001);
+        assert_eq!(mul_values(2, inverse), 1);
+    }
+
+    #[test]
+    fn inv_gcd_rejects_zero() {
+        assert!(matches!(GoldilocksField::inv_gcd(&0), Err(FieldError::InvZeroError)));
+        assert!(matches!(GoldilocksField::inv_fermat(&0), Err(FieldError::InvZeroError)));
+    }
+
+    #[test]
+    fn inv_gcd_agrees_with_fermat() {
+        let mut rng = SplitMix64(19);
+        let small = (1..=4096).map(GoldilocksField::from_u64);
+        let edges = [EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1].map(GoldilocksField::from_u64);
+        let samples: Vec<u64> = (0..2000).map(|_| GoldilocksField::from_u64(rng.next_element())).collect();
+        for x in small.chain(edges).chain(samples) {
+            let inverse = GoldilocksField::inv_gcd(&x).unwrap();
+            assert_eq!(inverse, GoldilocksField::inv_fermat(&x).unwrap(), "{x}");
+            assert_eq!(GoldilocksField::mul(&x, &inverse), GoldilocksField::one(), "{x}");
+        }
+    }
+
+    #[test]
+    fn inv_addchain_matches_generic_pow() {
+        let mut rng = SplitMix64(20);
+        assert!(matches!(GoldilocksField::inv_addchain(&0), Err(FieldError::InvZeroError)));
+        let edges = [1, 2, EPSILON, 1 << 63, MODULUS - 1].map(GoldilocksField::from_u64);
+        let samples: Vec<u64> = (0..2000).map(|_| GoldilocksField::from_u64(rng.next_element())).collect();
+        for x in edges.into_iter().chain(samples) {
+            let expected = <GoldilocksField as IsField>::pow(&x, MODULUS - 2);
+            assert_eq!(GoldilocksField::inv_addchain(&x).unwrap(), expected, "{x}");
+        }
+    }
+
+    #[test]
+    fn p_minus_2_chain_operation_count() {
+        use std::cell::Cell;
+        // Run the chain on exponents: squaring doubles, multiplying adds.
+        let (squarings, multiplications) = (Cell::new(0), Cell::new(0));
+        let exponent = p_minus_2_chain(
+            1u128,
+            |e| {
+                squarings.set(squarings.get() + 1);
+                2 * e
+            },
+            |e, f| {
+                multiplications.set(multiplications.get() + 1);
+                e + f
+            },
+        );
+        assert_eq!(exponent, (MODULUS - 2) as u128);
+        assert_eq!((squarings.get(), multiplications.get()), (64, 9));
+    }
+
+    #[test]
+    fn batch_inverse_matches_inv() {
+        let mut rng = SplitMix64(21);
+        for len in [1, 2, 3, 100] {
+            let values: Vec<GoldilocksField> =
+                (0..len).map(|_| GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1)).collect();
+            let inverses = batch_inverse(&values).unwrap();
+            for (x, inverse) in values.iter().zip(&inverses) {
+                assert_eq!(inverse.value, GoldilocksField::inv(&x.value).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn batch_inverse_of_empty_slice() {
+        assert_eq!(batch_inverse(&[]).ok(), Some(vec![]));
+        assert!(batch_inverse_allowing_zeros(&[]).is_empty());
+    }
+
+    #[test]
+    fn batch_inverse_with_zeros() {
+        let values = [3u64, 0, 5, 0, 7].map(GoldilocksField::new);
+        assert!(matches!(batch_inverse(&values), Err(FieldError::InvZeroError)));
+        assert!(matches!(batch_inverse(&[GoldilocksField::ZERO]), Err(FieldError::InvZeroError)));
+
+        let inverses = batch_inverse_allowing_zeros(&values);
+        for (x, inverse) in values.iter().zip(&inverses) {
+            if *x == GoldilocksField::ZERO {
+                assert_eq!(*inverse, GoldilocksField::ZERO);
+            } else {
+                assert_eq!(*x * *inverse, GoldilocksField::ONE);
+            }
+        }
+        assert_eq!(batch_inverse_allowing_zeros(&[GoldilocksField::ZERO; 3]), vec![GoldilocksField::ZERO; 3]);
+    }
+
+    #[test]
+    fn inv_gcd_of_self_inverse_elements() {
+        let one = GoldilocksField::ONE.value;
+        let neg_one = GoldilocksField::NEG_ONE.value;
+        assert_eq!(GoldilocksField::inv_gcd(&one).unwrap(), one);
+        assert_eq!(GoldilocksField::inv_gcd(&neg_one).unwrap(), neg_one);
+    }
+
+
+    #[test]
+    fn pow_2_3() {
+        assert_eq!(GoldilocksField::new(2).pow(3u32), GoldilocksField::new(8))
+    }
+
+    #[test]
+    fn pow_p_minus_1() {
+        assert_eq!(GoldilocksField::new(2).pow(MODULUS - 1), GoldilocksField::new(1))
+    }
+
+    #[test]
+    fn pow_uses_the_full_exponent_width() {
+        // (p - 1) as u32 is 0; the full exponent must not be truncated.
+        let two = GoldilocksField::new(2);
+        assert_eq!(two.pow(MODULUS - 2), GoldilocksField::ONE / two);
+        assert_eq!(two.pow(1u64 << 32), two.pow_u64(1 << 32));
+        assert_eq!(two.pow(96u16), GoldilocksField::NEG_ONE);
+        assert_eq!(two.pow(1u128 << 64), two.pow_u64(EPSILON + 1));
+    }
+
+    /// Little-endian limbs reduced modulo p - 1, the order of the group.
+    fn limbs_mod_group_order(limbs: &[u64]) -> u64 {
+        let order = (MODULUS - 1) as u128;
+        limbs.iter().rev().fold(0u128, |acc, &limb| ((acc << 64) | limb as u128) % order) as u64
+    }
+
+    #[test]
+    fn pow_limbs_matches_reduced_exponent() {
+        let mut rng = SplitMix64(34);
+        for _ in 0..100 {
+            let x = GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1);
+            let exponent = [rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64()];
+            let expected = x.pow_u64(limbs_mod_group_order(&exponent));
+            assert_eq!(x.pow_limbs(&exponent), expected);
+
+            let mut big_endian = exponent;
+            big_endian.reverse();
+            assert_eq!(x.pow_unsigned_integer(&UnsignedInteger::from_limbs(big_endian)), expected);
+        }
+    }
+
+    #[test]
+    fn pow_limbs_edge_exponents() {
+        let x = GoldilocksField::new(0x1234_5678_9abc_def0);
+        assert_eq!(x.pow_limbs(&[]), GoldilocksField::ONE);
+        assert_eq!(x.pow_limbs(&[0, 0, 0, 0]), GoldilocksField::ONE);
+        assert_eq!(GoldilocksField::ZERO.pow_limbs(&[0]), GoldilocksField::ONE);
+        assert_eq!(GoldilocksField::ZERO.pow_limbs(&[0, 1]), GoldilocksField::ZERO);
+        for e in [1, 2, 7, MODULUS - 2, u64::MAX] {
+            assert_eq!(x.pow_limbs(&[e]), x.pow_u64(e), "{e}");
+            assert_eq!(x.pow_limbs(&[e, 0, 0]), x.pow_u64(e), "{e}");
+        }
+    }
+
+    #[test]
+    fn exp_power_of_2_matches_pow_limbs() {
+        let mut rng = SplitMix64(35);
+        let x = GoldilocksField::new(rng.next_element());
+        assert_eq!(x.exp_power_of_2(0), x);
+        for k in 0..=100 {
+            let mut limbs = [0u64; 2];
+            limbs[k as usize / 64] = 1 << (k % 64);
+            assert_eq!(x.exp_power_of_2(k), x.pow_limbs(&limbs), "k = {k}");
+        }
+        assert_eq!(GoldilocksField::TWO.exp_power_of_2(5), GoldilocksField::new(1 << 32));
+    }
+
+    #[test]
+    fn pow_i64_of_zero_base() {
+        assert_eq!(GoldilocksField::ZERO.pow_i64(0).ok(), Some(GoldilocksField::ONE));
+        assert_eq!(GoldilocksField::ZERO.pow_i64(3).ok(), Some(GoldilocksField::ZERO));
+        assert!(matches!(GoldilocksField::ZERO.pow_i64(-1), Err(FieldError::InvZeroError)));
+        assert!(matches!(GoldilocksField::ZERO.pow_i64(i64::MIN), Err(FieldError::InvZeroError)));
+    }
+
+    #[test]
+    fn pow_i64_negative_exponents_invert() {
+        let mut rng = SplitMix64(36);
+        for _ in 0..200 {
+            let x = GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1);
+            let k = (rng.next_u64() >> 1) as i64;
+            assert_eq!(x.pow_i64(k).unwrap() * x.pow_i64(-k).unwrap(), GoldilocksField::ONE, "{x}^{k}");
+            assert_eq!(x.pow_i64(-1).ok(), Some(GoldilocksField::ONE / x));
+        }
+        let two = GoldilocksField::TWO;
+        // 2^-(2^63) = (2^(2^63))^-1.
+        assert_eq!(two.pow_i64(i64::MIN).ok(), Some(GoldilocksField::ONE / two.pow_u64(1 << 63)));
+    }
+
+    #[test]
+    fn fermats_little_theorem_with_64_bit_exponent() {
+        let mut rng = SplitMix64(33);
+        for _ in 0..200 {
+            let x = GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1);
+            assert_eq!(x.pow_u64(MODULUS - 1), GoldilocksField::ONE, "{x}");
+            assert_eq!(x.pow(MODULUS), x, "{x}");
+        }
+    }
+
+    #[test]
+    fn checked_div_by_zero_errors() {
+        for x in [0, 1, MODULUS - 1].map(GoldilocksField::new) {
+            assert!(matches!(GoldilocksField::checked_div(&x, &GoldilocksField::ZERO), Err(FieldError::InvZeroError)));
+        }
+    }
+
+    #[test]
+    fn checked_div_agrees_with_mul_by_inverse() {
+        let mut rng = SplitMix64(22);
+        for _ in 0..1000 {
+            let a = GoldilocksField::new(rng.next_element());
+            let b = GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1);
+            let inverse = GoldilocksField { value: GoldilocksField::inv(&b.value).unwrap() };
+            assert_eq!(GoldilocksField::checked_div(&a, &b).ok(), Some(a * inverse));
+            assert_eq!(GoldilocksField::checked_div(&GoldilocksField::ZERO, &b).ok(), Some(GoldilocksField::ZERO));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor is zero")]
+    fn div_by_zero_panics_with_context() {
+        let _ = GoldilocksField::ONE / GoldilocksField::ZERO;
+    }
+
+    #[test]
+    fn sqrt_of_zero_and_one() {
+        assert_eq!(GoldilocksField::ZERO.sqrt(), Some(GoldilocksField::ZERO));
+        assert_eq!(GoldilocksField::ONE.sqrt(), Some(GoldilocksField::ONE));
+    }
+
+    #[test]
+    fn sqrt_of_squares_is_plus_or_minus_x() {
+        let mut rng = SplitMix64(23);
+        for _ in 0..1000 {
+            let x = GoldilocksField::new(rng.next_element());
+            let root = x.square().sqrt().unwrap();
+            assert!(root == x || root == -x, "{x}");
+            assert!(root.to_canonical_u64() <= (-root).to_canonical_u64());
+        }
+    }
+
+    #[test]
+    fn sqrt_of_non_residues_is_none() {
+        assert_eq!(GoldilocksField::GENERATOR.sqrt(), None);
+        assert_eq!(GoldilocksField::new(1234567).sqrt(), None);
+        assert_eq!(GoldilocksField::new(0x8000_0000_0000_3039).sqrt(), None);
+    }
+
+    #[test]
+    fn sqrt_matches_big_integer_reference() {
+        // Smaller roots computed with arbitrary-precision Tonelli-Shanks.
+        let cases = [
+            (2, 0xff_feff_ff00),
+            (3, 0xffff_fffe_0000),
+            (4, 2),
+            (5, 0x4302_db78_5697_f6b3),
+            (MODULUS - 1, 0x1_0000_0000_0000),
+            (0xdead_beef_cafe_babe, 0x2ddd_24fc_fbe5_5935),
+        ];
+        for (square, root) in cases {
+            assert_eq!(GoldilocksField::new(square).sqrt(), Some(GoldilocksField::new(root)), "{square}");
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_matches_sqrt_of_quotient() {
+        let mut rng = SplitMix64(24);
+        for _ in 0..500 {
+            let u = GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1);
+            let v = GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1);
+            let ratio = u / v;
+            let (is_square, root) = GoldilocksField::sqrt_ratio(&u, &v);
+            assert_eq!(is_square, ratio.legendre_symbol() == 1);
+            match ratio.sqrt() {
+                Some(expected) => assert_eq!((is_square, root), (true, expected)),
+                None => {
+                    assert!(!is_square);
+                    assert_eq!(Some(root), (ratio * GoldilocksField::GENERATOR).sqrt());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_with_zero_operands() {
+        let x = GoldilocksField::new(5);
+        assert_eq!(GoldilocksField::sqrt_ratio(&GoldilocksField::ZERO, &x), (true, GoldilocksField::ZERO));
+        assert_eq!(GoldilocksField::sqrt_ratio(&x, &GoldilocksField::ZERO), (false, GoldilocksField::ZERO));
+        assert_eq!(GoldilocksField::sqrt_ratio(&GoldilocksField::ZERO, &GoldilocksField::ZERO), (false, GoldilocksField::ZERO));
+    }
+
+    #[test]
+    fn legendre_symbol_matches_euler_criterion() {
+        for x in 0..4096u64 {
+            let value = GoldilocksField::from_u64(x);
+            let euler = GoldilocksField::representative(&<GoldilocksField as IsField>::pow(&value, (MODULUS - 1) / 2));
+            let expected = match euler {
+                0 => 0,
+                1 => 1,
+                _ => -1,
+            };
+            assert_eq!(GoldilocksField::new(x).legendre_symbol(), expected, "{x}");
+        }
+    }
+
+    #[test]
+    fn legendre_symbol_agrees_with_sqrt() {
+        let mut rng = SplitMix64(25);
+        for _ in 0..1000 {
+            let x = GoldilocksField::new(rng.next_element());
+            assert_eq!(x.is_quadratic_residue(), x.sqrt().is_some(), "{x}");
+        }
+        assert_eq!(GoldilocksField::GENERATOR.legendre_symbol(), -1);
+        assert_eq!(GoldilocksField::ZERO.legendre_symbol(), 0);
+        assert!(GoldilocksField::ZERO.is_quadratic_residue());
+    }
+
+    #[test]
+    fn nth_root_inverts_the_x7_sbox() {
+        let mut rng = SplitMix64(26);
+        for _ in 0..200 {
+            let x = GoldilocksField::new(rng.next_element());
+            assert_eq!(x.pow_u64(7).nth_root(7), Some(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn cube_roots_of_cubes() {
+        let mut rng = SplitMix64(27);
+        for _ in 0..200 {
+            let cube = GoldilocksField::new(rng.next_element()).pow_u64(3);
+            assert_eq!(cube.cube_root().map(|y| y.pow_u64(3)), Some(cube));
+        }
+        assert_eq!(GoldilocksField::GENERATOR.cube_root(), None);
+        assert_eq!(GoldilocksField::ZERO.cube_root(), Some(GoldilocksField::ZERO));
+    }
+
+    #[test]
+    fn nth_root_with_shared_factors() {
+        let mut rng = SplitMix64(28);
+        for n in [2, 4, 6, 1 << 20, 3 << 31, 1 << 32, 15 << 33, 65537 * 257, MODULUS - 1] {
+            for _ in 0..20 {
+                let power = GoldilocksField::new(rng.next_element()).pow_u64(n);
+                assert_eq!(power.nth_root(n).map(|y| y.pow_u64(n)), Some(power), "n = {n}");
+            }
+            assert_eq!(GoldilocksField::GENERATOR.nth_root(n), None, "n = {n}");
+        }
+        assert_eq!(GoldilocksField::ONE.nth_root(0), Some(GoldilocksField::ONE));
+        assert_eq!(GoldilocksField::TWO.nth_root(0), None);
+    }
+
+    #[test]
+    fn two_adic_root_of_unity_has_exact_order() {
+        assert_eq!(MULTIPLICATIVE_ODD_ORDER << TWO_ADICITY, MODULUS - 1);
+        assert_eq!(GoldilocksField::GENERATOR.pow_u64(MULTIPLICATIVE_ODD_ORDER), TWO_ADIC_ROOT_OF_UNITY);
+        let mut x = TWO_ADIC_ROOT_OF_UNITY;
+        for _ in 0..TWO_ADICITY - 1 {
+            x = x.square();
+        }
+        assert_eq!(x, GoldilocksField::NEG_ONE);
+        assert_eq!(x.square(), GoldilocksField::ONE);
+    }
+
+    #[test]
+    fn primitive_roots_of_unity_have_exact_order() {
+        for k in (0..=20).chain([31, 32]) {
+            let mut x = GoldilocksField::primitive_root_of_unity(k).unwrap();
+            for i in 0..k {
+                assert_ne!(x, GoldilocksField::ONE, "k = {k}, squared {i} times");
+                x = x.square();
+            }
+            assert_eq!(x, GoldilocksField::ONE, "k = {k}");
+        }
+        assert_eq!(GoldilocksField::primitive_root_of_unity(0).ok(), Some(GoldilocksField::ONE));
+        assert_eq!(GoldilocksField::primitive_root_of_unity(1).ok(), Some(GoldilocksField::NEG_ONE));
+        assert_eq!(GoldilocksField::primitive_root_of_unity(32).ok(), Some(TWO_ADIC_ROOT_OF_UNITY));
+    }
+
+    #[test]
+    fn primitive_root_of_unity_rejects_large_k() {
+        assert!(matches!(GoldilocksField::primitive_root_of_unity(33), Err(FieldError::RootOfUnityError(33))));
+    }
 
-impl GoldilocksField {
+    #[test]
+    fn multiplicative_order_of_known_elements() {
+        assert_eq!(GoldilocksField::ONE.multiplicative_order().ok(), Some(1));
+        assert_eq!(GoldilocksField::NEG_ONE.multiplicative_order().ok(), Some(2));
+        assert_eq!(GoldilocksField::GENERATOR.multiplicative_order().ok(), Some(MODULUS - 1));
+        assert_eq!(TWO_ADIC_ROOT_OF_UNITY.multiplicative_order().ok(), Some(1 << 32));
+        assert!(matches!(GoldilocksField::ZERO.multiplicative_order(), Err(FieldError::InvZeroError)));
+    }
 
-    fn from(a: u64) -> Self {
-        if a >= MODULUS {
-            GoldilocksField { value: a % MODULUS }
-        } else {
-            GoldilocksField { value: a }
+    #[test]
+    fn multiplicative_order_of_powers() {
+        let mut rng = SplitMix64(29);
+        for _ in 0..200 {
+            let x = GoldilocksField::new(rng.next_element() % (MODULUS - 1) + 1);
+            let order = x.multiplicative_order().unwrap();
+            assert_eq!(x.pow_u64(order), GoldilocksField::ONE);
+            let k = rng.next_u64() % (1 << 40);
+            assert_eq!(x.pow_u64(k).multiplicative_order().ok(), Some(order / gcd(k, order)), "{x}^{k}");
         }
     }
 
-    fn new(a: u64) -> u64 {
-        if a >= MODULUS {
-            a % MODULUS  // Take the value modulo MODULUS to keep it within the range
-        } else {
-            a
+    #[test]
+    fn discrete_log_to_the_generator() {
+        let mut rng = SplitMix64(30);
+        let g = GoldilocksField::GENERATOR;
+        for k in [0, 1, 2, MODULUS - 2].into_iter().chain((0..20).map(|_| rng.next_u64() % (MODULUS - 1))) {
+            assert_eq!(GoldilocksField::discrete_log(&g, &g.pow_u64(k)), Some(k), "{k}");
         }
     }
 
-    fn generator() -> u64 {
-        7
+    #[test]
+    fn discrete_log_in_smaller_subgroups() {
+        let mut rng = SplitMix64(31);
+        let g = GoldilocksField::GENERATOR;
+        for order in [2u64, 3, 65537, 1 << 32, 255 * 257, (1 << 20) * 17] {
+            let base = g.pow_u64((MODULUS - 1) / order);
+            for _ in 0..5 {
+                let k = rng.next_u64() % order;
+                assert_eq!(GoldilocksField::discrete_log(&base, &base.pow_u64(k)), Some(k), "order {order}, k {k}");
+            }
+        }
     }
-}
 
-impl IsField for GoldilocksField {
-    type BaseType = u64;
+    #[test]
+    fn discrete_log_outside_the_subgroup() {
+        let g = GoldilocksField::GENERATOR;
+        assert_eq!(GoldilocksField::discrete_log(&TWO_ADIC_ROOT_OF_UNITY, &g), None);
+        assert_eq!(GoldilocksField::discrete_log(&GoldilocksField::ONE, &GoldilocksField::TWO), None);
+        assert_eq!(GoldilocksField::discrete_log(&g, &GoldilocksField::ZERO), None);
+        assert_eq!(GoldilocksField::discrete_log(&GoldilocksField::ZERO, &g), None);
+        assert_eq!(GoldilocksField::discrete_log(&GoldilocksField::ONE, &GoldilocksField::ONE), Some(0));
+    }
 
-    fn add(a: &u64, b: &u64) -> u64 {
-        (*a + *b) % MODULUS
+    #[test]
+    fn is_primitive_root_accepts_the_generator() {
+        assert!(GoldilocksField::from(7u64).is_primitive_root());
+        assert!(GoldilocksField::MULTIPLICATIVE_GENERATOR.is_primitive_root());
+        assert_eq!(
+            GoldilocksField::MULTIPLICATIVE_GENERATOR.multiplicative_order().ok(),
+            Some(MODULUS - 1)
+        );
     }
 
-    fn sub(a: &u64, b: &u64) -> u64 {
-        (*a + MODULUS - *b) % MODULUS
+    #[test]
+    fn is_primitive_root_rejects_non_generators() {
+        let mut rng = SplitMix64(32);
+        let square = GoldilocksField::new(rng.next_element()).square();
+        for x in [GoldilocksField::ZERO, GoldilocksField::ONE, GoldilocksField::NEG_ONE, square, TWO_ADIC_ROOT_OF_UNITY] {
+            assert!(!x.is_primitive_root(), "{x}");
+        }
+        // 7^k generates the group exactly when gcd(k, p - 1) = 1.
+        assert!(!GoldilocksField::GENERATOR.pow_u64(3).is_primitive_root());
+        assert!(!GoldilocksField::GENERATOR.pow_u64(5).is_primitive_root());
+        assert!(GoldilocksField::GENERATOR.pow_u64(11).is_primitive_root());
     }
 
-    fn neg(a: &u64) -> u64 {
-        (MODULUS - *a) % MODULUS
+    #[test]
+    fn div_1() {
+        assert_eq!(GoldilocksField::new(2) / GoldilocksField::new(1), GoldilocksField::new(2))
     }
 
-    fn mul(a: &u64, b: &u64) -> u64 {
-        (*a * *b) % MODULUS
+    #[test]
+    fn div_4_2() {
+        assert_eq!(GoldilocksField::new(4) / GoldilocksField::new(2), GoldilocksField::new(2))
     }
 
-    fn div(a: &u64, b: &u64) -> u64 {
-        Self::mul(a, &Self::inv(b).unwrap())
+    #[test]
+    fn div_4_3() {
+        assert_eq!(GoldilocksField::new(4) / GoldilocksField::new(3) * GoldilocksField::new(3), GoldilocksField::new(4))
     }
 
-    fn inv(a: &u64) -> Result<u64, FieldError> {
-        if *a == 0 {
-            return Err(FieldError::InvZeroError);
+
+    #[test]
+    fn two_plus_its_additive_inv_is_0() {
+        let two = GoldilocksField::new(2);
+        assert_eq!((two - two), GoldilocksField::new(0))
+    }
+
+    #[test]
+    fn four_minus_three_is_1() {
+        let four = GoldilocksField::new(4);
+        let three = GoldilocksField::new(3);
+
+        assert_eq!(four - three, GoldilocksField::new(1))
+    }
+
+    #[test]
+    fn zero_minus_1_is_order_minus_1() {
+        let zero = GoldilocksField::new(0);
+        let one = GoldilocksField::new(1);
+
+        assert_eq!(zero - one, GoldilocksField::new(MODULUS - 1))
+    }
+
+
+    #[test]
+    fn zero_constructor_returns_zero() {
+        assert_eq!(GoldilocksField::new(0), GoldilocksField::new(0));
+    }
+
+    #[test]
+    fn new_reduces_modulus_to_zero() {
+        assert_eq!(GoldilocksField::new(MODULUS), GoldilocksField::new(0));
+        assert_eq!(GoldilocksField::new(u64::MAX), GoldilocksField::new(EPSILON - 1));
+    }
+
+    #[test]
+    fn new_is_usable_in_const_items() {
+        const X: GoldilocksField = GoldilocksField::new(MODULUS + 12345);
+        assert_eq!(X, GoldilocksField::new(12345));
+        assert_eq!(X, GoldilocksField::from(12345u64));
+    }
+
+    #[test]
+    fn from_bytes_rejects_short_inputs() {
+        for len in [0, 7] {
+            let bytes = vec![0xab; len];
+            assert_eq!(
+                GoldilocksField::from_bytes_be(&bytes),
+                Err(ByteConversionError::FromBEBytesError)
+            );
+            assert_eq!(
+                GoldilocksField::from_bytes_le(&bytes),
+                Err(ByteConversionError::FromLEBytesError)
+            );
+            assert!(GoldilocksField::deserialize(&bytes).is_err());
         }
-        Ok(Self::pow(a, MODULUS - 2))
     }
 
-    fn eq(a: &u64, b: &u64) -> bool {
-        Self::from_u64(*a) == Self::from_u64(*b)
+    #[test]
+    fn from_bytes_reads_exactly_8_bytes() {
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(
+            GoldilocksField::from_bytes_be(&bytes),
+            Ok(GoldilocksField::new(0x0102_0304_0506_0708))
+        );
+        assert_eq!(
+            GoldilocksField::from_bytes_le(&bytes),
+            Ok(GoldilocksField::new(0x0807_0605_0403_0201))
+        );
     }
 
-    fn zero() -> u64 {
-        0
+    #[test]
+    fn from_random_bytes_matches_u128_remainder() {
+        let mut rng = SplitMix64(82);
+        for _ in 0..10_000 {
+            let mut bytes = [0u8; 16];
+            bytes[..8].copy_from_slice(&rng.next_u64().to_le_bytes());
+            bytes[8..].copy_from_slice(&rng.next_u64().to_le_bytes());
+            let expected = (u128::from_le_bytes(bytes) % MODULUS as u128) as u64;
+            assert_eq!(GoldilocksField::from_random_bytes(&bytes).to_canonical_u64(), expected);
+            assert_eq!(GoldilocksField::from_random_bytes_slice(&bytes), Ok(GoldilocksField::new(expected)));
+
+            let short: [u8; 8] = bytes[..8].try_into().unwrap();
+            let expected = u64::from_le_bytes(short) % MODULUS;
+            assert_eq!(GoldilocksField::from_random_bytes_64(&short).to_canonical_u64(), expected);
+        }
+        // (2^128 - 1) mod p and (2^64 - 1) mod p.
+        assert_eq!(GoldilocksField::from_random_bytes(&[0xff; 16]).to_canonical_u64(), 0xffff_fffe_0000_0000);
+        assert_eq!(GoldilocksField::from_random_bytes_64(&[0xff; 8]).to_canonical_u64(), 0xffff_fffe);
     }
 
-    fn one() -> u64 {
-        1
+    #[test]
+    fn from_random_bytes_slice_rejects_other_lengths() {
+        for len in [0, 8, 15, 17, 32] {
+            assert_eq!(
+                GoldilocksField::from_random_bytes_slice(&vec![1; len]),
+                Err(ByteConversionError::FromLEBytesError),
+                "length {len}"
+            );
+        }
     }
 
-    fn from_u64(x: u64) -> u64 {
-        x % MODULUS
+    #[test]
+    fn from_bytes_ignores_trailing_bytes() {
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert_eq!(GoldilocksField::from_bytes_be(&bytes), GoldilocksField::from_bytes_be(&bytes[..8]));
+        assert_eq!(GoldilocksField::from_bytes_le(&bytes), GoldilocksField::from_bytes_le(&bytes[..8]));
     }
 
-    fn from_base_type(x: u64) -> u64 {
-        Self::from_u64(x)
+    #[test]
+    fn deserialize_requires_exactly_serialized_size_bytes() {
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        for deserialize in [GoldilocksField::deserialize, GoldilocksField::deserialize_be] {
+            assert_eq!(deserialize(&bytes[..7]), Err(DeserializationError::InvalidAmountOfBytes));
+            assert_eq!(deserialize(&bytes), Err(DeserializationError::InvalidAmountOfBytes));
+            assert_eq!(deserialize(&[]), Err(DeserializationError::InvalidAmountOfBytes));
+        }
+        assert_eq!(GoldilocksField::deserialize(&bytes[..8]), Ok(GoldilocksField::new(0x0807_0605_0403_0201)));
+        assert_eq!(GoldilocksField::deserialize_be(&bytes[..8]), Ok(GoldilocksField::new(0x0102_0304_0506_0708)));
+        assert_eq!(GoldilocksField::serialized_size(), GoldilocksField::ONE.as_bytes().len());
+        assert_eq!(GoldilocksField::serialized_size(), GoldilocksField::ONE.serialize_be().len());
     }
 
-}
+    #[test]
+    fn deserialize_rejects_non_canonical_values() {
+        for encoded in [MODULUS, MODULUS + 1, u64::MAX] {
+            assert_eq!(GoldilocksField::deserialize(&encoded.to_le_bytes()), Err(DeserializationError::InvalidValue));
+            assert_eq!(GoldilocksField::deserialize_be(&encoded.to_be_bytes()), Err(DeserializationError::InvalidValue));
+        }
+        let p_minus_1 = GoldilocksField::deserialize(&(MODULUS - 1).to_le_bytes()).unwrap();
+        assert_eq!(p_minus_1, -GoldilocksField::ONE);
+        assert_eq!(GoldilocksField::deserialize_be(&(MODULUS - 1).to_be_bytes()), Ok(p_minus_1));
+    }
 
-impl IsPrimeField for GoldilocksField {
-    type RepresentativeType = u64;
+    #[test]
+    fn serialization_byte_layouts_are_pinned() {
+        let x = GoldilocksField::new(0x0123_4567_89ab_cdef);
+        assert_eq!(x.as_bytes(), [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]);
+        assert_eq!(x.serialize_be(), [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+        assert_eq!((-GoldilocksField::ONE).as_bytes(), [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!((-GoldilocksField::ONE).serialize_be(), [0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+        assert_eq!(GoldilocksField::new(MODULUS).as_bytes(), [0; 8]);
+    }
 
-    fn representative(a: &Self::BaseType) -> Self::RepresentativeType {
-        *a
+    // plonky2's `Write::write_field` writes `x.to_canonical_u64().to_le_bytes()`,
+    // and winterfell's `Serializable for BaseElement` writes the same bytes.
+    #[test]
+    fn serialization_matches_plonky2() {
+        let mut rng = SplitMix64(93);
+        for x in [GoldilocksField::new(0xdead_beef_0bad_cafe)].into_iter().chain((0..100).map(|_| rng.next_field())) {
+            assert_eq!(x.as_bytes(), x.to_canonical_u64().to_le_bytes());
+            let mut reversed = x.serialize_be();
+            reversed.reverse();
+            assert_eq!(x.as_bytes(), reversed);
+        }
+        let plonky2_bytes = [0xfe, 0xca, 0xad, 0x0b, 0xef, 0xbe, 0xad, 0xde];
+        assert_eq!(GoldilocksField::deserialize(&plonky2_bytes), Ok(GoldilocksField::new(0xdead_beef_0bad_cafe)));
     }
 
-    fn field_bit_size() -> usize {
-        ((MODULUS - 1).ilog2() + 1) as usize
+    #[test]
+    fn as_bytes_is_the_little_endian_canonical_encoding() {
+        let mut rng = SplitMix64(94);
+        for x in [GoldilocksField::new(MODULUS - 1)].into_iter().chain((0..100).map(|_| rng.next_field())) {
+            assert_eq!(x.as_bytes(), x.to_bytes_le());
+        }
+        assert_eq!(GoldilocksField::new(0x0102).as_bytes(), [2, 1, 0, 0, 0, 0, 0, 0]);
     }
 
-    fn from_hex(hex_string: &str) -> Result<Self::BaseType, CreationError> {
-        let mut hex_string = hex_string;
-        if hex_string.len() > 2 && &hex_string[..2] == "0x" {
-            hex_string = &hex_string[2..];
+    #[test]
+    fn serialize_deserialize_round_trips() {
+        let mut rng = SplitMix64(90);
+        for x in [GoldilocksField::ZERO, GoldilocksField::ONE, -GoldilocksField::ONE]
+            .into_iter()
+            .chain((0..10_000).map(|_| rng.next_field()))
+        {
+            let bytes = x.as_bytes();
+            assert_eq!(bytes.len(), GoldilocksField::serialized_size());
+            assert_eq!(GoldilocksField::deserialize(&bytes), Ok(x));
+            assert_eq!(GoldilocksField::deserialize_be(&x.serialize_be()), Ok(x));
         }
+    }
+
+    #[test]
+    fn from_bytes_reduces_non_canonical_encodings() {
+        let cases = [(MODULUS, 0), (MODULUS + 1, 1), (u64::MAX, EPSILON - 1)];
+        for (encoded, expected) in cases {
+            let element = GoldilocksField::from_bytes_be(&encoded.to_be_bytes()).unwrap();
+            assert_eq!(element, GoldilocksField::new(expected));
+            assert_eq!(element.to_bytes_be(), expected.to_be_bytes());
 
-        u64::from_str_radix(hex_string, 16).map_err(|_| CreationError::InvalidHexString)
+            let element = GoldilocksField::from_bytes_le(&encoded.to_le_bytes()).unwrap();
+            assert_eq!(element, GoldilocksField::new(expected));
+            assert_eq!(element.to_bytes_le(), expected.to_le_bytes());
+        }
     }
 
-}
+    #[test]
+    fn from_bytes_canonical_rejects_non_canonical_encodings() {
+        for encoded in [MODULUS, MODULUS + 1, u64::MAX] {
+            assert_eq!(
+                GoldilocksField::from_bytes_be_canonical(&encoded.to_be_bytes()),
+                Err(ByteConversionError::InvalidValue)
+            );
+            assert_eq!(
+                GoldilocksField::from_bytes_le_canonical(&encoded.to_le_bytes()),
+                Err(ByteConversionError::InvalidValue)
+            );
+        }
+        assert_eq!(
+            GoldilocksField::from_bytes_be_canonical(&[0; 7]),
+            Err(ByteConversionError::FromBEBytesError)
+        );
+    }
 
-impl ByteConversion for GoldilocksField {
+    #[test]
+    fn from_bytes_canonical_round_trips() {
+        let mut rng = SplitMix64(6);
+        for value in [0, 1, MODULUS - 1].into_iter().chain((0..100).map(|_| rng.next_element())) {
+            let element = GoldilocksField::new(value);
+            assert_eq!(GoldilocksField::from_bytes_be_canonical(&element.to_bytes_be()), Ok(element));
+            assert_eq!(GoldilocksField::from_bytes_le_canonical(&element.to_bytes_le()), Ok(element));
+        }
+    }
 
-    //#[cfg(feature = "std")]
-    fn to_bytes_be(&self) -> Vec<u8> {
-        u64::to_be_bytes(self.value).into()
+    #[test]
+    fn from_hex_parses_canonical_values() {
+        assert_eq!(from_hex_value("0x0"), Ok(0));
+        assert_eq!(from_hex_value("ff"), Ok(255));
+        assert_eq!(from_hex_value("0xffffffff00000000"), Ok(MODULUS - 1));
+        assert_eq!(from_hex_value("0000000000000000000000000000002a"), Ok(42));
     }
 
-    //#[cfg(feature = "std")]
-    fn to_bytes_le(&self) -> Vec<u8> {
-        u64::to_le_bytes(self.value).into()
+    #[test]
+    fn from_hex_reduces_values_above_the_modulus() {
+        assert_eq!(from_hex_value("ffffffff00000001"), Ok(0));
+        assert_eq!(from_hex_value("0xffffffffffffffff"), Ok(EPSILON - 1));
+        assert_eq!(from_hex_value("10000000000000000"), Ok(EPSILON));
+        assert_eq!(from_hex_value(&"ff".repeat(20)), Ok(0xffff_fffe_0000_0001));
     }
 
-    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
-        let bytes: [u8; 8] = bytes[0..8].try_into().map_err(|_| ByteConversionError::FromBEBytesError)?; 
-        let value = u64::from_be_bytes(bytes);
-        Ok(Self { value }) 
+    #[test]
+    fn from_hex_accepts_a_keccak_digest() {
+        // keccak256(""), reduced with arbitrary-precision integers.
+        let digest = "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+        assert_eq!(from_hex_value(digest), Ok(0x9d09_c3cd_c778_67d0));
     }
 
-    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
-        let bytes: [u8; 8] = bytes[0..8].try_into().map_err(|_| ByteConversionError::FromLEBytesError)?;
-        let value = u64::from_le_bytes(bytes);
-        Ok(Self { value }) 
+    #[test]
+    fn from_hex_rejects_malformed_strings() {
+        for input in ["", "0x", "0xg", "+1", "12 34", "0x-1", "é"] {
+            assert_eq!(GoldilocksField::from_hex(input), Err(CreationError::InvalidHexString), "{input:?}");
+        }
     }
-    
 
-    
-}
+    #[test]
+    fn to_hex_formats_canonical_values() {
+        assert_eq!(GoldilocksField::new(0).to_hex(), "0x0");
+        assert_eq!(GoldilocksField::new(0xabc).to_hex(), "0xabc");
+        assert_eq!(GoldilocksField::new(MODULUS - 1).to_hex(), "0xffffffff00000000");
+    }
 
-impl Serializable for GoldilocksField {
-    fn serialize(&self) -> Vec<u8> {
-        self.to_bytes_be()
+    #[test]
+    fn non_canonical_storage_formats_canonically() {
+        // A stored value of p or more stands for the same element as the
+        // value minus p, whatever the backend's representation.
+        for raw in [0, 1, 3, EPSILON - 1] {
+            let (stored, shifted) = (GoldilocksField { value: raw }, GoldilocksField { value: raw + MODULUS });
+            assert_eq!(shifted.to_canonical_u64(), stored.to_canonical_u64());
+            assert_eq!(to_hex(&(raw + MODULUS)), to_hex(&raw));
+            assert_eq!(format!("{shifted}"), format!("{stored}"));
+            assert_eq!(format!("{shifted:#x}"), format!("{stored:#x}"));
+        }
     }
-}
 
-impl Deserializable for GoldilocksField {
-    fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>
-        where
-            Self: Sized {
-                Self::from_bytes_be(bytes).map_err(|x| x.into())
+    #[test]
+    fn to_hex_padded_emits_16_nibbles() {
+        assert_eq!(GoldilocksField::new(0).to_hex_padded(), "0x0000000000000000");
+        assert_eq!(GoldilocksField::new(0xabc).to_hex_padded(), "0x0000000000000abc");
+        assert_eq!(GoldilocksField::new(MODULUS - 1).to_hex_padded(), "0xffffffff00000000");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;  
-    const MODULUS: u64 = 0xffff_ffff_0000_0001;
+    #[test]
+    fn to_hex_round_trips_through_from_hex() {
+        let mut rng = SplitMix64(8);
+        for value in [0, 1, MODULUS - 1].into_iter().chain((0..1000).map(|_| rng.next_element())) {
+            let element = GoldilocksField::new(value);
+            assert_eq!(from_hex_value(&element.to_hex()), Ok(value));
+            assert_eq!(from_hex_value(&element.to_hex_padded()), Ok(value));
+        }
+    }
 
+    #[test]
+    fn ordering_follows_canonical_values() {
+        let values = [0, 1, 2, EPSILON, 1 << 32, 1 << 63, MODULUS - 2, MODULUS - 1].map(GoldilocksField::new);
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{a} vs {b}");
+            }
+        }
+        assert!(GoldilocksField::NEG_ONE > GoldilocksField::ONE);
+    }
 
     #[test]
-    fn get_generator() {
-        assert_eq!(GoldilocksField::generator(), 7);
+    fn display_prints_canonical_decimal() {
+        assert_eq!(format!("{}", GoldilocksField::new(0)), "0");
+        assert_eq!(format!("{}", GoldilocksField::new(1)), "1");
+        assert_eq!(format!("{}", GoldilocksField::new(MODULUS - 1)), "18446744069414584320");
+        assert_eq!(format!("{:>8}", GoldilocksField::new(12345)), "   12345");
+        assert_eq!(format!("{:*<8}", GoldilocksField::new(12345)), "12345***");
     }
 
     #[test]
-    fn test_add() {
-        let a = 7;
-        let b = 10;
-        assert_eq!((GoldilocksField::add(&a, &b)), 17);
+    fn hex_formatting_respects_flags() {
+        let mid = GoldilocksField::new(0x1234_abcd);
+        assert_eq!(format!("{:x}", GoldilocksField::new(0)), "0");
+        assert_eq!(format!("{:#x}", GoldilocksField::new(1)), "0x1");
+        assert_eq!(format!("{:#x}", GoldilocksField::new(MODULUS - 1)), "0xffffffff00000000");
+        assert_eq!(format!("{:X}", GoldilocksField::new(MODULUS - 1)), "FFFFFFFF00000000");
+        assert_eq!(format!("{:016x}", mid), "000000001234abcd");
+        assert_eq!(format!("{:#018X}", mid), "0x000000001234ABCD");
     }
 
     #[test]
-    fn max_order_plus_1_is_0() {
-        let a = MODULUS - 1;
-        let b = 1;
-        assert_eq!(GoldilocksField::add(&a, &b), 0);
+    fn from_str_parses_decimal_values() {
+        assert_eq!("0".parse(), Ok(GoldilocksField::new(0)));
+        assert_eq!("  +42\n".parse(), Ok(GoldilocksField::new(42)));
+        assert_eq!("18446744069414584320".parse(), Ok(GoldilocksField::new(MODULUS - 1)));
+        assert_eq!("18446744069414584321".parse(), Ok(GoldilocksField::new(0)));
+        assert_eq!("18446744069414584322".parse(), Ok(GoldilocksField::new(1)));
     }
 
     #[test]
-    fn test_sub() {
-        let a = 5;
-        let b = 3;
-        assert_eq!(GoldilocksField::sub(&a, &b), 2);
+    fn from_str_reduces_long_decimals() {
+        let forty_digits = "1234567890123456789012345678901234567890";
+        assert_eq!(forty_digits.parse(), Ok(GoldilocksField::new(7897153530003846820)));
     }
 
     #[test]
-    fn test_neg() {
-        let a = 5;
-        let b = 3;
-        assert_eq!(GoldilocksField::mul(&a, &b), 15);
+    fn from_str_delegates_to_from_hex() {
+        assert_eq!("0xff".parse(), Ok(GoldilocksField::new(255)));
+        assert_eq!(" 0xffffffff00000001 ".parse(), Ok(GoldilocksField::new(0)));
+        assert_eq!("0xzz".parse::<GoldilocksField>(), Err(ParseFieldElementError::InvalidDigit));
     }
 
     #[test]
-    fn test_mul() {
-        let a = 5;
-        let b = 3;
-        assert_eq!(GoldilocksField::mul(&a, &b), 15);
+    fn from_str_rejects_invalid_strings() {
+        assert_eq!("".parse::<GoldilocksField>(), Err(ParseFieldElementError::Empty));
+        assert_eq!("  + ".parse::<GoldilocksField>(), Err(ParseFieldElementError::Empty));
+        for input in ["-1", "12a", "1 2", "++1", "1.0"] {
+            assert_eq!(
+                input.parse::<GoldilocksField>(),
+                Err(ParseFieldElementError::InvalidDigit),
+                "{input:?}"
+            );
+        }
     }
 
     #[test]
-    fn mul_order_minus_1() {
-        let a  = MODULUS - 1;
-        let b  = MODULUS - 1;
-        assert_eq!(GoldilocksField::mul(&a, &b), 1);
+    fn from_i64_maps_negatives_to_additive_inverses() {
+        assert_eq!(GoldilocksField::from(-1i64), GoldilocksField::new(MODULUS - 1));
+        assert_eq!(GoldilocksField::from_i64(0), GoldilocksField::new(0));
+        assert_eq!(GoldilocksField::from_i64(i64::MAX), GoldilocksField::new(i64::MAX as u64));
+        assert_eq!(GoldilocksField::from_i64(i64::MIN), GoldilocksField::new(MODULUS - (1 << 63)));
+        assert_eq!(
+            GoldilocksField::from_i64(i64::MIN) + GoldilocksField::new(1 << 63),
+            GoldilocksField::new(0)
+        );
     }
 
     #[test]
-    fn inv_0_error() {
-        let a = 0;
-        let result = GoldilocksField::inv(&a);
-        assert!(matches!(result, Err(FieldError::InvZeroError)));
+    fn to_i64_checked_round_trips() {
+        let bound = (1i64 << 62) + ((1i64 << 62) - (1i64 << 32));
+        let mut rng = SplitMix64(11);
+        let samples = (0..1000).map(|_| rng.next_u64() as i64 % bound);
+        for x in [0, 1, -1, bound, -bound].into_iter().chain(samples) {
+            assert_eq!(GoldilocksField::from_i64(x).to_i64_checked(), Some(x), "{x}");
+        }
+        assert_eq!(GoldilocksField::new(MODULUS - 1).to_i64_checked(), Some(-1));
+        assert_eq!(GoldilocksField::new(1 << 63).to_i64_checked(), Some(-0x7fff_ffff_0000_0001));
     }
-    
 
     #[test]
-    fn inv_2() {
-        let a = 2;
-        let modulus = MODULUS; // Assuming you have MODULUS defined somewhere
-        let inverse_result = GoldilocksField::inv(&a).unwrap();
-    
-        // Check if a * inv(a) is congruent to 1 modulo MODULUS
-        let product = GoldilocksField::mul(&modulus, &inverse_result);
-        assert_eq!(product, 1);
+    fn to_i64_checked_rejects_ambiguous_values() {
+        assert_eq!(GoldilocksField::from_i64(i64::MIN).to_i64_checked(), None);
+        assert_eq!(GoldilocksField::from_i64(i64::MAX).to_i64_checked(), None);
+        assert_eq!(GoldilocksField::new((1 << 63) - (1 << 32) + 1).to_i64_checked(), None);
     }
 
+    #[test]
+    fn from_u128_corner_cases() {
+        let p = MODULUS as u128;
+        assert_eq!(GoldilocksField::from(u128::MAX), GoldilocksField::new(0xffff_fffe_0000_0000));
+        assert_eq!(GoldilocksField::from_u128((p - 1) * (p - 1)), GoldilocksField::new(1));
+        assert_eq!(GoldilocksField::from_u128(1 << 64), GoldilocksField::new(EPSILON));
+        assert_eq!(GoldilocksField::from_u128(1 << 96), GoldilocksField::new(MODULUS - 1));
+        for k in [0, 1, 2, EPSILON as u128, u64::MAX as u128, u128::MAX / p] {
+            assert_eq!(GoldilocksField::from_u128(k * p), GoldilocksField::new(0), "{k} * p");
+        }
+    }
 
     #[test]
-    fn pow_2_3() {
-        assert_eq!(GoldilocksField::new(2).pow(3), GoldilocksField::new(8))
+    fn from_u128_matches_exact_remainder() {
+        let mut rng = SplitMix64(12);
+        for _ in 0..10_000 {
+            let x = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+            let expected = (x % MODULUS as u128) as u64;
+            assert_eq!(GoldilocksField::from_u128(x), GoldilocksField::new(expected), "{x}");
+        }
     }
 
     #[test]
-    fn pow_p_minus_1() {
-        assert_eq!(GoldilocksField::new(2).pow((MODULUS - 1) as u32), GoldilocksField::new(1))
+    fn from_small_unsigned_types() {
+        assert_eq!(GoldilocksField::from(true), GoldilocksField::new(1));
+        assert_eq!(GoldilocksField::from(false), GoldilocksField::new(0));
+        assert_eq!(GoldilocksField::from(u8::MAX), GoldilocksField::new(255));
+        assert_eq!(GoldilocksField::from(u16::MAX), GoldilocksField::new(65535));
+        assert_eq!(GoldilocksField::from(u32::MAX), GoldilocksField::new(EPSILON));
+        assert_eq!(GoldilocksField::from(usize::MAX), GoldilocksField::new(usize::MAX as u64));
     }
 
     #[test]
-    fn div_1() {
-        assert_eq!(GoldilocksField::new(2) / GoldilocksField::new(1), GoldilocksField::new(2))
+    fn from_canonical_u32_skips_reduction() {
+        const X: GoldilocksField = GoldilocksField::from_canonical_u32(u32::MAX);
+        assert_eq!(X, GoldilocksField::new(u32::MAX as u64));
+        assert_eq!(GoldilocksField::from_canonical_u32(0), GoldilocksField::new(0));
     }
 
     #[test]
-    fn div_4_2() {
-        assert_eq!(GoldilocksField::new(4) / GoldilocksField::new(2), GoldilocksField::new(2))
+    fn fixed_size_byte_conversions_agree_with_byte_conversion() {
+        let mut rng = SplitMix64(14);
+        for value in [0, 1, MODULUS - 1].into_iter().chain((0..100).map(|_| rng.next_element())) {
+            let element = GoldilocksField::new(value);
+            let bytes: [u8; 8] = element.into();
+            assert_eq!(bytes.to_vec(), element.to_bytes_be());
+            assert_eq!(GoldilocksField::from(bytes), element);
+            assert_eq!(GoldilocksField::try_from(&bytes[..]), Ok(element));
+            assert_eq!(GoldilocksField::from_bytes_be(&bytes), Ok(element));
+        }
     }
 
     #[test]
-    fn div_4_3() {
-        assert_eq!(GoldilocksField::new(4) / GoldilocksField::new(3) * GoldilocksField::new(3), GoldilocksField::new(4))
+    fn from_byte_array_canonicalizes() {
+        assert_eq!(GoldilocksField::from(MODULUS.to_be_bytes()), GoldilocksField::new(0));
+        assert_eq!(GoldilocksField::from(u64::MAX.to_be_bytes()), GoldilocksField::new(EPSILON - 1));
     }
 
+    #[test]
+    fn try_from_slice_rejects_wrong_lengths() {
+        for len in [0, 7, 9] {
+            let bytes = vec![1; len];
+            assert_eq!(
+                GoldilocksField::try_from(&bytes[..]),
+                Err(ByteConversionError::FromBEBytesError),
+                "{len} bytes"
+            );
+        }
+    }
 
     #[test]
-    fn two_plus_its_additive_inv_is_0() {
-        let two = GoldilocksField::new(2);
-        assert_eq!((two - two), GoldilocksField::new(0))
+    fn associated_constants_through_the_field_api() {
+        let (zero, one) = (GoldilocksField::ZERO.value, GoldilocksField::ONE.value);
+        let neg_one = GoldilocksField::NEG_ONE.value;
+        assert_eq!(GoldilocksField::add(&zero, &one), one);
+        assert_eq!(GoldilocksField::add(&neg_one, &one), zero);
+        assert_eq!(GoldilocksField::add(&one, &one), GoldilocksField::TWO.value);
+        assert_eq!(GoldilocksField::neg(&one), neg_one);
+        assert_eq!(GoldilocksField::GENERATOR, GoldilocksField::generator());
     }
 
     #[test]
-    fn four_minus_three_is_1() {
-        let four = GoldilocksField::new(4);
-        let three = GoldilocksField::new(3);
+    fn associated_constants_in_const_arrays() {
+        const ROW: [GoldilocksField; 4] = [GoldilocksField::ZERO; 4];
+        const MIXED: [GoldilocksField; 3] =
+            [GoldilocksField::ONE, GoldilocksField::TWO, GoldilocksField::NEG_ONE];
+        assert!(ROW.iter().all(|x| *x == GoldilocksField::new(0)));
+        assert_eq!(MIXED[0] + MIXED[2], GoldilocksField::ZERO);
+        assert_eq!(MIXED[1] - MIXED[0], GoldilocksField::ONE);
+    }
 
-        assert_eq!(four - three, GoldilocksField::new(1))
+    #[test]
+    fn square_matches_mul() {
+        let mut rng = SplitMix64(16);
+        let edges = [0, 1, EPSILON, (1 << 32) + 1, (1 << 32) + 7, MODULUS - 1];
+        for x in edges.into_iter().chain((0..10_000).map(|_| rng.next_element())) {
+            let x = GoldilocksField::new(x).value;
+            let expected = GoldilocksField::mul(&x, &x);
+            assert_eq!(<GoldilocksField as IsField>::square(&x), expected, "{x}");
+            assert_eq!(GoldilocksField { value: x }.square(), GoldilocksField { value: expected });
+        }
     }
 
     #[test]
-    fn zero_minus_1_is_order_minus_1() {
-        let zero = GoldilocksField::new(0);
-        let one = GoldilocksField::new(1);
+    fn halve_one_is_inverse_of_two() {
+        let inv_two = GoldilocksField { value: GoldilocksField::inv(&GoldilocksField::TWO.value).unwrap() };
+        assert_eq!(GoldilocksField::ONE.halve(), inv_two);
+        assert_eq!(GoldilocksField::NEG_ONE.halve(), -inv_two);
+        assert_eq!(GoldilocksField::ZERO.halve(), GoldilocksField::ZERO);
+    }
 
-        assert_eq!(zero - one, GoldilocksField::new(MODULUS - 1))
+    #[test]
+    fn double_and_halve_agree_with_generic_operations() {
+        let inv_two = GoldilocksField { value: GoldilocksField::inv(&GoldilocksField::TWO.value).unwrap() };
+        let mut rng = SplitMix64(17);
+        let edges = [0, 1, 2, EPSILON, 1 << 63, MODULUS - 2, MODULUS - 1];
+        for x in edges.into_iter().chain((0..10_000).map(|_| rng.next_element())) {
+            let x = GoldilocksField::new(x);
+            assert_eq!(x.double(), x + x);
+            assert_eq!(x.double(), x * GoldilocksField::TWO);
+            assert_eq!(x.halve(), x * inv_two);
+            assert_eq!(x.double().halve(), x);
+            assert_eq!(x.halve().double(), x);
+        }
     }
 
+    #[test]
+    fn mul_small_constants_agree_with_mul() {
+        let mut rng = SplitMix64(18);
+        let edges = [0, 1, EPSILON, 1 << 32, 1 << 63, MODULUS - 1];
+        let constants = [0, 1, 2, 3, 7, 255, u32::MAX - 1, u32::MAX];
+        let samples: Vec<u64> = (0..1000).map(|_| rng.next_element()).collect();
+        for x in edges.into_iter().chain(samples) {
+            let element = GoldilocksField::new(x);
+            for c in constants.into_iter().chain([rng.next_u64() as u32]) {
+                let expected = element * GoldilocksField::from(c);
+                assert_eq!(element.mul_u32(c), expected, "{x} * {c}");
+                assert_eq!(element.mul_u64_small(c as u64), expected, "{x} * {c}");
+            }
+            assert_eq!(element.mul_by_7(), element * GoldilocksField::GENERATOR);
+            assert_eq!(element.mul_by_3(), element * GoldilocksField::from(3u64));
+        }
+    }
 
     #[test]
-    fn zero_constructor_returns_zero() {
-        assert_eq!(GoldilocksField::new(0), GoldilocksField::new(0));
+    fn mul_u64_small_falls_back_for_wide_constants() {
+        let x = GoldilocksField::new(MODULUS - 1);
+        for c in [1 << 32, u64::MAX, MODULUS - 1] {
+            assert_eq!(x.mul_u64_small(c), x * GoldilocksField::new(c));
+        }
+    }
+
+    #[test]
+    fn inner_product_matches_naive_fold() {
+        let mut rng = SplitMix64(19);
+        for len in [0, 1, 12, 1000, 1 << 16] {
+            let a = random_elements(&mut rng, len);
+            let b = random_elements(&mut rng, len);
+            let naive = a.iter().zip(&b).fold(GoldilocksField::ZERO, |acc, (x, y)| acc + *x * *y);
+            assert_eq!(inner_product(&a, &b), naive, "length {len}");
+        }
+    }
+
+    #[test]
+    fn inner_product_handles_accumulator_wrap_around() {
+        let a = vec![GoldilocksField::NEG_ONE; 1000];
+        assert_eq!(inner_product(&a, &a), GoldilocksField::new(1000));
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn inner_product_rejects_mismatched_lengths() {
+        inner_product(&[GoldilocksField::ONE; 2], &[GoldilocksField::ONE; 3]);
+    }
+
+    #[test]
+    fn multiply_accumulate_matches_mul_then_add() {
+        let mut rng = SplitMix64(20);
+        for _ in 0..10_000 {
+            let (a, b, c) = (rng.next_element(), rng.next_element(), rng.next_element());
+            let expected = GoldilocksField::add(&GoldilocksField::mul(&a, &b), &c);
+            assert_eq!(multiply_accumulate(&a, &b, &c), expected, "{a} * {b} + {c}");
+        }
+    }
+
+    #[test]
+    fn multiply_accumulate_extreme_operands() {
+        let max = GoldilocksField::NEG_ONE;
+        // (p - 1)^2 + (p - 1) exceeds 2^127.
+        assert!((MODULUS - 1) as u128 * (MODULUS - 1) as u128 > 1 << 127);
+        assert_eq!(GoldilocksField::multiply_accumulate(&max, &max, &max), GoldilocksField::ZERO);
+        assert_eq!(
+            GoldilocksField::multiply_accumulate(&max, &max, &GoldilocksField::ONE),
+            GoldilocksField::TWO
+        );
+        for (a, b) in [(1 << 63, 1 << 63), (MODULUS - 1, 1 << 63)] {
+            let (a, b, c) = (GoldilocksField::new(a), GoldilocksField::new(b), max);
+            assert_eq!(GoldilocksField::multiply_accumulate(&a, &b, &c), a * b + c);
+        }
+    }
+
+    #[test]
+    fn reduce_u128_matches_remainder() {
+        let p = MODULUS as u128;
+        let mut rng = SplitMix64(21);
+        let samples = (0..10_000).map(|_| ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128);
+        let edges = [0, 1, p - 1, p, p + 1, u64::MAX as u128, 1 << 64, 1 << 96, (1 << 96) - 1, u128::MAX];
+        for x in edges.into_iter().chain(samples) {
+            assert_eq!(reduce_u128(x), (x % p) as u64, "{x}");
+        }
+        for k in [1, 2, EPSILON as u128, u64::MAX as u128, u128::MAX / p] {
+            assert_eq!(reduce_u128(k * p), 0, "{k} * p");
+        }
+    }
+
+    #[test]
+    fn reduce_u96_matches_remainder() {
+        let p = MODULUS as u128;
+        let mut rng = SplitMix64(22);
+        let edges = [(0, 0), (0, MODULUS), (0, u64::MAX), (u32::MAX, u64::MAX), (1, 0), (u32::MAX, 0)];
+        let samples = (0..10_000).map(|_| (rng.next_u64() as u32, rng.next_u64()));
+        for (hi, lo) in edges.into_iter().chain(samples) {
+            let x = ((hi as u128) << 64) | lo as u128;
+            assert_eq!(reduce_u96(hi, lo), (x % p) as u64, "{x}");
+        }
+    }
+
+    #[test]
+    fn canonicalize_maps_into_range() {
+        assert_eq!(canonicalize(0), 0);
+        assert_eq!(canonicalize(MODULUS - 1), MODULUS - 1);
+        assert_eq!(canonicalize(MODULUS), 0);
+        assert_eq!(canonicalize(u64::MAX), EPSILON - 1);
+        let mut rng = SplitMix64(22);
+        for _ in 0..10_000 {
+            let x = rng.next_u64();
+            assert_eq!(canonicalize(x), x % MODULUS);
+        }
+    }
+
+    #[test]
+    fn from_base_type_canonicalizes() {
+        let canonical = |x: u64| GoldilocksField::representative(&x);
+        assert_eq!(canonical(GoldilocksField::from_base_type(MODULUS)), 0);
+        assert_eq!(canonical(GoldilocksField::from_base_type(u64::MAX)), EPSILON - 1);
+        assert_eq!(canonical(GoldilocksField::from_u64(MODULUS + 1)), 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-canonical operand")]
+    fn debug_builds_catch_non_canonical_values() {
+        let bad = GoldilocksField { value: MODULUS };
+        let _ = bad * GoldilocksField::ONE;
     }
 
     #[test]
@@ -294,6 +2698,37 @@ mod tests {
         let f2 = GoldilocksField::from(GoldilocksField::representative(&f1.value)); 
         assert_eq!(f1, f2);
     }
-    
 
+    #[test]
+    fn reference_assign_and_iterator_ops_agree_with_by_value_ops() {
+        let mut rng = SplitMix64(0xaa55);
+        let xs = random_elements(&mut rng, 64);
+        let ys = random_elements(&mut rng, 64);
+        for (&a, b) in xs.iter().zip(&ys) {
+            assert_eq!(a + b, a + *b);
+            assert_eq!(a - b, a - *b);
+            assert_eq!(a * b, a * *b);
+            let (mut sum, mut diff, mut prod) = (a, a, a);
+            sum += b;
+            diff -= *b;
+            prod *= b;
+            assert_eq!((sum, diff, prod), (a + *b, a - *b, a * *b));
+            if *b != GoldilocksField::ZERO {
+                let mut quot = a;
+                quot /= b;
+                assert_eq!(quot, a / *b);
+                assert_eq!(a / b, a / *b);
+            }
+        }
+
+        let sum = xs.iter().fold(GoldilocksField::ZERO, |acc, &x| acc + x);
+        let product = xs.iter().fold(GoldilocksField::ONE, |acc, &x| acc * x);
+        assert_eq!(xs.iter().sum::<GoldilocksField>(), sum);
+        assert_eq!(xs.iter().copied().sum::<GoldilocksField>(), sum);
+        assert_eq!(xs.iter().product::<GoldilocksField>(), product);
+        assert_eq!(xs.iter().copied().product::<GoldilocksField>(), product);
+        assert_eq!(core::iter::empty::<GoldilocksField>().sum::<GoldilocksField>(), GoldilocksField::ZERO);
+        assert_eq!(core::iter::empty::<GoldilocksField>().product::<GoldilocksField>(), GoldilocksField::ONE);
+        assert_eq!(GoldilocksField::default(), GoldilocksField::ZERO);
+    }
 }
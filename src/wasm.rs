@@ -0,0 +1,242 @@
+//! WebAssembly bindings through wasm-bindgen, behind the `wasm` feature.
+//!
+//! JS numbers cannot hold a u64, so elements enter as decimal or `0x`-hex
+//! strings and leave through `toHex()` / `toString()`; exponents are BigInts.
+//! Byte arrays use the `encoding` layout: each element is its canonical value
+//! as 8 little-endian bytes, and a digest is 4 elements, 32 bytes.
+
+use wasm_bindgen::prelude::*;
+
+use crate::encoding::{deserialize_elements, serialize_elements, ELEMENT_SIZE};
+use crate::hash::poseidon::{hash_n_to_m, DIGEST_LEN};
+use crate::merkle::{Digest, MerkleProof, PoseidonHasher};
+use crate::GoldilocksField;
+
+/// Bytes per encoded digest.
+const DIGEST_SIZE: usize = DIGEST_LEN * ELEMENT_SIZE;
+
+/// A Goldilocks field element.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmGoldilocks(GoldilocksField);
+
+#[wasm_bindgen]
+impl WasmGoldilocks {
+    /// Parses a decimal or `0x`-prefixed hex string of any length, reducing
+    /// it modulo p, as `GoldilocksField::from_str` does.
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: &str) -> Result<WasmGoldilocks, JsError> {
+        Ok(WasmGoldilocks(value.parse()?))
+    }
+
+    /// Parses hex digits, with or without a `0x` prefix, reducing modulo p.
+    #[wasm_bindgen(js_name = fromHex)]
+    pub fn from_hex(hex: &str) -> Result<WasmGoldilocks, JsError> {
+        let digits = hex.strip_prefix("0x").unwrap_or(hex);
+        Ok(WasmGoldilocks(format!("0x{digits}").parse()?))
+    }
+
+    pub fn zero() -> WasmGoldilocks {
+        WasmGoldilocks(GoldilocksField::ZERO)
+    }
+
+    pub fn one() -> WasmGoldilocks {
+        WasmGoldilocks(GoldilocksField::ONE)
+    }
+
+    pub fn add(&self, other: &WasmGoldilocks) -> WasmGoldilocks {
+        WasmGoldilocks(self.0 + other.0)
+    }
+
+    pub fn sub(&self, other: &WasmGoldilocks) -> WasmGoldilocks {
+        WasmGoldilocks(self.0 - other.0)
+    }
+
+    pub fn mul(&self, other: &WasmGoldilocks) -> WasmGoldilocks {
+        WasmGoldilocks(self.0 * other.0)
+    }
+
+    /// Throws if `other` is zero.
+    pub fn div(&self, other: &WasmGoldilocks) -> Result<WasmGoldilocks, JsError> {
+        GoldilocksField::checked_div(&self.0, &other.0)
+            .map(WasmGoldilocks)
+            .map_err(|_| JsError::new("division by zero"))
+    }
+
+    pub fn neg(&self) -> WasmGoldilocks {
+        WasmGoldilocks(-self.0)
+    }
+
+    pub fn square(&self) -> WasmGoldilocks {
+        WasmGoldilocks(self.0.square())
+    }
+
+    /// Raises to a BigInt exponent below 2^64.
+    pub fn pow(&self, exponent: u64) -> WasmGoldilocks {
+        WasmGoldilocks(self.0.pow_u64(exponent))
+    }
+
+    /// Throws if the element is zero.
+    pub fn inverse(&self) -> Result<WasmGoldilocks, JsError> {
+        WasmGoldilocks::one().div(self)
+    }
+
+    pub fn equals(&self, other: &WasmGoldilocks) -> bool {
+        self.0 == other.0
+    }
+
+    #[wasm_bindgen(js_name = isZero)]
+    pub fn is_zero(&self) -> bool {
+        self.0 == GoldilocksField::ZERO
+    }
+
+    /// Lowercase `0x`-prefixed hex of the canonical value.
+    #[wasm_bindgen(js_name = toHex)]
+    pub fn to_hex(&self) -> String {
+        self.0.to_hex()
+    }
+
+    /// Decimal canonical value.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_decimal_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+fn digest_to_bytes(digest: &[GoldilocksField]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    serialize_elements(digest, &mut bytes);
+    bytes
+}
+
+/// Decodes concatenated 32-byte digests.
+fn digests_from_bytes(bytes: &[u8]) -> Result<Vec<Digest>, String> {
+    if bytes.len() % DIGEST_SIZE != 0 {
+        return Err(format!("byte length {} is not a multiple of the digest size {DIGEST_SIZE}", bytes.len()));
+    }
+    let elements = deserialize_elements(bytes).map_err(|e| e.to_string())?;
+    Ok(elements.chunks_exact(DIGEST_LEN).map(|chunk| chunk.try_into().expect("chunk of DIGEST_LEN")).collect())
+}
+
+fn poseidon_digest(input: &[u8]) -> Result<Vec<u8>, String> {
+    let elements = deserialize_elements(input).map_err(|e| e.to_string())?;
+    Ok(digest_to_bytes(&hash_n_to_m::<12>(&elements, DIGEST_LEN)))
+}
+
+fn verify_proof(root: &[u8], index: usize, leaf: &[u8], siblings: &[u8]) -> Result<bool, String> {
+    let root = match digests_from_bytes(root)?[..] {
+        [root] => root,
+        _ => return Err(format!("root must be {DIGEST_SIZE} bytes, got {}", root.len())),
+    };
+    let leaf = deserialize_elements(leaf).map_err(|e| e.to_string())?;
+    let proof = MerkleProof::new(PoseidonHasher, digests_from_bytes(siblings)?);
+    Ok(proof.verify(&root, index, &leaf))
+}
+
+/// Width-12 Poseidon of the encoded elements in `input`, in overwrite mode
+/// without padding like plonky2's `hash_no_pad`, returned as a 32-byte
+/// digest. Throws if `input` is not a whole number of canonical elements.
+#[wasm_bindgen]
+pub fn poseidon_hash(input: &[u8]) -> Result<Vec<u8>, JsError> {
+    poseidon_digest(input).map_err(|e| JsError::new(&e))
+}
+
+/// Whether `leaf`, given as encoded elements, sits at `index` of the
+/// `merkle::MerkleTree` with `PoseidonHasher` and this 32-byte `root`.
+/// `siblings` is the authentication path as concatenated 32-byte digests,
+/// leaf level first. Throws on malformed input; a well-formed proof that
+/// does not check out returns false.
+#[wasm_bindgen]
+pub fn verify_merkle_proof(root: &[u8], index: usize, leaf: &[u8], siblings: &[u8]) -> Result<bool, JsError> {
+    verify_proof(root, index, leaf, siblings).map_err(|e| JsError::new(&e))
+}
+
+// The exported functions above build a `JsError` on failure, which needs a JS
+// host; these tests cover the Rust side natively, and tests/wasm.rs runs the
+// bindings under wasm32-unknown-unknown.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::{MerkleHasher, MerkleTree};
+    use crate::MODULUS;
+
+    /// Unwraps a binding result; `JsError` has no `Debug` to print.
+    fn ok<T>(result: Result<T, JsError>) -> T {
+        result.unwrap_or_else(|_| panic!("binding returned an error"))
+    }
+
+    fn encode(values: &[u64]) -> Vec<u8> {
+        values.iter().flat_map(|x| x.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn parsing_reduces_and_round_trips() {
+        let x = ok(WasmGoldilocks::new("18446744069414584322"));
+        assert_eq!(x, WasmGoldilocks::one());
+        let x = ok(WasmGoldilocks::new("0xdeadbeef"));
+        assert_eq!(x.to_hex(), "0xdeadbeef");
+        assert_eq!(x.to_decimal_string(), "3735928559");
+        assert_eq!(ok(WasmGoldilocks::from_hex("DEADBEEF")), x);
+        assert_eq!(ok(WasmGoldilocks::from_hex("0xdeadbeef")), x);
+        assert_eq!(ok(WasmGoldilocks::from_hex("ffffffff00000001")), WasmGoldilocks::zero());
+    }
+
+    #[test]
+    fn arithmetic_matches_the_field() {
+        let a = WasmGoldilocks(GoldilocksField::new(MODULUS - 2));
+        let b = WasmGoldilocks(GoldilocksField::new(5));
+        assert_eq!(a.add(&b).to_decimal_string(), "3");
+        assert_eq!(b.sub(&a).to_decimal_string(), "7");
+        assert_eq!(a.mul(&b).0, GoldilocksField::new(MODULUS - 10));
+        assert_eq!(ok(a.div(&b)).mul(&b), a);
+        assert_eq!(ok(b.inverse()).mul(&b), WasmGoldilocks::one());
+        assert_eq!(a.neg().to_decimal_string(), "2");
+        assert_eq!(b.square().to_decimal_string(), "25");
+        assert_eq!(b.pow(3).to_decimal_string(), "125");
+        assert!(a.add(&a.neg()).is_zero());
+        assert!(a.equals(&a) && !a.equals(&b));
+    }
+
+    #[test]
+    fn poseidon_digest_matches_hash_n_to_m() {
+        let input = encode(&(0..10).collect::<Vec<_>>());
+        assert_eq!(
+            poseidon_digest(&input).unwrap(),
+            encode(&[0xf2c9b602d5dc31f4, 0x914f17a76f289ecc, 0x0bcae24b87e24834, 0xd1fa70baefe919e0])
+        );
+        assert!(poseidon_digest(&input[..7]).is_err());
+        assert!(poseidon_digest(&encode(&[MODULUS])).unwrap_err().contains("not below"));
+    }
+
+    #[test]
+    fn merkle_proofs_verify_from_bytes() {
+        let leaves: Vec<Vec<GoldilocksField>> = (0..5u64).map(|i| vec![GoldilocksField::new(i), GoldilocksField::new(i * i)]).collect();
+        let tree = MerkleTree::build(&leaves, PoseidonHasher);
+        let root = digest_to_bytes(&tree.root());
+        for (index, leaf) in leaves.iter().enumerate() {
+            let siblings: Vec<u8> = tree.open(index).siblings.iter().flat_map(|d| digest_to_bytes(d)).collect();
+            let mut leaf_bytes = Vec::new();
+            serialize_elements(leaf, &mut leaf_bytes);
+            assert_eq!(verify_proof(&root, index, &leaf_bytes, &siblings), Ok(true));
+            assert_eq!(verify_proof(&root, index ^ 1, &leaf_bytes, &siblings), Ok(false));
+
+            let mut tampered = siblings.clone();
+            tampered[0] ^= 1;
+            assert_eq!(verify_proof(&root, index, &leaf_bytes, &tampered), Ok(false));
+            assert!(verify_proof(&root[..31], index, &leaf_bytes, &siblings).is_err());
+            assert!(verify_proof(&root, index, &leaf_bytes, &siblings[..40]).is_err());
+        }
+    }
+
+    #[test]
+    fn overlong_paths_are_rejected_without_panicking() {
+        let leaf = encode(&[1, 2]);
+        let root = digest_to_bytes(&PoseidonHasher.hash_leaf(&[GoldilocksField::new(1), GoldilocksField::new(2)]));
+        // 33 siblings overflow a 32-bit index shift, 65 a 64-bit one.
+        for count in [33, 65, 100] {
+            let siblings = vec![0; count * DIGEST_SIZE];
+            assert_eq!(verify_proof(&root, 0, &leaf, &siblings), Ok(false), "{count} siblings");
+            assert_eq!(verify_proof(&root, usize::MAX, &leaf, &siblings), Ok(false), "{count} siblings");
+        }
+    }
+}
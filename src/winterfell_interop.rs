@@ -0,0 +1,79 @@
+//! Conversions to and from Winterfell's `f64::BaseElement`, behind the
+//! `winterfell-interop` feature.
+//!
+//! Winterfell stores elements in Montgomery form. Values cross over as
+//! canonical u64s through `BaseElement::new` and `BaseElement::as_int`, so
+//! neither side's representation leaks into the other.
+
+use winter_math::fields::f64::BaseElement;
+
+use crate::GoldilocksField;
+
+impl From<BaseElement> for GoldilocksField {
+    fn from(x: BaseElement) -> Self {
+        GoldilocksField::from_canonical(x.as_int())
+    }
+}
+
+impl From<GoldilocksField> for BaseElement {
+    fn from(x: GoldilocksField) -> Self {
+        BaseElement::new(x.to_canonical_u64())
+    }
+}
+
+/// Converts a slice of Winterfell elements in one pass into a preallocated
+/// vector.
+pub fn from_winterfell_slice(xs: &[BaseElement]) -> Vec<GoldilocksField> {
+    xs.iter().map(|&x| x.into()).collect()
+}
+
+/// Converts a slice of elements in one pass into a preallocated vector of
+/// Winterfell elements.
+pub fn to_winterfell_vec(xs: &[GoldilocksField]) -> Vec<BaseElement> {
+    xs.iter().map(|&x| x.into()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::SplitMix64;
+    use crate::MODULUS;
+    use winter_math::FieldElement;
+
+    fn samples(rng: &mut SplitMix64, n: usize) -> Vec<GoldilocksField> {
+        let mut xs = vec![GoldilocksField::ZERO, GoldilocksField::ONE, GoldilocksField::new(MODULUS - 1)];
+        xs.extend((0..n).map(|_| rng.next_field()));
+        xs
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let mut rng = SplitMix64(96);
+        let xs = samples(&mut rng, 1000);
+        for &x in &xs {
+            let y = BaseElement::from(x);
+            assert_eq!(y.as_int(), x.to_canonical_u64());
+            assert_eq!(GoldilocksField::from(y), x);
+        }
+        assert_eq!(BaseElement::from(GoldilocksField::ONE), BaseElement::ONE);
+        assert_eq!(from_winterfell_slice(&to_winterfell_vec(&xs)), xs);
+        assert_eq!(to_winterfell_vec(&[]), []);
+    }
+
+    #[test]
+    fn arithmetic_agrees_with_winterfell() {
+        let mut rng = SplitMix64(0x3f64);
+        let xs = samples(&mut rng, 3000);
+        let ys = samples(&mut rng, 3000);
+        for (&a, &b) in xs.iter().zip(ys.iter().rev()) {
+            let (wa, wb) = (BaseElement::from(a), BaseElement::from(b));
+            assert_eq!((wa + wb).as_int(), (a + b).to_canonical_u64());
+            assert_eq!((wa - wb).as_int(), (a - b).to_canonical_u64());
+            assert_eq!((wa * wb).as_int(), (a * b).to_canonical_u64());
+            // Winterfell maps zero to zero instead of failing.
+            if a != GoldilocksField::ZERO {
+                assert_eq!(wa.inv().as_int(), (GoldilocksField::ONE / a).to_canonical_u64());
+            }
+        }
+    }
+}
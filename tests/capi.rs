@@ -0,0 +1,120 @@
+#![cfg(feature = "capi")]
+
+use goldilocks_64bit::ffi::*;
+use goldilocks_64bit::ntt::ntt_in_place;
+use goldilocks_64bit::{GoldilocksField, MODULUS};
+
+fn call(f: impl FnOnce(&mut u64) -> GoldilocksStatus) -> Result<u64, GoldilocksStatus> {
+    let mut out = 0;
+    match f(&mut out) {
+        GoldilocksStatus::Ok => Ok(out),
+        status => Err(status),
+    }
+}
+
+fn samples(n: u64) -> Vec<u64> {
+    (0..n).map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15) % MODULUS).collect()
+}
+
+#[test]
+fn scalar_functions_match_the_field() {
+    let xs = samples(64);
+    for (&a, &b) in xs.iter().zip(xs.iter().rev()) {
+        let (x, y) = (GoldilocksField::from_canonical(a), GoldilocksField::from_canonical(b));
+        assert_eq!(call(|out| unsafe { goldilocks_add(a, b, out) }), Ok((x + y).to_canonical_u64()));
+        assert_eq!(call(|out| unsafe { goldilocks_sub(a, b, out) }), Ok((x - y).to_canonical_u64()));
+        assert_eq!(call(|out| unsafe { goldilocks_mul(a, b, out) }), Ok((x * y).to_canonical_u64()));
+        if a != 0 {
+            let inverse = call(|out| unsafe { goldilocks_inv(a, out) }).unwrap();
+            assert_eq!(GoldilocksField::from_canonical(inverse) * x, GoldilocksField::ONE);
+        }
+    }
+    assert_eq!(call(|out| unsafe { goldilocks_add(MODULUS - 1, 1, out) }), Ok(0));
+    assert_eq!(call(|out| unsafe { goldilocks_sub(0, 1, out) }), Ok(MODULUS - 1));
+}
+
+#[test]
+fn scalar_functions_report_invalid_input() {
+    assert_eq!(call(|out| unsafe { goldilocks_add(MODULUS, 1, out) }), Err(GoldilocksStatus::NonCanonical));
+    assert_eq!(call(|out| unsafe { goldilocks_mul(1, u64::MAX, out) }), Err(GoldilocksStatus::NonCanonical));
+    assert_eq!(call(|out| unsafe { goldilocks_inv(MODULUS, out) }), Err(GoldilocksStatus::NonCanonical));
+    assert_eq!(call(|out| unsafe { goldilocks_inv(0, out) }), Err(GoldilocksStatus::ZeroInverse));
+    unsafe {
+        assert_eq!(goldilocks_sub(1, 2, core::ptr::null_mut()), GoldilocksStatus::NullPointer);
+        assert_eq!(goldilocks_inv(1, core::ptr::null_mut()), GoldilocksStatus::NullPointer);
+    }
+
+    // Outputs are left alone on failure.
+    let mut out = 42;
+    assert_eq!(unsafe { goldilocks_inv(0, &mut out) }, GoldilocksStatus::ZeroInverse);
+    assert_eq!(out, 42);
+}
+
+#[test]
+fn ntt_matches_the_rust_api_and_inverts() {
+    for log_n in [0, 1, 3, 10] {
+        let original = samples(1 << log_n);
+        let mut expected: Vec<GoldilocksField> = original.iter().map(|&x| GoldilocksField::from_canonical(x)).collect();
+        ntt_in_place(&mut expected).unwrap();
+
+        let mut values = original.clone();
+        assert_eq!(unsafe { goldilocks_ntt(values.as_mut_ptr(), values.len(), false) }, GoldilocksStatus::Ok);
+        assert_eq!(values, expected.iter().map(GoldilocksField::to_canonical_u64).collect::<Vec<_>>());
+        assert_eq!(unsafe { goldilocks_ntt(values.as_mut_ptr(), values.len(), true) }, GoldilocksStatus::Ok);
+        assert_eq!(values, original);
+    }
+}
+
+#[test]
+fn ntt_rejects_bad_buffers_without_touching_them() {
+    let mut values = samples(6);
+    let original = values.clone();
+    assert_eq!(unsafe { goldilocks_ntt(values.as_mut_ptr(), values.len(), false) }, GoldilocksStatus::InvalidLength);
+    assert_eq!(unsafe { goldilocks_ntt(values.as_mut_ptr(), 0, false) }, GoldilocksStatus::InvalidLength);
+    values[3] = MODULUS;
+    assert_eq!(unsafe { goldilocks_ntt(values.as_mut_ptr(), 4, true) }, GoldilocksStatus::NonCanonical);
+    assert_eq!(values[..3], original[..3]);
+    assert_eq!(unsafe { goldilocks_ntt(core::ptr::null_mut(), 4, false) }, GoldilocksStatus::NullPointer);
+}
+
+#[test]
+fn batch_inverse_inverts_in_place() {
+    let original: Vec<u64> = samples(100).into_iter().map(|x| x.max(1)).collect();
+    let mut values = original.clone();
+    assert_eq!(unsafe { goldilocks_batch_inverse(values.as_mut_ptr(), values.len()) }, GoldilocksStatus::Ok);
+    for (&x, &inverse) in original.iter().zip(&values) {
+        assert_eq!(GoldilocksField::from_canonical(x) * GoldilocksField::from_canonical(inverse), GoldilocksField::ONE);
+    }
+
+    values[50] = 0;
+    let before = values.clone();
+    assert_eq!(unsafe { goldilocks_batch_inverse(values.as_mut_ptr(), values.len()) }, GoldilocksStatus::ZeroInverse);
+    assert_eq!(values, before);
+    assert_eq!(unsafe { goldilocks_batch_inverse(values.as_mut_ptr(), 0) }, GoldilocksStatus::Ok);
+    assert_eq!(unsafe { goldilocks_batch_inverse(core::ptr::null_mut(), 0) }, GoldilocksStatus::NullPointer);
+}
+
+#[test]
+fn header_declares_every_exported_function() {
+    let header = include_str!("../include/goldilocks.h");
+    for name in [
+        "goldilocks_add(",
+        "goldilocks_sub(",
+        "goldilocks_mul(",
+        "goldilocks_inv(",
+        "goldilocks_ntt(",
+        "goldilocks_batch_inverse(",
+    ] {
+        assert!(header.contains(&format!("GoldilocksStatus {name}")), "{name} missing from the header");
+    }
+    for (variant, value) in [
+        ("OK", GoldilocksStatus::Ok),
+        ("NULL_POINTER", GoldilocksStatus::NullPointer),
+        ("NON_CANONICAL", GoldilocksStatus::NonCanonical),
+        ("ZERO_INVERSE", GoldilocksStatus::ZeroInverse),
+        ("INVALID_LENGTH", GoldilocksStatus::InvalidLength),
+        ("PANIC", GoldilocksStatus::Panic),
+    ] {
+        assert!(header.contains(&format!("GOLDILOCKS_STATUS_{variant} = {},", value as u32)), "{variant}");
+    }
+}
@@ -0,0 +1,30 @@
+use goldilocks_64bit::GoldilocksField;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+type FE = FieldElement<GoldilocksField>;
+
+#[test]
+fn fft_evaluation_round_trips_through_interpolation() {
+    let coefficients: Vec<FE> = (0..1024u64)
+        .map(|i| FE::from(i.wrapping_mul(0x9e37_79b9_7f4a_7c15)))
+        .collect();
+    let poly = Polynomial::new(&coefficients);
+
+    let evaluations = Polynomial::evaluate_fft::<GoldilocksField>(&poly, 1, None).unwrap();
+    assert_eq!(evaluations.len(), 1024);
+
+    let root = GoldilocksField::get_primitive_root_of_unity(10).unwrap();
+    assert_eq!(evaluations[1], poly.evaluate(&root));
+
+    let recovered = Polynomial::interpolate_fft::<GoldilocksField>(&evaluations).unwrap();
+    assert_eq!(recovered.coefficients(), &coefficients[..]);
+}
+
+#[test]
+fn primitive_root_of_unity_matches_the_crate_constant() {
+    let root = GoldilocksField::get_primitive_root_of_unity(32).unwrap();
+    assert_eq!(root.representative(), goldilocks_64bit::TWO_ADIC_ROOT_OF_UNITY.to_canonical_u64());
+    assert!(GoldilocksField::get_primitive_root_of_unity(33).is_err());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "num-traits")]
+
+use goldilocks_64bit::{GoldilocksField, MODULUS};
+use num_traits::{Inv, Num};
+
+/// Determinant by Gaussian elimination, using only what `Num + Inv` provide.
+fn determinant<T: Num + Inv<Output = T> + Copy>(mut rows: Vec<Vec<T>>) -> T {
+    let n = rows.len();
+    let mut det = T::one();
+    for col in 0..n {
+        let Some(pivot) = (col..n).find(|&r| !rows[r][col].is_zero()) else {
+            return T::zero();
+        };
+        if pivot != col {
+            rows.swap(pivot, col);
+            det = T::zero() - det;
+        }
+        det = det * rows[col][col];
+        let pivot_inv = rows[col][col].inv();
+        let (upper, lower) = rows.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] * pivot_inv;
+            for (x, &y) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *x = *x - factor * y;
+            }
+        }
+    }
+    det
+}
+
+fn to_field(rows: &[Vec<i64>]) -> Vec<Vec<GoldilocksField>> {
+    rows.iter().map(|row| row.iter().map(|&x| GoldilocksField::from(x)).collect()).collect()
+}
+
+fn to_f64(rows: &[Vec<i64>]) -> Vec<Vec<f64>> {
+    rows.iter().map(|row| row.iter().map(|&x| x as f64).collect()).collect()
+}
+
+#[test]
+fn determinant_of_small_integer_matrices_matches_f64() {
+    let matrices = [
+        vec![vec![2, -3, 1], vec![2, 0, -1], vec![1, 4, 5]],
+        vec![vec![0, 1, 2, 3], vec![1, 0, 1, 4], vec![5, 2, 0, 1], vec![3, 3, 7, 0]],
+        vec![vec![0, 0, 1], vec![0, 1, 0], vec![1, 0, 0]],
+        vec![vec![7]],
+    ];
+    for m in &matrices {
+        let expected = determinant(to_f64(m)).round() as i64;
+        assert_eq!(determinant(to_field(m)), GoldilocksField::from(expected), "{m:?}");
+    }
+    assert_eq!(determinant(to_field(&matrices[0])), GoldilocksField::from(49u64));
+    assert_eq!(determinant(to_field(&matrices[2])), -GoldilocksField::ONE);
+}
+
+#[test]
+fn determinant_of_singular_matrices_is_zero() {
+    let repeated_row = vec![vec![1, 2, 3], vec![4, 5, 6], vec![1, 2, 3]];
+    assert_eq!(determinant(to_field(&repeated_row)), GoldilocksField::ZERO);
+
+    // Singular only modulo p: over the integers the determinant is p.
+    let p = GoldilocksField::from_str_radix(&MODULUS.to_string(), 10).unwrap();
+    let rows = vec![vec![p, GoldilocksField::ONE], vec![GoldilocksField::ZERO, GoldilocksField::ONE]];
+    assert_eq!(determinant(rows), GoldilocksField::ZERO);
+}
+
+#[test]
+fn determinant_of_a_triangular_matrix_is_the_product_of_its_diagonal() {
+    let diagonal: Vec<GoldilocksField> = (1..=8u64)
+        .map(|i| GoldilocksField::from_str_radix(&format!("{:x}", i.wrapping_mul(0x9e37_79b9_7f4a_7c15)), 16).unwrap())
+        .collect();
+    let rows: Vec<Vec<GoldilocksField>> = (0..8)
+        .map(|r| {
+            (0..8u64)
+                .map(|c| match (c as usize).cmp(&r) {
+                    core::cmp::Ordering::Less => GoldilocksField::ZERO,
+                    core::cmp::Ordering::Equal => diagonal[r],
+                    core::cmp::Ordering::Greater => GoldilocksField::from(c * 31 + r as u64),
+                })
+                .collect()
+        })
+        .collect();
+    let product: GoldilocksField = diagonal.iter().product();
+    assert_eq!(determinant(rows), product);
+}
@@ -0,0 +1,99 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+//! Run with `wasm-pack test --node --features wasm`.
+
+use goldilocks_64bit::encoding::serialize_elements;
+use goldilocks_64bit::merkle::{MerkleTree, PoseidonHasher};
+use goldilocks_64bit::wasm::{poseidon_hash, verify_merkle_proof, WasmGoldilocks};
+use goldilocks_64bit::GoldilocksField;
+use wasm_bindgen::JsError;
+use wasm_bindgen_test::*;
+
+fn ok<T>(result: Result<T, JsError>) -> T {
+    result.unwrap_or_else(|_| panic!("binding threw"))
+}
+
+fn element(s: &str) -> WasmGoldilocks {
+    ok(WasmGoldilocks::new(s))
+}
+
+fn encode(values: &[u64]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+#[wasm_bindgen_test]
+fn strings_round_trip() {
+    for hex in ["0x0", "0x1", "0xdeadbeef", "0xffffffff00000000"] {
+        assert_eq!(element(hex).to_hex(), hex);
+        assert_eq!(ok(WasmGoldilocks::from_hex(&hex[2..])).to_hex(), hex);
+    }
+    assert_eq!(element("18446744069414584320").to_hex(), "0xffffffff00000000");
+    assert_eq!(element("18446744069414584320").to_decimal_string(), "18446744069414584320");
+    // Inputs of p or more are reduced.
+    assert!(element("18446744069414584321").is_zero());
+    assert!(element("0xffffffff00000002").equals(&WasmGoldilocks::one()));
+    assert!(WasmGoldilocks::new("12z").is_err());
+    assert!(WasmGoldilocks::new("").is_err());
+    assert!(WasmGoldilocks::from_hex("0x").is_err());
+}
+
+#[wasm_bindgen_test]
+fn arithmetic_matches_precomputed_vectors() {
+    let a = element("0x123456789abcdef0");
+    let b = element("0xfedcba9876543210");
+    assert_eq!(a.add(&b).to_hex(), "0x11111112111110ff");
+    assert_eq!(a.sub(&b).to_hex(), "0x13579bdf2468ace1");
+    assert_eq!(a.mul(&b).to_hex(), "0xfaeafd1f6c7bbad4");
+    assert_eq!(ok(a.div(&b)).to_hex(), "0x864b8a8fd1d60864");
+    assert_eq!(ok(b.inverse()).to_hex(), "0x660864b72e6d1d61");
+    assert_eq!(a.pow(0xdeadbeefcafebabe).to_hex(), "0x4c7bc46ddf530c53");
+    assert_eq!(a.neg().to_decimal_string(), "17134975601950794001");
+    assert_eq!(a.square(), a.mul(&a));
+    assert!(a.div(&WasmGoldilocks::zero()).is_err());
+    assert!(WasmGoldilocks::zero().inverse().is_err());
+}
+
+#[wasm_bindgen_test]
+fn poseidon_hash_known_answers() {
+    // The width-12 `hash_n_to_m` known answer for the inputs 0..10.
+    let input = encode(&(0..10).collect::<Vec<_>>());
+    assert_eq!(
+        ok(poseidon_hash(&input)),
+        encode(&[0xf2c9b602d5dc31f4, 0x914f17a76f289ecc, 0x0bcae24b87e24834, 0xd1fa70baefe919e0])
+    );
+    assert_eq!(ok(poseidon_hash(&input[..8 * 4])).len(), 32);
+    assert!(poseidon_hash(&input[..9]).is_err());
+    assert!(poseidon_hash(&encode(&[u64::MAX])).is_err());
+}
+
+#[wasm_bindgen_test]
+fn merkle_proofs_verify() {
+    let leaves: Vec<Vec<GoldilocksField>> = (0..6u64).map(|i| vec![GoldilocksField::new(i); 3]).collect();
+    let tree = MerkleTree::build(&leaves, PoseidonHasher);
+    let mut root = Vec::new();
+    serialize_elements(&tree.root(), &mut root);
+    for (index, leaf) in leaves.iter().enumerate() {
+        let mut siblings = Vec::new();
+        for digest in &tree.open(index).siblings {
+            serialize_elements(digest, &mut siblings);
+        }
+        let mut leaf_bytes = Vec::new();
+        serialize_elements(leaf, &mut leaf_bytes);
+        assert!(ok(verify_merkle_proof(&root, index, &leaf_bytes, &siblings)));
+        assert!(!ok(verify_merkle_proof(&root, index + 1, &leaf_bytes, &siblings)));
+        assert!(verify_merkle_proof(&root[..16], index, &leaf_bytes, &siblings).is_err());
+    }
+}
+
+#[wasm_bindgen_test]
+fn overlong_paths_are_rejected() {
+    // More siblings than a 32-bit index has bits.
+    let leaves = vec![vec![GoldilocksField::ONE]; 2];
+    let tree = MerkleTree::build(&leaves, PoseidonHasher);
+    let mut root = Vec::new();
+    serialize_elements(&tree.root(), &mut root);
+    let mut leaf = Vec::new();
+    serialize_elements(&leaves[0], &mut leaf);
+    let siblings = vec![0; 33 * 32];
+    assert!(!ok(verify_merkle_proof(&root, 0, &leaf, &siblings)));
+    assert!(!ok(verify_merkle_proof(&root, usize::MAX, &leaf, &siblings)));
+}